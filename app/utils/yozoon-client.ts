@@ -1,28 +1,64 @@
-import { 
-  Program, 
-  AnchorProvider, 
-  BN, 
-  web3, 
-  EventParser 
+import {
+  Program,
+  AnchorProvider,
+  BN,
+  EventParser
 } from '@project-serum/anchor';
-import { 
-  PublicKey, 
-  Transaction, 
-  SystemProgram, 
-  TransactionInstruction, 
-  Connection 
+import {
+  PublicKey,
+  SystemProgram,
+  TransactionInstruction,
+  AccountMeta,
+  SYSVAR_RENT_PUBKEY,
+  BPF_LOADER_UPGRADEABLE_PROGRAM_ID
 } from '@solana/web3.js';
-import { 
-  TOKEN_PROGRAM_ID, 
-  ASSOCIATED_TOKEN_PROGRAM_ID, 
-  getAssociatedTokenAddress 
+import {
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddress
 } from '@solana/spl-token';
 import { YozoonIDL } from '../idl/types';
 import { getYozoonProgram } from '../idl/types';
+import {
+  LpLockMode,
+  MigrationTargetKind,
+  MigrationThresholds,
+  MintExtensions
+} from '../idl/yozoon';
 
-export interface PricePoint {
-  supply: BN;
-  pricePerToken: BN;
+export const TOKEN_METADATA_PROGRAM_ID = new PublicKey('metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s');
+export const RAYDIUM_CPMM_PROGRAM_ID = new PublicKey('CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C');
+export const RAYDIUM_CPMM_CREATE_POOL_FEE_RECEIVER = new PublicKey('DNXgeM9EiiaAbaWvwjHj9fQQLAX5ZsfHyvmYUNRAdNC8');
+export const MEMO_PROGRAM_ID = new PublicKey('MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr');
+export const WRAPPED_SOL_MINT = new PublicKey('So11111111111111111111111111111111111111112');
+
+/** Bits of `Config.pauseFlags`, passed to `setPauseState` */
+export const PauseFlags = {
+  BUY: 1 << 0,
+  SELL: 1 << 1,
+  AIRDROP: 1 << 2,
+  REFERRAL: 1 << 3,
+  MIGRATE: 1 << 4,
+  ALL: 0b11111,
+};
+
+/** Bits of `Config.frozenParams`, passed to `freezeParameters` */
+export const FreezeFlags = {
+  PRICE_POINTS: 1 << 0,
+  FEES: 1 << 1,
+  TREASURY: 1 << 2,
+  MIGRATION_THRESHOLDS: 1 << 3,
+  ORACLE_FEEDS: 1 << 4,
+  METADATA: 1 << 5,
+};
+
+/** Optional accounts of `buyTokens` / `buyTokensUsd` */
+export interface BuyOptions {
+  /** Referrer recorded in the buyer's referral account, if any */
+  referrer?: PublicKey;
+  /** Pyth SOL/USD feed on the config allowlist, to price the purchase in USD */
+  solUsdPrice?: PublicKey;
 }
 
 export class YozoonClient {
@@ -37,431 +73,684 @@ export class YozoonClient {
   // --- PDA DERIVATION METHODS ---
 
   /**
-   * Get the config address for the protocol
+   * Get the launchpad address
+   */
+  async getLaunchpadAddress(): Promise<PublicKey> {
+    const [launchpadAddress] = await PublicKey.findProgramAddress(
+      [Buffer.from('launchpad')],
+      this.program.programId
+    );
+    return launchpadAddress;
+  }
+
+  /**
+   * Get the program data account holding the program's upgrade authority
    */
-  async getConfigAddress(): Promise<PublicKey> {
+  async getProgramDataAddress(): Promise<PublicKey> {
+    const [programDataAddress] = await PublicKey.findProgramAddress(
+      [this.program.programId.toBuffer()],
+      BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+    );
+    return programDataAddress;
+  }
+
+  /**
+   * Get the config address of a launch
+   */
+  async getConfigAddress(mint: PublicKey): Promise<PublicKey> {
     const [configAddress] = await PublicKey.findProgramAddress(
-      [Buffer.from('config')],
+      [Buffer.from('config'), mint.toBuffer()],
       this.program.programId
     );
     return configAddress;
   }
 
   /**
-   * Get the bonding curve address
+   * Get the bonding curve address of a launch
    */
-  async getBondingCurveAddress(): Promise<PublicKey> {
+  async getBondingCurveAddress(mint: PublicKey): Promise<PublicKey> {
     const [bondingCurveAddress] = await PublicKey.findProgramAddress(
-      [Buffer.from('bonding_curve')],
+      [Buffer.from('bonding_curve'), mint.toBuffer()],
       this.program.programId
     );
     return bondingCurveAddress;
   }
 
   /**
-   * Get the referral address for a specific referrer
+   * Get the SOL reserve address of a launch
    */
-  async getReferralAddress(referrer: PublicKey): Promise<PublicKey> {
+  async getSolReserveAddress(mint: PublicKey): Promise<PublicKey> {
+    const [solReserveAddress] = await PublicKey.findProgramAddress(
+      [Buffer.from('sol_reserve'), mint.toBuffer()],
+      this.program.programId
+    );
+    return solReserveAddress;
+  }
+
+  /**
+   * Get the price observations address of a launch
+   */
+  async getPriceObservationsAddress(mint: PublicKey): Promise<PublicKey> {
+    const [priceObservationsAddress] = await PublicKey.findProgramAddress(
+      [Buffer.from('price_observations'), mint.toBuffer()],
+      this.program.programId
+    );
+    return priceObservationsAddress;
+  }
+
+  /**
+   * Get the referral address of a user in a launch
+   */
+  async getReferralAddress(mint: PublicKey, user: PublicKey): Promise<PublicKey> {
     const [referralAddress] = await PublicKey.findProgramAddress(
-      [Buffer.from('referral'), referrer.toBuffer()],
+      [Buffer.from('referral'), mint.toBuffer(), user.toBuffer()],
       this.program.programId
     );
     return referralAddress;
   }
 
   /**
-   * Get the airdrop address for a specific recipient
+   * Get the airdrop ledger address of a launch
+   */
+  async getAirdropLedgerAddress(mint: PublicKey): Promise<PublicKey> {
+    const [airdropLedgerAddress] = await PublicKey.findProgramAddress(
+      [Buffer.from('airdrop_ledger'), mint.toBuffer()],
+      this.program.programId
+    );
+    return airdropLedgerAddress;
+  }
+
+  /**
+   * Get the pool creator address of a launch
    */
-  async getAirdropAddress(recipient: PublicKey): Promise<PublicKey> {
-    const [airdropAddress] = await PublicKey.findProgramAddress(
-      [Buffer.from('airdrop'), recipient.toBuffer()],
+  async getPoolCreatorAddress(mint: PublicKey): Promise<PublicKey> {
+    const [poolCreatorAddress] = await PublicKey.findProgramAddress(
+      [Buffer.from('pool_creator'), mint.toBuffer()],
       this.program.programId
     );
-    return airdropAddress;
+    return poolCreatorAddress;
   }
 
   /**
-   * Get the token mint address
+   * Get the pool record address of a migrated launch
    */
-  async getTokenMintAddress(): Promise<PublicKey> {
-    const configAddress = await this.getConfigAddress();
-    const configData = await this.fetchConfigData(configAddress);
-    return configData.tokenMint;
+  async getRaydiumPoolAddress(mint: PublicKey): Promise<PublicKey> {
+    const [raydiumPoolAddress] = await PublicKey.findProgramAddress(
+      [Buffer.from('raydium_pool'), mint.toBuffer()],
+      this.program.programId
+    );
+    return raydiumPoolAddress;
+  }
+
+  /**
+   * Get the LP lock vault address of a pool
+   */
+  async getLpLockAddress(raydiumPool: PublicKey): Promise<PublicKey> {
+    const [lpLockAddress] = await PublicKey.findProgramAddress(
+      [Buffer.from('lp_lock'), raydiumPool.toBuffer()],
+      this.program.programId
+    );
+    return lpLockAddress;
+  }
+
+  /**
+   * Get the fee key record address of a fee key NFT
+   */
+  async getFeeKeyNftAddress(raydiumPool: PublicKey, nftMint: PublicKey): Promise<PublicKey> {
+    const [feeKeyNftAddress] = await PublicKey.findProgramAddress(
+      [Buffer.from('nft_fee_key'), raydiumPool.toBuffer(), nftMint.toBuffer()],
+      this.program.programId
+    );
+    return feeKeyNftAddress;
+  }
+
+  /**
+   * Get the fee vault address of a pool for one of its mints
+   */
+  async getFeeVaultAddress(raydiumPool: PublicKey, mint: PublicKey): Promise<PublicKey> {
+    const [feeVaultAddress] = await PublicKey.findProgramAddress(
+      [Buffer.from('fee_vault'), raydiumPool.toBuffer(), mint.toBuffer()],
+      this.program.programId
+    );
+    return feeVaultAddress;
+  }
+
+  /**
+   * Get the Raydium CPMM pool accounts `migrate` takes as remaining accounts, in
+   * program order, for a pool under the `ammConfig` fee tier
+   */
+  async getRaydiumCpmmMigrateAccounts(mint: PublicKey, ammConfig: PublicKey): Promise<AccountMeta[]> {
+    const pda = async (seeds: Buffer[]) =>
+      (await PublicKey.findProgramAddress(seeds, RAYDIUM_CPMM_PROGRAM_ID))[0];
+
+    // CPMM orders the pair by mint address
+    const [token0Mint, token1Mint] = Buffer.compare(mint.toBuffer(), WRAPPED_SOL_MINT.toBuffer()) < 0
+      ? [mint, WRAPPED_SOL_MINT]
+      : [WRAPPED_SOL_MINT, mint];
+    const poolState = await pda([
+      Buffer.from('pool'),
+      ammConfig.toBuffer(),
+      token0Mint.toBuffer(),
+      token1Mint.toBuffer(),
+    ]);
+    const lpMint = await pda([Buffer.from('pool_lp_mint'), poolState.toBuffer()]);
+    const poolCreator = await this.getPoolCreatorAddress(mint);
+
+    const writable = (pubkey: PublicKey): AccountMeta => ({ pubkey, isSigner: false, isWritable: true });
+    return [
+      { pubkey: ammConfig, isSigner: false, isWritable: false },
+      { pubkey: await pda([Buffer.from('vault_and_lp_mint_auth_seed')]), isSigner: false, isWritable: false },
+      writable(poolState),
+      writable(lpMint),
+      writable(await getAssociatedTokenAddress(lpMint, poolCreator, true)),
+      writable(await pda([Buffer.from('pool_vault'), poolState.toBuffer(), token0Mint.toBuffer()])),
+      writable(await pda([Buffer.from('pool_vault'), poolState.toBuffer(), token1Mint.toBuffer()])),
+      writable(await pda([Buffer.from('observation'), poolState.toBuffer()])),
+      writable(RAYDIUM_CPMM_CREATE_POOL_FEE_RECEIVER),
+    ];
+  }
+
+  /**
+   * Get the Metaplex metadata address of a mint
+   */
+  async getMetadataAddress(mint: PublicKey): Promise<PublicKey> {
+    const [metadataAddress] = await PublicKey.findProgramAddress(
+      [Buffer.from('metadata'), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+      TOKEN_METADATA_PROGRAM_ID
+    );
+    return metadataAddress;
+  }
+
+  /**
+   * Get the token program that owns a mint (SPL Token or Token-2022)
+   */
+  async getTokenProgram(mint: PublicKey): Promise<PublicKey> {
+    const mintInfo = await this.provider.connection.getAccountInfo(mint);
+    if (!mintInfo) {
+      throw new Error(`Mint ${mint.toBase58()} does not exist`);
+    }
+    return mintInfo.owner;
   }
 
   // --- DATA FETCHING METHODS ---
 
+  /**
+   * Fetch launchpad account data
+   */
+  async fetchLaunchpadData() {
+    return await this.program.account.launchpad.fetch(await this.getLaunchpadAddress());
+  }
+
   /**
    * Fetch config account data
    */
-  async fetchConfigData(configAddress: PublicKey) {
-    return await this.program.account.config.fetch(configAddress);
+  async fetchConfigData(mint: PublicKey) {
+    return await this.program.account.config.fetch(await this.getConfigAddress(mint));
   }
 
   /**
    * Fetch bonding curve account data
    */
-  async fetchBondingCurveData(bondingCurveAddress: PublicKey) {
-    return await this.program.account.bondingCurve.fetch(bondingCurveAddress);
+  async fetchBondingCurveData(mint: PublicKey) {
+    return await this.program.account.bondingCurve.fetch(await this.getBondingCurveAddress(mint));
   }
 
   /**
    * Fetch referral account data
    */
-  async fetchReferralData(referralAddress: PublicKey) {
-    return await this.program.account.referral.fetch(referralAddress);
+  async fetchReferralData(mint: PublicKey, user: PublicKey) {
+    return await this.program.account.referral.fetch(await this.getReferralAddress(mint, user));
+  }
+
+  /**
+   * Fetch airdrop ledger account data
+   */
+  async fetchAirdropLedgerData(mint: PublicKey) {
+    return await this.program.account.airdropLedger.fetch(await this.getAirdropLedgerAddress(mint));
   }
 
   /**
-   * Fetch airdrop account data
+   * Fetch pool record data of a migrated launch
    */
-  async fetchAirdropData(airdropAddress: PublicKey) {
-    return await this.program.account.airdrop.fetch(airdropAddress);
+  async fetchRaydiumPoolData(mint: PublicKey) {
+    return await this.program.account.raydiumPool.fetch(await this.getRaydiumPoolAddress(mint));
   }
 
   /**
-   * Check if the protocol has been migrated to Raydium
+   * Check if a launch has migrated to its DEX
    */
-  async isMigratedToRaydium(bondingCurveAddress: PublicKey): Promise<boolean> {
-    const bondingCurveData = await this.fetchBondingCurveData(bondingCurveAddress);
-    return bondingCurveData.isMigrated;
+  async isMigrated(mint: PublicKey): Promise<boolean> {
+    const bondingCurveData = await this.fetchBondingCurveData(mint);
+    return 'migrated' in bondingCurveData.state;
   }
 
   /**
    * Get the current token price
    */
-  async getCurrentTokenPrice(bondingCurveAddress: PublicKey): Promise<BN> {
-    const bondingCurveData = await this.fetchBondingCurveData(bondingCurveAddress);
-    
-    // Find the current price point based on total sold supply
-    const totalSoldSupply = bondingCurveData.totalSoldSupply;
-    const pricePoints = bondingCurveData.pricePoints;
-    
-    let currentPrice = pricePoints[0].pricePerToken;
-    
-    for (let i = 0; i < pricePoints.length; i++) {
-      if (totalSoldSupply.gte(pricePoints[i].supply)) {
-        currentPrice = pricePoints[i].pricePerToken;
-      } else {
-        break;
-      }
-    }
-    
-    return currentPrice;
-  }
-
-  /**
-   * Calculate the number of tokens a user would receive for a given SOL amount
-   */
-  async calculateTokensForSol(
-    bondingCurveAddress: PublicKey,
-    solAmount: BN
-  ): Promise<BN> {
-    const bondingCurveData = await this.fetchBondingCurveData(bondingCurveAddress);
-    const currentPrice = await this.getCurrentTokenPrice(bondingCurveAddress);
-    
-    // This is a simplified calculation - the actual protocol uses a more precise approach
-    // that accounts for crossing price points
-    return solAmount.mul(new BN(1_000_000_000)).div(currentPrice);
-  }
-
-  /**
-   * Calculate the price at a specific supply level
-   */
-  async calculatePriceAtSupply(
-    bondingCurveAddress: PublicKey,
-    supply: BN
-  ): Promise<BN> {
-    const bondingCurveData = await this.fetchBondingCurveData(bondingCurveAddress);
-    const pricePoints = bondingCurveData.pricePoints;
-    
-    let price = pricePoints[0].pricePerToken;
-    
-    for (let i = 0; i < pricePoints.length; i++) {
-      if (supply.gte(pricePoints[i].supply)) {
-        price = pricePoints[i].pricePerToken;
-      } else {
-        break;
-      }
-    }
-    
-    return price;
+  async getCurrentTokenPrice(mint: PublicKey): Promise<BN> {
+    return await this.program.methods
+      .calculateCurrentPrice()
+      .accounts({
+        config: await this.getConfigAddress(mint),
+        bondingCurve: await this.getBondingCurveAddress(mint),
+      })
+      .view();
+  }
+
+  /**
+   * Calculate the number of tokens a buyer would receive for a given SOL amount
+   */
+  async calculateTokensForSol(mint: PublicKey, solAmount: BN): Promise<BN> {
+    return await this.program.methods
+      .calculateTokensForSol(solAmount)
+      .accounts({
+        config: await this.getConfigAddress(mint),
+        bondingCurve: await this.getBondingCurveAddress(mint),
+        mint,
+      })
+      .view();
+  }
+
+  /**
+   * Get the time-weighted average price over the last `windowSecs` seconds
+   */
+  async getTwap(mint: PublicKey, windowSecs: number): Promise<BN> {
+    return await this.program.methods
+      .getTwap(windowSecs)
+      .accounts({
+        config: await this.getConfigAddress(mint),
+        bondingCurve: await this.getBondingCurveAddress(mint),
+        priceObservations: await this.getPriceObservationsAddress(mint),
+      })
+      .view();
   }
 
   // --- INSTRUCTION BUILDING METHODS ---
 
   /**
-   * Build instruction to initialize the mint (admin only)
+   * Build instruction to create the launchpad; the signer must be the program's upgrade
+   * authority and becomes the launchpad authority
+   */
+  async buildInitializeLaunchpadInstruction(
+    authority: PublicKey,
+    creationFee: BN
+  ): Promise<TransactionInstruction> {
+    return this.program.methods
+      .initializeLaunchpad(creationFee)
+      .accounts({
+        launchpad: await this.getLaunchpadAddress(),
+        authority,
+        program: this.program.programId,
+        programData: await this.getProgramDataAddress(),
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+  }
+
+  /**
+   * Build instruction to nominate a new launchpad authority, who must accept (launchpad authority only)
+   */
+  async buildTransferLaunchpadAuthorityInstruction(
+    authority: PublicKey,
+    newAuthority: PublicKey
+  ): Promise<TransactionInstruction> {
+    return this.program.methods
+      .transferLaunchpadAuthority(newAuthority)
+      .accounts({
+        launchpad: await this.getLaunchpadAddress(),
+        authority,
+      })
+      .instruction();
+  }
+
+  /**
+   * Build instruction for the nominated launchpad authority to accept the role
+   */
+  async buildAcceptLaunchpadAuthorityInstruction(
+    pendingAuthority: PublicKey
+  ): Promise<TransactionInstruction> {
+    return this.program.methods
+      .acceptLaunchpadAuthority()
+      .accounts({
+        launchpad: await this.getLaunchpadAddress(),
+        pendingAuthority,
+      })
+      .instruction();
+  }
+
+  /**
+   * Build instruction to withdraw a pending launchpad authority nomination (launchpad authority only)
+   */
+  async buildCancelLaunchpadAuthorityTransferInstruction(
+    authority: PublicKey
+  ): Promise<TransactionInstruction> {
+    return this.program.methods
+      .cancelLaunchpadAuthorityTransfer()
+      .accounts({
+        launchpad: await this.getLaunchpadAddress(),
+        authority,
+      })
+      .instruction();
+  }
+
+  /**
+   * Build instruction to replace the launch creator allowlist (launchpad authority only)
+   */
+  async buildSetLaunchCreatorsInstruction(
+    authority: PublicKey,
+    creators: PublicKey[]
+  ): Promise<TransactionInstruction> {
+    return this.program.methods
+      .setLaunchCreators(creators)
+      .accounts({
+        launchpad: await this.getLaunchpadAddress(),
+        authority,
+      })
+      .instruction();
+  }
+
+  /**
+   * Build instruction to create a launch: the mint, its metadata and config.
+   * The mint keypair must sign the transaction.
    */
   async buildInitializeMintInstruction(
     admin: PublicKey,
-    defaultReferralFee: BN
+    mint: PublicKey,
+    name: string,
+    symbol: string,
+    uri: string,
+    decimals: number,
+    maxSupply: BN,
+    extensions: MintExtensions
   ): Promise<TransactionInstruction> {
-    const configAddress = await this.getConfigAddress();
-    
+    const launchpad = await this.fetchLaunchpadData();
+    const usesToken2022 =
+      extensions.transferFee !== null ||
+      extensions.metadataPointer ||
+      extensions.nonTransferableUntilMigration;
+
     return this.program.methods
-      .initializeMint(defaultReferralFee)
+      .initializeMint(name, symbol, uri, decimals, maxSupply, extensions)
       .accounts({
-        config: configAddress,
-        admin: admin,
+        launchpad: await this.getLaunchpadAddress(),
+        feeRecipient: launchpad.feeRecipient,
+        config: await this.getConfigAddress(mint),
+        mint,
+        metadata: await this.getMetadataAddress(mint),
+        admin,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        tokenProgram: usesToken2022 ? TOKEN_2022_PROGRAM_ID : TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
       })
       .instruction();
   }
 
   /**
-   * Build instruction to initialize the bonding curve (admin only)
+   * Build instruction to initialize the bonding curve of a launch (admin only)
    */
   async buildInitializeBondingCurveInstruction(
     admin: PublicKey,
-    pricePoints: PricePoint[]
+    mint: PublicKey,
+    pricePoints: BN[],
+    thresholds: MigrationThresholds,
+    migrationTarget: MigrationTargetKind,
+    launchDeadline: BN
   ): Promise<TransactionInstruction> {
-    const configAddress = await this.getConfigAddress();
-    const bondingCurveAddress = await this.getBondingCurveAddress();
-    const tokenMint = await this.getTokenMintAddress();
-    
     return this.program.methods
-      .initializeBondingCurve(pricePoints)
+      .initializeBondingCurve(pricePoints, thresholds, migrationTarget, launchDeadline)
       .accounts({
-        config: configAddress,
-        bondingCurve: bondingCurveAddress,
-        tokenMint: tokenMint,
-        admin: admin,
+        config: await this.getConfigAddress(mint),
+        bondingCurve: await this.getBondingCurveAddress(mint),
+        priceObservations: await this.getPriceObservationsAddress(mint),
+        solReserve: await this.getSolReserveAddress(mint),
+        admin,
         systemProgram: SystemProgram.programId,
       })
       .instruction();
   }
 
   /**
-   * Build instruction to buy tokens
+   * Accounts shared by `buyTokens` and `buyTokensUsd`
+   */
+  private async buyAccounts(buyer: PublicKey, mint: PublicKey, options: BuyOptions) {
+    const tokenProgram = await this.getTokenProgram(mint);
+
+    return {
+      config: await this.getConfigAddress(mint),
+      bondingCurve: await this.getBondingCurveAddress(mint),
+      priceObservations: await this.getPriceObservationsAddress(mint),
+      mint,
+      buyerTokenAccount: await getAssociatedTokenAddress(mint, buyer, false, tokenProgram),
+      buyer,
+      solReserve: await this.getSolReserveAddress(mint),
+      referral: options.referrer ? await this.getReferralAddress(mint, buyer) : null,
+      referrer: options.referrer ?? null,
+      solUsdPrice: options.solUsdPrice ?? null,
+      systemProgram: SystemProgram.programId,
+      tokenProgram,
+    };
+  }
+
+  /**
+   * Build instruction to buy tokens for `solAmount` lamports into the buyer's
+   * associated token account
    */
   async buildBuyTokensInstruction(
     buyer: PublicKey,
+    mint: PublicKey,
     solAmount: BN,
-    minTokensExpected: BN
+    options: BuyOptions = {}
   ): Promise<TransactionInstruction> {
-    const configAddress = await this.getConfigAddress();
-    const bondingCurveAddress = await this.getBondingCurveAddress();
-    const tokenMint = await this.getTokenMintAddress();
-    
-    const buyerTokenAccount = await getAssociatedTokenAddress(
-      tokenMint,
-      buyer,
-      false
-    );
-    
     return this.program.methods
-      .buyTokens(solAmount, minTokensExpected)
-      .accounts({
-        config: configAddress,
-        bondingCurve: bondingCurveAddress,
-        tokenMint: tokenMint,
-        buyer: buyer,
-        buyerTokenAccount: buyerTokenAccount,
-        systemProgram: SystemProgram.programId,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-      })
+      .buyTokens(solAmount)
+      .accounts(await this.buyAccounts(buyer, mint, options))
       .instruction();
   }
 
   /**
-   * Build instruction to buy tokens with a referral
+   * Build instruction to buy `usdAmount` (6 decimals) worth of tokens, paying at
+   * most `maxSolIn` lamports; needs `options.solUsdPrice`
    */
-  async buildBuyTokensWithReferralInstruction(
+  async buildBuyTokensUsdInstruction(
     buyer: PublicKey,
-    solAmount: BN,
-    minTokensExpected: BN,
-    referrer: PublicKey
+    mint: PublicKey,
+    usdAmount: BN,
+    maxSolIn: BN,
+    options: BuyOptions
   ): Promise<TransactionInstruction> {
-    const configAddress = await this.getConfigAddress();
-    const bondingCurveAddress = await this.getBondingCurveAddress();
-    const tokenMint = await this.getTokenMintAddress();
-    const referralAddress = await this.getReferralAddress(referrer);
-    
-    const buyerTokenAccount = await getAssociatedTokenAddress(
-      tokenMint,
-      buyer,
-      false
-    );
-    
     return this.program.methods
-      .buyTokensWithReferral(solAmount, minTokensExpected)
-      .accounts({
-        config: configAddress,
-        bondingCurve: bondingCurveAddress,
-        tokenMint: tokenMint,
-        buyer: buyer,
-        buyerTokenAccount: buyerTokenAccount,
-        referral: referralAddress,
-        referrer: referrer,
-        systemProgram: SystemProgram.programId,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-      })
+      .buyTokensUsd(usdAmount, maxSolIn)
+      .accounts(await this.buyAccounts(buyer, mint, options))
       .instruction();
   }
 
   /**
-   * Build instruction to sell tokens
+   * Build instruction to sell tokens back to the curve
    */
   async buildSellTokensInstruction(
     seller: PublicKey,
-    tokenAmount: BN,
-    minSolExpected: BN
+    mint: PublicKey,
+    tokenAmount: BN
   ): Promise<TransactionInstruction> {
-    const configAddress = await this.getConfigAddress();
-    const bondingCurveAddress = await this.getBondingCurveAddress();
-    const tokenMint = await this.getTokenMintAddress();
-    
-    const sellerTokenAccount = await getAssociatedTokenAddress(
-      tokenMint,
-      seller,
-      false
-    );
-    
+    const tokenProgram = await this.getTokenProgram(mint);
+
     return this.program.methods
-      .sellTokens(tokenAmount, minSolExpected)
+      .sellTokens(tokenAmount)
       .accounts({
-        config: configAddress,
-        bondingCurve: bondingCurveAddress,
-        tokenMint: tokenMint,
-        seller: seller,
-        sellerTokenAccount: sellerTokenAccount,
+        config: await this.getConfigAddress(mint),
+        seller,
+        sellerTokenAccount: await getAssociatedTokenAddress(mint, seller, false, tokenProgram),
+        mint,
+        bondingCurve: await this.getBondingCurveAddress(mint),
+        priceObservations: await this.getPriceObservationsAddress(mint),
+        solReserve: await this.getSolReserveAddress(mint),
+        tokenProgram,
         systemProgram: SystemProgram.programId,
-        tokenProgram: TOKEN_PROGRAM_ID,
       })
       .instruction();
   }
 
   /**
-   * Build instruction to create a referral
+   * Build instruction for a user to record their referrer in a launch
    */
-  async buildCreateReferralInstruction(
-    payer: PublicKey,
-    referrer: PublicKey,
-    referralFee: BN
+  async buildSetReferralInstruction(
+    user: PublicKey,
+    mint: PublicKey,
+    referrer: PublicKey
   ): Promise<TransactionInstruction> {
-    const configAddress = await this.getConfigAddress();
-    const referralAddress = await this.getReferralAddress(referrer);
-    
     return this.program.methods
-      .createReferral(referralFee)
+      .setReferral(referrer)
       .accounts({
-        config: configAddress,
-        referral: referralAddress,
-        referrer: referrer,
-        payer: payer,
+        config: await this.getConfigAddress(mint),
+        referral: await this.getReferralAddress(mint, user),
+        user,
         systemProgram: SystemProgram.programId,
       })
       .instruction();
   }
 
   /**
-   * Build instruction to create an airdrop (admin only)
+   * Build instruction to change a user's referral fee, in basis points (admin only)
    */
-  async buildCreateAirdropInstruction(
+  async buildUpdateReferralFeeInstruction(
     admin: PublicKey,
-    recipient: PublicKey,
-    amount: BN
+    mint: PublicKey,
+    user: PublicKey,
+    newFeePercentage: BN
   ): Promise<TransactionInstruction> {
-    const configAddress = await this.getConfigAddress();
-    const airdropAddress = await this.getAirdropAddress(recipient);
-    const tokenMint = await this.getTokenMintAddress();
-    
     return this.program.methods
-      .createAirdrop(amount)
+      .updateReferralFee(newFeePercentage)
       .accounts({
-        config: configAddress,
-        airdrop: airdropAddress,
-        tokenMint: tokenMint,
-        admin: admin,
-        recipient: recipient,
-        systemProgram: SystemProgram.programId,
+        referral: await this.getReferralAddress(mint, user),
+        user,
+        config: await this.getConfigAddress(mint),
+        admin,
       })
       .instruction();
   }
 
   /**
-   * Build instruction to claim an airdrop
+   * Build instruction to airdrop tokens to a recipient's associated token account (admin only)
    */
-  async buildClaimAirdropInstruction(
-    recipient: PublicKey
+  async buildAirdropTokensInstruction(
+    admin: PublicKey,
+    mint: PublicKey,
+    recipient: PublicKey,
+    amount: BN
   ): Promise<TransactionInstruction> {
-    const configAddress = await this.getConfigAddress();
-    const airdropAddress = await this.getAirdropAddress(recipient);
-    const tokenMint = await this.getTokenMintAddress();
-    
-    const recipientTokenAccount = await getAssociatedTokenAddress(
-      tokenMint,
-      recipient,
-      false
-    );
-    
+    const tokenProgram = await this.getTokenProgram(mint);
+
     return this.program.methods
-      .claimAirdrop()
+      .airdropTokens(amount)
       .accounts({
-        config: configAddress,
-        airdrop: airdropAddress,
-        tokenMint: tokenMint,
-        recipient: recipient,
-        recipientTokenAccount: recipientTokenAccount,
+        config: await this.getConfigAddress(mint),
+        admin,
+        bondingCurve: await this.getBondingCurveAddress(mint),
+        airdropLedger: await this.getAirdropLedgerAddress(mint),
+        mint,
+        recipientTokenAccount: await getAssociatedTokenAddress(mint, recipient, false, tokenProgram),
         systemProgram: SystemProgram.programId,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram,
       })
       .instruction();
   }
 
   /**
-   * Build instruction to pause the protocol (admin only)
+   * Build instruction to set the pause mask of a launch, see `PauseFlags` (admin only)
    */
-  async buildPauseProtocolInstruction(
-    admin: PublicKey
+  async buildSetPauseStateInstruction(
+    admin: PublicKey,
+    mint: PublicKey,
+    pauseMask: number
   ): Promise<TransactionInstruction> {
-    const configAddress = await this.getConfigAddress();
-    
     return this.program.methods
-      .pauseProtocol()
+      .setPauseState(pauseMask)
       .accounts({
-        config: configAddress,
-        admin: admin,
+        config: await this.getConfigAddress(mint),
+        admin,
       })
       .instruction();
   }
 
   /**
-   * Build instruction to unpause the protocol (admin only)
+   * Build instruction to permanently freeze parameters, see `FreezeFlags` (admin only)
    */
-  async buildUnpauseProtocolInstruction(
-    admin: PublicKey
+  async buildFreezeParametersInstruction(
+    admin: PublicKey,
+    mint: PublicKey,
+    freezeMask: number
   ): Promise<TransactionInstruction> {
-    const configAddress = await this.getConfigAddress();
-    
     return this.program.methods
-      .unpauseProtocol()
+      .freezeParameters(freezeMask)
       .accounts({
-        config: configAddress,
-        admin: admin,
+        config: await this.getConfigAddress(mint),
+        admin,
       })
       .instruction();
   }
 
   /**
-   * Build instruction to transfer admin (admin only)
+   * Build instruction to nominate a new admin, who must accept (admin only)
    */
   async buildTransferAdminInstruction(
     admin: PublicKey,
+    mint: PublicKey,
     newAdmin: PublicKey
   ): Promise<TransactionInstruction> {
-    const configAddress = await this.getConfigAddress();
-    
     return this.program.methods
-      .transferAdmin()
+      .transferAdmin(newAdmin)
       .accounts({
-        config: configAddress,
-        admin: admin,
-        newAdmin: newAdmin,
+        config: await this.getConfigAddress(mint),
+        admin,
+      })
+      .instruction();
+  }
+
+  /**
+   * Build instruction for the nominated admin to accept the role
+   */
+  async buildAcceptAdminInstruction(
+    pendingAdmin: PublicKey,
+    mint: PublicKey
+  ): Promise<TransactionInstruction> {
+    return this.program.methods
+      .acceptAdmin()
+      .accounts({
+        config: await this.getConfigAddress(mint),
+        pendingAdmin,
+      })
+      .instruction();
+  }
+
+  /**
+   * Build instruction to withdraw a pending admin nomination (admin only)
+   */
+  async buildCancelAdminTransferInstruction(
+    admin: PublicKey,
+    mint: PublicKey
+  ): Promise<TransactionInstruction> {
+    return this.program.methods
+      .cancelAdminTransfer()
+      .accounts({
+        config: await this.getConfigAddress(mint),
+        admin,
+      })
+      .instruction();
+  }
+
+  /**
+   * Build instruction to give up the admin role for good (admin only, with nothing paused)
+   */
+  async buildRenounceAdminInstruction(
+    admin: PublicKey,
+    mint: PublicKey
+  ): Promise<TransactionInstruction> {
+    return this.program.methods
+      .renounceAdmin()
+      .accounts({
+        config: await this.getConfigAddress(mint),
+        admin,
       })
       .instruction();
   }
@@ -469,42 +758,232 @@ export class YozoonClient {
   /**
    * Build instruction to update treasury (admin only)
    */
-  async buildUpdateTreasuryInstruction(
+  async buildSetTreasuryInstruction(
     admin: PublicKey,
+    mint: PublicKey,
     newTreasury: PublicKey
   ): Promise<TransactionInstruction> {
-    const configAddress = await this.getConfigAddress();
-    
     return this.program.methods
-      .updateTreasury()
+      .setTreasury(newTreasury)
+      .accounts({
+        config: await this.getConfigAddress(mint),
+        admin,
+      })
+      .instruction();
+  }
+
+  /**
+   * Build instruction to migrate a ready launch to its DEX. Anyone may call it and
+   * earns the migration bounty. The fee key NFT mint keypair must sign, and
+   * `dexAccounts` are the pool accounts of the DEX in `bondingCurve.migrationTarget`.
+   */
+  async buildMigrateInstruction(
+    caller: PublicKey,
+    mint: PublicKey,
+    nftMint: PublicKey,
+    solUsdPrice: PublicKey,
+    dexProgram: PublicKey,
+    lpLockMode: LpLockMode,
+    dexAccounts: AccountMeta[]
+  ): Promise<TransactionInstruction> {
+    const config = await this.fetchConfigData(mint);
+    const tokenProgram = await this.getTokenProgram(mint);
+    const poolCreator = await this.getPoolCreatorAddress(mint);
+    const raydiumPool = await this.getRaydiumPoolAddress(mint);
+
+    return this.program.methods
+      .migrate(lpLockMode)
+      .accounts({
+        config: await this.getConfigAddress(mint),
+        bondingCurve: await this.getBondingCurveAddress(mint),
+        mint,
+        wrappedSol: WRAPPED_SOL_MINT,
+        solReserve: await this.getSolReserveAddress(mint),
+        poolCreator,
+        tokenAccount: await getAssociatedTokenAddress(mint, poolCreator, true, tokenProgram),
+        solTokenAccount: await getAssociatedTokenAddress(WRAPPED_SOL_MINT, poolCreator, true),
+        nftMint,
+        treasury: config.treasury,
+        treasuryNftAccount: await getAssociatedTokenAddress(nftMint, config.treasury, true),
+        raydiumPool,
+        lpLock: await this.getLpLockAddress(raydiumPool),
+        feeKeyNft: await this.getFeeKeyNftAddress(raydiumPool, nftMint),
+        caller,
+        solUsdPrice,
+        dexProgram,
+        systemProgram: SystemProgram.programId,
+        tokenProgram,
+        splTokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .remainingAccounts(dexAccounts)
+      .instruction();
+  }
+
+  /**
+   * Build instruction to wrap up a migrated launch: revokes the mint authority and
+   * closes the bonding curve and SOL reserve to the treasury
+   */
+  async buildFinalizeMigrationInstruction(
+    caller: PublicKey,
+    mint: PublicKey
+  ): Promise<TransactionInstruction> {
+    const config = await this.fetchConfigData(mint);
+
+    return this.program.methods
+      .finalizeMigration()
       .accounts({
-        config: configAddress,
-        admin: admin,
-        newTreasury: newTreasury,
+        config: await this.getConfigAddress(mint),
+        bondingCurve: await this.getBondingCurveAddress(mint),
+        mint,
+        solReserve: await this.getSolReserveAddress(mint),
+        treasury: config.treasury,
+        caller,
+        tokenProgram: await this.getTokenProgram(mint),
+        systemProgram: SystemProgram.programId,
       })
       .instruction();
   }
 
   /**
-   * Build instruction to migrate to Raydium (admin only)
+   * Build instruction to put a launch that will not migrate into refund mode
    */
-  async buildMigrateToRaydiumInstruction(
-    admin: PublicKey
+  async buildEnterRefundModeInstruction(
+    caller: PublicKey,
+    mint: PublicKey
   ): Promise<TransactionInstruction> {
-    const configAddress = await this.getConfigAddress();
-    const bondingCurveAddress = await this.getBondingCurveAddress();
-    const tokenMint = await this.getTokenMintAddress();
-    
-    // Note: In a real implementation, you would need to include all the Raydium accounts
-    // This is a simplified version
     return this.program.methods
-      .migrateToRaydium()
+      .enterRefundMode()
       .accounts({
-        config: configAddress,
-        bondingCurve: bondingCurveAddress,
-        tokenMint: tokenMint,
-        admin: admin,
-        // ... other Raydium accounts would be specified here
+        config: await this.getConfigAddress(mint),
+        bondingCurve: await this.getBondingCurveAddress(mint),
+        mint,
+        solReserve: await this.getSolReserveAddress(mint),
+        caller,
+      })
+      .instruction();
+  }
+
+  /**
+   * Build instruction to burn tokens for a pro rata share of the SOL reserve
+   */
+  async buildClaimRefundInstruction(
+    holder: PublicKey,
+    mint: PublicKey,
+    tokenAmount: BN
+  ): Promise<TransactionInstruction> {
+    const tokenProgram = await this.getTokenProgram(mint);
+
+    return this.program.methods
+      .claimRefund(tokenAmount)
+      .accounts({
+        config: await this.getConfigAddress(mint),
+        bondingCurve: await this.getBondingCurveAddress(mint),
+        mint,
+        solReserve: await this.getSolReserveAddress(mint),
+        holderTokenAccount: await getAssociatedTokenAddress(mint, holder, false, tokenProgram),
+        holder,
+        tokenProgram,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+  }
+
+  /**
+   * Build instruction for a fee key NFT holder to claim their share of the
+   * locked LP's Raydium CPMM trading fees
+   */
+  async buildClaimPoolFeesInstruction(
+    holder: PublicKey,
+    mint: PublicKey,
+    nftMint: PublicKey
+  ): Promise<TransactionInstruction> {
+    const pool = await this.fetchRaydiumPoolData(mint);
+    const raydiumPool = await this.getRaydiumPoolAddress(mint);
+    const tokenProgram = await this.getTokenProgram(mint);
+    const [poolAuthority] = await PublicKey.findProgramAddress(
+      [Buffer.from('vault_and_lp_mint_auth_seed')],
+      RAYDIUM_CPMM_PROGRAM_ID
+    );
+
+    return this.program.methods
+      .claimPoolFees()
+      .accounts({
+        raydiumPool,
+        feeKeyNft: await this.getFeeKeyNftAddress(raydiumPool, nftMint),
+        holderNftAccount: await getAssociatedTokenAddress(nftMint, holder),
+        holderTokenAccount: await getAssociatedTokenAddress(mint, holder, false, tokenProgram),
+        holderSolAccount: await getAssociatedTokenAddress(WRAPPED_SOL_MINT, holder),
+        holder,
+        mint,
+        wrappedSol: WRAPPED_SOL_MINT,
+        feeVaultToken: await this.getFeeVaultAddress(raydiumPool, mint),
+        feeVaultSol: await this.getFeeVaultAddress(raydiumPool, WRAPPED_SOL_MINT),
+        lpLock: await this.getLpLockAddress(raydiumPool),
+        poolAuthority,
+        poolState: pool.poolState,
+        tokenVault: pool.tokenAAccount,
+        solVault: pool.tokenBAccount,
+        lpMint: pool.lpMint,
+        raydiumProgram: RAYDIUM_CPMM_PROGRAM_ID,
+        tokenProgram,
+        splTokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+        memoProgram: MEMO_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .instruction();
+  }
+
+  /**
+   * Build instruction to split `shareBps` of a fee key into a new fee key NFT.
+   * The locked LP's fees are collected first, so the old key keeps what it earned.
+   * The new NFT mint keypair must sign the transaction.
+   */
+  async buildSplitFeeKeyInstruction(
+    holder: PublicKey,
+    mint: PublicKey,
+    nftMint: PublicKey,
+    newNftMint: PublicKey,
+    shareBps: BN
+  ): Promise<TransactionInstruction> {
+    const pool = await this.fetchRaydiumPoolData(mint);
+    const raydiumPool = await this.getRaydiumPoolAddress(mint);
+    const [poolAuthority] = await PublicKey.findProgramAddress(
+      [Buffer.from('vault_and_lp_mint_auth_seed')],
+      RAYDIUM_CPMM_PROGRAM_ID
+    );
+
+    return this.program.methods
+      .splitFeeKey(shareBps)
+      .accounts({
+        raydiumPool,
+        feeKeyNft: await this.getFeeKeyNftAddress(raydiumPool, nftMint),
+        holderNftAccount: await getAssociatedTokenAddress(nftMint, holder),
+        newNftMint,
+        newNftAccount: await getAssociatedTokenAddress(newNftMint, holder),
+        newFeeKeyNft: await this.getFeeKeyNftAddress(raydiumPool, newNftMint),
+        holder,
+        mint,
+        wrappedSol: WRAPPED_SOL_MINT,
+        feeVaultToken: await this.getFeeVaultAddress(raydiumPool, mint),
+        feeVaultSol: await this.getFeeVaultAddress(raydiumPool, WRAPPED_SOL_MINT),
+        lpLock: await this.getLpLockAddress(raydiumPool),
+        poolAuthority,
+        poolState: pool.poolState,
+        tokenVault: pool.tokenAAccount,
+        solVault: pool.tokenBAccount,
+        lpMint: pool.lpMint,
+        raydiumProgram: RAYDIUM_CPMM_PROGRAM_ID,
+        tokenProgram: await this.getTokenProgram(mint),
+        splTokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: TOKEN_2022_PROGRAM_ID,
+        memoProgram: MEMO_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
       })
       .instruction();
   }
@@ -512,49 +991,47 @@ export class YozoonClient {
   // --- EVENT SUBSCRIPTION METHODS ---
 
   /**
-   * Subscribe to token purchase events
+   * Subscribe to the program events of one name
    */
-  subscribeToTokenPurchaseEvents(callback: (event: any) => void) {
+  subscribeToEvents(eventName: string, callback: (event: any) => void) {
     const eventParser = new EventParser(this.program.programId, this.program.coder);
-    
+
     const listener = this.provider.connection.onLogs(
       this.program.programId,
       (logs) => {
         if (logs.err) return;
-        
+
         const events = eventParser.parseLogs(logs.logs);
         for (const event of events) {
-          if (event.name === 'TokenPurchaseEvent') {
+          if (event.name === eventName) {
             callback(event.data);
           }
         }
       }
     );
-    
+
     return listener;
   }
 
+  /**
+   * Subscribe to token purchase events
+   */
+  subscribeToTokenPurchaseEvents(callback: (event: any) => void) {
+    return this.subscribeToEvents('TokenPurchaseEvent', callback);
+  }
+
   /**
    * Subscribe to price calculation events
    */
   subscribeToPriceCalculatedEvents(callback: (event: any) => void) {
-    const eventParser = new EventParser(this.program.programId, this.program.coder);
-    
-    const listener = this.provider.connection.onLogs(
-      this.program.programId,
-      (logs) => {
-        if (logs.err) return;
-        
-        const events = eventParser.parseLogs(logs.logs);
-        for (const event of events) {
-          if (event.name === 'PriceCalculatedEvent') {
-            callback(event.data);
-          }
-        }
-      }
-    );
-    
-    return listener;
+    return this.subscribeToEvents('PriceCalculatedEvent', callback);
+  }
+
+  /**
+   * Subscribe to migration events
+   */
+  subscribeToMigrationEvents(callback: (event: any) => void) {
+    return this.subscribeToEvents('MigrationCompletedEvent', callback);
   }
 
   /**
@@ -563,4 +1040,4 @@ export class YozoonClient {
   async unsubscribe(listener: number) {
     await this.provider.connection.removeOnLogsListener(listener);
   }
-} 
\ No newline at end of file
+}
@@ -3,130 +3,179 @@ export type Yozoon = {
   "name": "yozoon",
   "instructions": [
     {
-      "name": "initializeMint",
+      "name": "initializeLaunchpad",
       "accounts": [
         {
-          "name": "admin",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "config",
+          "name": "launchpad",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "authority",
           "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "treasury",
-          "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "systemProgram",
+          "name": "program",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "programData",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "defaultReferralFee",
+          "name": "creationFee",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "initializeBondingCurve",
+      "name": "setCreationFee",
       "accounts": [
         {
-          "name": "admin",
+          "name": "launchpad",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "config",
+          "name": "authority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "creationFee",
+          "type": "u64"
         },
         {
-          "name": "bondingCurve",
+          "name": "feeRecipient",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "setLaunchCreators",
+      "accounts": [
+        {
+          "name": "launchpad",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "authority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "pricePoints",
+          "name": "creators",
           "type": {
-            "vec": {
-              "defined": "PricePoint"
-            }
+            "vec": "publicKey"
           }
         }
       ]
     },
     {
-      "name": "buyTokens",
+      "name": "transferLaunchpadAuthority",
       "accounts": [
         {
-          "name": "user",
+          "name": "launchpad",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "config",
+          "name": "authority",
           "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptLaunchpadAuthority",
+      "accounts": [
+        {
+          "name": "launchpad",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bondingCurve",
+          "name": "pendingAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelLaunchpadAuthorityTransfer",
+      "accounts": [
+        {
+          "name": "launchpad",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeMint",
+      "accounts": [
+        {
+          "name": "launchpad",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenAccount",
+          "name": "feeRecipient",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "treasury",
+          "name": "config",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "referrer",
+          "name": "mint",
           "isMut": true,
-          "isSigner": false,
-          "isOptional": true
+          "isSigner": true
         },
         {
-          "name": "referral",
+          "name": "metadata",
           "isMut": true,
-          "isSigner": false,
-          "isOptional": true
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
           "name": "systemProgram",
@@ -146,268 +195,365 @@ export type Yozoon = {
       ],
       "args": [
         {
-          "name": "solAmount",
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "symbol",
+          "type": "string"
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        },
+        {
+          "name": "decimals",
+          "type": "u8"
+        },
+        {
+          "name": "maxSupply",
           "type": "u64"
+        },
+        {
+          "name": "extensions",
+          "type": {
+            "defined": "MintExtensions"
+          }
         }
       ]
     },
     {
-      "name": "sellTokens",
+      "name": "updateMetadata",
       "accounts": [
-        {
-          "name": "user",
-          "isMut": true,
-          "isSigner": true
-        },
         {
           "name": "config",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bondingCurve",
+          "name": "metadata",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
-          "isMut": true,
-          "isSigner": false
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "userTokenAccount",
-          "isMut": true,
+          "name": "tokenMetadataProgram",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
         },
         {
-          "name": "treasury",
-          "isMut": true,
-          "isSigner": false
+          "name": "symbol",
+          "type": "string"
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "uri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "transferAdmin",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "tokenAmount",
-          "type": "u64"
+          "name": "newAdmin",
+          "type": "publicKey"
         }
       ]
     },
     {
-      "name": "createReferral",
+      "name": "acceptAdmin",
       "accounts": [
         {
-          "name": "referrer",
+          "name": "config",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "referral",
+          "name": "pendingAdmin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelAdminTransfer",
+      "accounts": [
+        {
+          "name": "config",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "config",
+          "name": "admin",
           "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPauseState",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "customFee",
-          "type": "u64"
+          "name": "pauseMask",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "updateReferralFee",
+      "name": "setTreasury",
       "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "admin",
           "isMut": false,
           "isSigner": true
-        },
+        }
+      ],
+      "args": [
+        {
+          "name": "newTreasury",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "setMigrationBounty",
+      "accounts": [
         {
           "name": "config",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "newDefaultFee",
+          "name": "newBounty",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "updateTreasury",
+      "name": "freezeParameters",
       "accounts": [
-        {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": true
-        },
         {
           "name": "config",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "newTreasury",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "freezeMask",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "transferAdmin",
+      "name": "setOracleFeeds",
       "accounts": [
-        {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": true
-        },
         {
           "name": "config",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "newAdmin",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "feeds",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
     },
     {
-      "name": "pauseProtocol",
+      "name": "renounceAdmin",
       "accounts": [
-        {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": true
-        },
         {
           "name": "config",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         }
       ],
       "args": []
     },
     {
-      "name": "unpauseProtocol",
+      "name": "migrateAccountLayout",
       "accounts": [
         {
-          "name": "admin",
+          "name": "config",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "config",
+          "name": "target",
           "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "migrateToRaydium",
-      "accounts": [
+        },
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
         {
           "name": "admin",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "config",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rekeyLegacyLaunch",
+      "accounts": [
+        {
+          "name": "launchpad",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "bondingCurve",
+          "name": "legacyConfig",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
-          "isMut": false,
+          "name": "legacyBondingCurve",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "treasury",
+          "name": "legacyPriceObservations",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "raydiumPoolId",
+          "name": "legacyAirdropLedger",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "poolAuthority",
-          "isMut": false,
+          "name": "legacySolReserve",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "poolTokenMint",
+          "name": "config",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenVault",
+          "name": "bondingCurve",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "solVault",
+          "name": "priceObservations",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "treasuryTokenAccount",
+          "name": "airdropLedger",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "feeKeyNft",
+          "name": "solReserve",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "raydiumProgram",
-          "isMut": false,
+          "name": "mint",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "metadata",
+          "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "caller",
+          "isMut": true,
+          "isSigner": true
+        },
         {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
@@ -415,250 +561,98 @@ export type Yozoon = {
       "args": []
     },
     {
-      "name": "createAirdrop",
+      "name": "rekeyLegacyReferral",
       "accounts": [
         {
-          "name": "admin",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "config",
+          "name": "launchpad",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
-          "isMut": false,
+          "name": "legacyReferral",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "referral",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "receiverTokenAccount",
+          "name": "user",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "Config",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "admin",
-            "type": "publicKey"
-          },
-          {
-            "name": "treasury",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "defaultReferralFee",
-            "type": "u64"
-          },
-          {
-            "name": "isPaused",
-            "type": "bool"
-          }
-        ]
-      }
-    },
-    {
-      "name": "BondingCurve",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "totalSoldSupply",
-            "type": "u64"
-          },
-          {
-            "name": "totalSolRaised",
-            "type": "u64"
-          },
-          {
-            "name": "pricePoints",
-            "type": {
-              "vec": {
-                "defined": "PricePoint"
-              }
-            }
-          },
-          {
-            "name": "isMigrated",
-            "type": "bool"
-          }
-        ]
-      }
-    },
-    {
-      "name": "Referral",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "referrer",
-            "type": "publicKey"
-          },
-          {
-            "name": "fee",
-            "type": "u64"
-          },
-          {
-            "name": "totalEarned",
-            "type": "u64"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "PricePoint",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "supply",
-            "type": "u64"
-          },
-          {
-            "name": "pricePerToken",
-            "type": "u64"
-          }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "InvalidPricePoints",
-      "msg": "Invalid price points, must have at least 2 points in ascending order"
-    },
-    {
-      "code": 6001,
-      "name": "AdminOnly",
-      "msg": "This action can only be performed by the admin"
-    },
-    {
-      "code": 6002,
-      "name": "ProtocolPaused",
-      "msg": "Protocol is currently paused"
-    },
-    {
-      "code": 6003,
-      "name": "MinimumPurchaseAmount",
-      "msg": "Purchase amount is below minimum threshold"
-    },
-    {
-      "code": 6004,
-      "name": "MinimumSaleAmount",
-      "msg": "Sale amount is below minimum threshold"
-    },
-    {
-      "code": 6005,
-      "name": "InvalidReferralFee",
-      "msg": "Referral fee exceeds maximum allowed value"
-    },
-    {
-      "code": 6006,
-      "name": "InsufficientReserve",
-      "msg": "Insufficient reserve balance for this transaction"
-    },
-    {
-      "code": 6007,
-      "name": "MigrationThresholdNotMet",
-      "msg": "Migration threshold has not been met yet"
+      "args": []
     },
     {
-      "code": 6008,
-      "name": "AlreadyMigrated",
-      "msg": "Bonding curve has already been migrated"
-    }
-  ]
-};
-
-export const IDL: Yozoon = {
-  "version": "0.1.0",
-  "name": "yozoon",
-  "instructions": [
-    {
-      "name": "initializeMint",
+      "name": "initializeBondingCurve",
       "accounts": [
-        {
-          "name": "admin",
-          "isMut": true,
-          "isSigner": true
-        },
         {
           "name": "config",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "bondingCurve",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "treasury",
-          "isMut": false,
+          "name": "priceObservations",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "solReserve",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "rent",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "defaultReferralFee",
-          "type": "u64"
+          "name": "pricePoints",
+          "type": {
+            "vec": "u64"
+          }
+        },
+        {
+          "name": "thresholds",
+          "type": {
+            "defined": "MigrationThresholds"
+          }
+        },
+        {
+          "name": "migrationTarget",
+          "type": {
+            "defined": "MigrationTargetKind"
+          }
+        },
+        {
+          "name": "launchDeadline",
+          "type": "i64"
         }
       ]
     },
     {
-      "name": "initializeBondingCurve",
+      "name": "setMigrationThresholds",
       "accounts": [
-        {
-          "name": "admin",
-          "isMut": true,
-          "isSigner": true
-        },
         {
           "name": "config",
           "isMut": false,
@@ -670,18 +664,16 @@ export const IDL: Yozoon = {
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "pricePoints",
+          "name": "thresholds",
           "type": {
-            "vec": {
-              "defined": "PricePoint"
-            }
+            "defined": "MigrationThresholds"
           }
         }
       ]
@@ -689,14 +681,9 @@ export const IDL: Yozoon = {
     {
       "name": "buyTokens",
       "accounts": [
-        {
-          "name": "user",
-          "isMut": true,
-          "isSigner": true
-        },
         {
           "name": "config",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -705,44 +692,55 @@ export const IDL: Yozoon = {
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "priceObservations",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenAccount",
+          "name": "mint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "treasury",
+          "name": "buyerTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "referrer",
+          "name": "buyer",
           "isMut": true,
-          "isSigner": false,
-          "isOptional": true
+          "isSigner": true
         },
         {
-          "name": "referral",
+          "name": "solReserve",
           "isMut": true,
-          "isSigner": false,
+          "isSigner": false
+        },
+        {
+          "name": "referral",
+          "isMut": false,
+          "isSigner": false,
           "isOptional": true
         },
         {
-          "name": "systemProgram",
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "solUsdPrice",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         }
@@ -755,38 +753,61 @@ export const IDL: Yozoon = {
       ]
     },
     {
-      "name": "sellTokens",
+      "name": "buyTokensUsd",
       "accounts": [
         {
-          "name": "user",
+          "name": "config",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "config",
-          "isMut": false,
+          "name": "bondingCurve",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bondingCurve",
+          "name": "priceObservations",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "mint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenAccount",
+          "name": "buyerTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "treasury",
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "solReserve",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "referral",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "solUsdPrice",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -800,26 +821,55 @@ export const IDL: Yozoon = {
       ],
       "args": [
         {
-          "name": "tokenAmount",
+          "name": "usdAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxSolIn",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "createReferral",
+      "name": "sellTokens",
       "accounts": [
         {
-          "name": "referrer",
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "referral",
+          "name": "sellerTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "config",
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "priceObservations",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "solReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -831,47 +881,80 @@ export const IDL: Yozoon = {
       ],
       "args": [
         {
-          "name": "customFee",
+          "name": "tokenAmount",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "updateReferralFee",
+      "name": "calculateCurrentPrice",
       "accounts": [
         {
-          "name": "admin",
+          "name": "config",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "u64"
+    },
+    {
+      "name": "calculateTokensForSol",
+      "accounts": [
         {
           "name": "config",
-          "isMut": true,
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "newDefaultFee",
+          "name": "solAmount",
           "type": "u64"
         }
-      ]
+      ],
+      "returns": "u64"
     },
     {
-      "name": "updateTreasury",
+      "name": "initializePriceObservations",
       "accounts": [
         {
-          "name": "admin",
+          "name": "config",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "config",
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceObservations",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "newTreasury",
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
@@ -879,327 +962,6020 @@ export const IDL: Yozoon = {
       "args": []
     },
     {
-      "name": "transferAdmin",
+      "name": "getTwap",
       "accounts": [
         {
-          "name": "admin",
+          "name": "config",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "config",
-          "isMut": true,
+          "name": "bondingCurve",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "newAdmin",
+          "name": "priceObservations",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "windowSecs",
+          "type": "u32"
+        }
+      ],
+      "returns": "u64"
     },
     {
-      "name": "pauseProtocol",
+      "name": "setReferral",
       "accounts": [
         {
-          "name": "admin",
+          "name": "config",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "config",
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "referrer",
+          "type": "publicKey"
+        }
+      ]
     },
     {
-      "name": "unpauseProtocol",
+      "name": "updateReferralFee",
       "accounts": [
         {
-          "name": "admin",
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "config",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "newFeePercentage",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "migrateToRaydium",
+      "name": "airdropTokens",
       "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "admin",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "config",
+          "name": "bondingCurve",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bondingCurve",
+          "name": "airdropLedger",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
-          "isMut": false,
+          "name": "mint",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "treasury",
+          "name": "recipientTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "raydiumPoolId",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "poolAuthority",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "poolTokenMint",
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migrate",
+      "accounts": [
+        {
+          "name": "config",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenVault",
+          "name": "bondingCurve",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "solVault",
+          "name": "mint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "treasuryTokenAccount",
-          "isMut": true,
+          "name": "wrappedSol",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "feeKeyNft",
+          "name": "solReserve",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "raydiumProgram",
-          "isMut": false,
+          "name": "poolCreator",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "tokenAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
+          "name": "solTokenAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "createAirdrop",
-      "accounts": [
-        {
-          "name": "admin",
+          "name": "nftMint",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "config",
+          "name": "treasury",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
-          "isMut": false,
+          "name": "treasuryNftAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "raydiumPool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "receiverTokenAccount",
+          "name": "lpLock",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
+          "name": "feeKeyNft",
+          "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "amount",
+          "name": "caller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "solUsdPrice",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "dexProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lpLockMode",
+          "type": {
+            "defined": "LpLockMode"
+          }
+        }
+      ]
+    },
+    {
+      "name": "finalizeMigration",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "solReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "caller",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "thawTokenAccount",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "checkAutoMigration",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "solUsdPrice",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "enterRefundMode",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "solReserve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "caller",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimRefund",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "solReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenAmount",
           "type": "u64"
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
+    {
+      "name": "claimPoolFees",
+      "accounts": [
+        {
+          "name": "raydiumPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeKeyNft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holderNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "holderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holderSolAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrappedSol",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultToken",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultSol",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpLock",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "solVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "raydiumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "token2022Program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memoProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "splitFeeKey",
+      "accounts": [
+        {
+          "name": "raydiumPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeKeyNft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holderNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newNftMint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "newNftAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newFeeKeyNft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrappedSol",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultToken",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultSol",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpLock",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "solVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "raydiumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "token2022Program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memoProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "shareBps",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "RaydiumPool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenAMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenBMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenAAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenBAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "lpMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "poolState",
+            "type": "publicKey"
+          },
+          {
+            "name": "initTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "isInitialized",
+            "type": "bool"
+          },
+          {
+            "name": "lpAmount",
+            "type": "u64"
+          },
+          {
+            "name": "lpLockMode",
+            "type": {
+              "defined": "LpLockMode"
+            }
+          },
+          {
+            "name": "lpLock",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalFeesToken",
+            "type": "u64"
+          },
+          {
+            "name": "totalFeesSol",
+            "type": "u64"
+          },
+          {
+            "name": "target",
+            "type": {
+              "defined": "MigrationTargetKind"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeKeyNft",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "feePercentage",
+            "type": "u64"
+          },
+          {
+            "name": "lastClaimed",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "feesCheckpointToken",
+            "type": "u64"
+          },
+          {
+            "name": "feesCheckpointSol",
+            "type": "u64"
+          },
+          {
+            "name": "unclaimedToken",
+            "type": "u64"
+          },
+          {
+            "name": "unclaimedSol",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pauseFlags",
+            "type": "u8"
+          },
+          {
+            "name": "treasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "pendingAdminDeadline",
+            "type": "i64"
+          },
+          {
+            "name": "totalValue",
+            "type": "u64"
+          },
+          {
+            "name": "totalSupply",
+            "type": "u64"
+          },
+          {
+            "name": "frozenParams",
+            "type": "u8"
+          },
+          {
+            "name": "migrationBounty",
+            "type": "u64"
+          },
+          {
+            "name": "oracleFeeds",
+            "type": {
+              "array": [
+                "publicKey",
+                3
+              ]
+            }
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          },
+          {
+            "name": "maxSupply",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                15
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Launchpad",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeRecipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "creationFee",
+            "type": "u64"
+          },
+          {
+            "name": "creators",
+            "type": {
+              "array": [
+                "publicKey",
+                32
+              ]
+            }
+          },
+          {
+            "name": "launchCount",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "legacyMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BondingCurve",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "totalSolRaised",
+            "type": "u64"
+          },
+          {
+            "name": "totalSoldSupply",
+            "type": "u64"
+          },
+          {
+            "name": "pricePoints",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "isMigrated",
+            "type": "bool"
+          },
+          {
+            "name": "thresholds",
+            "type": {
+              "defined": "MigrationThresholds"
+            }
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "CurveState"
+            }
+          },
+          {
+            "name": "migrationTarget",
+            "type": {
+              "defined": "MigrationTargetKind"
+            }
+          },
+          {
+            "name": "launchDeadline",
+            "type": "i64"
+          },
+          {
+            "name": "readySince",
+            "type": "i64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                14
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Referral",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "referrer",
+            "type": "publicKey"
+          },
+          {
+            "name": "feePercentage",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AirdropLedger",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "totalAirdropped",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceObservations",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "cumulativePrice",
+            "type": "u128"
+          },
+          {
+            "name": "lastTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "head",
+            "type": "u16"
+          },
+          {
+            "name": "count",
+            "type": "u16"
+          },
+          {
+            "name": "observations",
+            "type": {
+              "array": [
+                {
+                  "defined": "Observation"
+                },
+                64
+              ]
+            }
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "MigrationThresholds",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "solMin",
+            "type": "u64"
+          },
+          {
+            "name": "solMax",
+            "type": "u64"
+          },
+          {
+            "name": "supplyThreshold",
+            "type": "u64"
+          },
+          {
+            "name": "usdMin",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintExtensions",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "transferFee",
+            "type": {
+              "option": {
+                "defined": "TransferFeeParams"
+              }
+            }
+          },
+          {
+            "name": "metadataPointer",
+            "type": "bool"
+          },
+          {
+            "name": "nonTransferableUntilMigration",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferFeeParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "basisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "maximumFee",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Observation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "cumulativePrice",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MigrationTargetKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "RaydiumCpmm"
+          },
+          {
+            "name": "OrcaWhirlpool"
+          },
+          {
+            "name": "MeteoraDamm"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LpLockMode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Burn"
+          },
+          {
+            "name": "Lock"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CurveState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Trading"
+          },
+          {
+            "name": "ReadyToMigrate"
+          },
+          {
+            "name": "Migrated"
+          },
+          {
+            "name": "Refunding"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "TokenPurchaseEvent",
+      "fields": [
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "netSol",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokens",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "usdPrice",
+          "type": {
+            "option": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "refundedSol",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PriceCalculatedEvent",
+      "fields": [
+        {
+          "name": "supply",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenCalculationEvent",
+      "fields": [
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "netSol",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokens",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReferralCreatedEvent",
+      "fields": [
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "feePercentage",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AirdropEvent",
+      "fields": [
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigrationCompletedEvent",
+      "fields": [
+        {
+          "name": "target",
+          "type": {
+            "defined": "MigrationTargetKind"
+          },
+          "index": false
+        },
+        {
+          "name": "poolState",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "solValue",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokensSold",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "caller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bounty",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigrationFinalizedEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "treasury",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "reclaimedLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "caller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LiquidityLockedEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "poolState",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lpMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lpAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "mode",
+          "type": {
+            "defined": "LpLockMode"
+          },
+          "index": false
+        },
+        {
+          "name": "lpLock",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PoolFeesClaimedEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "feeKeyMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "holder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "shareBps",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FeeKeySplitEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "parentMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "parentShareBps",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "newMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newShareBps",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "holder",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigrationBountyUpdatedEvent",
+      "fields": [
+        {
+          "name": "oldBounty",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "newBounty",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigrationThresholdsUpdatedEvent",
+      "fields": [
+        {
+          "name": "oldThresholds",
+          "type": {
+            "defined": "MigrationThresholds"
+          },
+          "index": false
+        },
+        {
+          "name": "newThresholds",
+          "type": {
+            "defined": "MigrationThresholds"
+          },
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CurveReadyToMigrateEvent",
+      "fields": [
+        {
+          "name": "totalSol",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalSupply",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RefundModeEnteredEvent",
+      "fields": [
+        {
+          "name": "caller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "byAdmin",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "reserve",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokenSupply",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RefundClaimedEvent",
+      "fields": [
+        {
+          "name": "holder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AdminTransferInitiatedEvent",
+      "fields": [
+        {
+          "name": "currentAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposedAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AdminTransferCancelledEvent",
+      "fields": [
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "cancelledAdmin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AdminTransferCompletedEvent",
+      "fields": [
+        {
+          "name": "previousAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newAdmin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PauseStateChangedEvent",
+      "fields": [
+        {
+          "name": "pauseFlags",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TreasuryUpdatedEvent",
+      "fields": [
+        {
+          "name": "oldTreasury",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newTreasury",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OracleFeedsUpdatedEvent",
+      "fields": [
+        {
+          "name": "feeds",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MetadataUpdatedEvent",
+      "fields": [
+        {
+          "name": "name",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "symbol",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "uri",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ParametersFrozenEvent",
+      "fields": [
+        {
+          "name": "newlyFrozen",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "frozenParams",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AdminRenouncedEvent",
+      "fields": [
+        {
+          "name": "formerAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "frozenParams",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AccountLayoutMigratedEvent",
+      "fields": [
+        {
+          "name": "account",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "fromVersion",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "toVersion",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "dataLen",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MintInitializedEvent",
+      "fields": [
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AdminTransferredEvent",
+      "fields": [
+        {
+          "name": "oldAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AdminAcceptedEvent",
+      "fields": [
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BondingCurveInitializedEvent",
+      "fields": [
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pricePoints",
+          "type": {
+            "vec": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokensPurchasedEvent",
+      "fields": [
+        {
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReferralSetEvent",
+      "fields": [
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReferralFeeUpdatedEvent",
+      "fields": [
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oldFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "newFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigrationEvent",
+      "fields": [
+        {
+          "name": "totalSol",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalUsd",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalSupply",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenSaleEvent",
+      "fields": [
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LaunchCreatedEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creationFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "launchCount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LegacyLaunchRekeyedEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "legacyConfig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "config",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "reserveLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "caller",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LaunchpadUpdatedEvent",
+      "fields": [
+        {
+          "name": "creationFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeRecipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creators",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LaunchpadAuthorityTransferInitiatedEvent",
+      "fields": [
+        {
+          "name": "currentAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposedAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LaunchpadAuthorityTransferCancelledEvent",
+      "fields": [
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "cancelledAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LaunchpadAuthorityTransferCompletedEvent",
+      "fields": [
+        {
+          "name": "previousAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigrationReadyEvent",
+      "fields": [
+        {
+          "name": "totalSol",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalUsd",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalSupply",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "Unauthorized",
+      "msg": "Unauthorized"
+    },
+    {
+      "code": 6001,
+      "name": "ProtocolPaused",
+      "msg": "Protocol is paused"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidParameter",
+      "msg": "Invalid parameter"
+    },
+    {
+      "code": 6003,
+      "name": "TooManyPricePoints",
+      "msg": "Too many price points"
+    },
+    {
+      "code": 6004,
+      "name": "AmountTooSmall",
+      "msg": "Amount too small"
+    },
+    {
+      "code": 6005,
+      "name": "Migrated",
+      "msg": "Protocol has been migrated"
+    },
+    {
+      "code": 6006,
+      "name": "DustAmount",
+      "msg": "Dust amount"
+    },
+    {
+      "code": 6007,
+      "name": "SupplyExceeded",
+      "msg": "Supply exceeded"
+    },
+    {
+      "code": 6008,
+      "name": "SelfReferral",
+      "msg": "Self referral not allowed"
+    },
+    {
+      "code": 6009,
+      "name": "FeeTooHigh",
+      "msg": "Referral fee too high"
+    },
+    {
+      "code": 6010,
+      "name": "InsufficientReserve",
+      "msg": "Insufficient reserve"
+    },
+    {
+      "code": 6011,
+      "name": "MigrationThresholdNotReached",
+      "msg": "Migration threshold not reached"
+    },
+    {
+      "code": 6012,
+      "name": "AlreadyMigrated",
+      "msg": "Protocol already migrated to Raydium"
+    },
+    {
+      "code": 6013,
+      "name": "RaydiumPoolCreationFailed",
+      "msg": "Failed to create Raydium pool"
+    },
+    {
+      "code": 6014,
+      "name": "NftFeeKeyCreationFailed",
+      "msg": "Failed to create NFT fee key"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidMigrationAmount",
+      "msg": "Invalid SOL amount for migration"
+    },
+    {
+      "code": 6016,
+      "name": "LiquidityLockFailed",
+      "msg": "Raydium liquidity lock failed"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidTreasury",
+      "msg": "Treasury account does not match config"
+    },
+    {
+      "code": 6018,
+      "name": "ParametersFrozen",
+      "msg": "Parameter is permanently frozen"
+    },
+    {
+      "code": 6019,
+      "name": "NoPendingAdmin",
+      "msg": "No admin transfer is pending"
+    },
+    {
+      "code": 6020,
+      "name": "AdminTransferExpired",
+      "msg": "Admin transfer nomination has expired"
+    },
+    {
+      "code": 6021,
+      "name": "UnsupportedAccountVersion",
+      "msg": "Unsupported account layout version"
+    },
+    {
+      "code": 6022,
+      "name": "AccountLayoutCurrent",
+      "msg": "Account layout is already current"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidPoolAccount",
+      "msg": "Pool account does not match the derived address"
+    },
+    {
+      "code": 6024,
+      "name": "MathOverflow",
+      "msg": "Arithmetic overflow"
+    },
+    {
+      "code": 6025,
+      "name": "LiquidityDepositMismatch",
+      "msg": "Pool deposit does not match the curve reserves"
+    },
+    {
+      "code": 6026,
+      "name": "MigrationPending",
+      "msg": "Trading is frozen until the curve migrates"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidOracleAccount",
+      "msg": "Oracle account is not a valid SOL/USD price feed"
+    },
+    {
+      "code": 6028,
+      "name": "StalePrice",
+      "msg": "Oracle price is stale"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidOraclePrice",
+      "msg": "Oracle price is not positive"
+    },
+    {
+      "code": 6030,
+      "name": "PriceConfidenceTooLow",
+      "msg": "Oracle price confidence interval is too wide"
+    },
+    {
+      "code": 6031,
+      "name": "MigrationUsdThresholdNotReached",
+      "msg": "Migration USD threshold not reached"
+    },
+    {
+      "code": 6032,
+      "name": "NotFeeKeyHolder",
+      "msg": "Signer does not hold the fee key NFT"
+    },
+    {
+      "code": 6033,
+      "name": "LpNotLocked",
+      "msg": "Pool LP tokens were burned, so there are no fees to claim"
+    },
+    {
+      "code": 6034,
+      "name": "UnsupportedMigrationTarget",
+      "msg": "Operation is not supported on this migration target"
+    },
+    {
+      "code": 6035,
+      "name": "RefundModeActive",
+      "msg": "Curve is in refund mode"
+    },
+    {
+      "code": 6036,
+      "name": "RefundModeNotActive",
+      "msg": "Curve is not in refund mode"
+    },
+    {
+      "code": 6037,
+      "name": "RefundNotAvailable",
+      "msg": "Refund mode needs a passed launch deadline, or the admin while migration is pending"
+    },
+    {
+      "code": 6038,
+      "name": "NotMigrated",
+      "msg": "Curve has not migrated yet"
+    },
+    {
+      "code": 6039,
+      "name": "MintAuthorityRevoked",
+      "msg": "Mint authority has been revoked"
+    },
+    {
+      "code": 6040,
+      "name": "InsufficientObservations",
+      "msg": "Not enough price history for the requested window"
+    },
+    {
+      "code": 6041,
+      "name": "SlippageExceeded",
+      "msg": "Buy would cost more SOL than the given limit"
+    },
+    {
+      "code": 6042,
+      "name": "FuturePrice",
+      "msg": "Oracle price is dated in the future"
+    },
+    {
+      "code": 6043,
+      "name": "OracleFeedNotAllowed",
+      "msg": "Oracle feed is not on the allowlist"
+    },
+    {
+      "code": 6044,
+      "name": "MetadataTooLong",
+      "msg": "Token name, symbol or URI is too long"
+    },
+    {
+      "code": 6045,
+      "name": "Token2022Required",
+      "msg": "Mint extensions need the Token-2022 program"
+    },
+    {
+      "code": 6046,
+      "name": "CreatorNotAllowed",
+      "msg": "Signer is not on the launchpad's creator allowlist"
+    },
+    {
+      "code": 6047,
+      "name": "SellExceedsSoldSupply",
+      "msg": "Sell exceeds the supply sold through the curve"
+    },
+    {
+      "code": 6048,
+      "name": "ReferrerMismatch",
+      "msg": "Referrer does not match the referral"
+    },
+    {
+      "code": 6049,
+      "name": "PausedOnRenounce",
+      "msg": "Unpause every operation before renouncing admin"
+    },
+    {
+      "code": 6050,
+      "name": "RefundExceedsSoldSupply",
+      "msg": "Refund exceeds the supply sold through the curve"
+    },
+    {
+      "code": 6051,
+      "name": "NoPendingAuthority",
+      "msg": "No launchpad authority transfer is pending"
+    }
+  ]
+};
+
+export const IDL: Yozoon = {
+  "version": "0.1.0",
+  "name": "yozoon",
+  "instructions": [
+    {
+      "name": "initializeLaunchpad",
+      "accounts": [
+        {
+          "name": "launchpad",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "creationFee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setCreationFee",
+      "accounts": [
+        {
+          "name": "launchpad",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "creationFee",
+          "type": "u64"
+        },
+        {
+          "name": "feeRecipient",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "setLaunchCreators",
+      "accounts": [
+        {
+          "name": "launchpad",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "creators",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLaunchpadAuthority",
+      "accounts": [
+        {
+          "name": "launchpad",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptLaunchpadAuthority",
+      "accounts": [
+        {
+          "name": "launchpad",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelLaunchpadAuthorityTransfer",
+      "accounts": [
+        {
+          "name": "launchpad",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeMint",
+      "accounts": [
+        {
+          "name": "launchpad",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "symbol",
+          "type": "string"
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        },
+        {
+          "name": "decimals",
+          "type": "u8"
+        },
+        {
+          "name": "maxSupply",
+          "type": "u64"
+        },
+        {
+          "name": "extensions",
+          "type": {
+            "defined": "MintExtensions"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateMetadata",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "symbol",
+          "type": "string"
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "transferAdmin",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptAdmin",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAdmin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelAdminTransfer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPauseState",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "pauseMask",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setTreasury",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newTreasury",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "setMigrationBounty",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newBounty",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "freezeParameters",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "freezeMask",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setOracleFeeds",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "feeds",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "renounceAdmin",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateAccountLayout",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "target",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rekeyLegacyLaunch",
+      "accounts": [
+        {
+          "name": "launchpad",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "legacyConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "legacyBondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "legacyPriceObservations",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "legacyAirdropLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "legacySolReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "priceObservations",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "airdropLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "solReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "caller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rekeyLegacyReferral",
+      "accounts": [
+        {
+          "name": "launchpad",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "legacyReferral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeBondingCurve",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "priceObservations",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "solReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "pricePoints",
+          "type": {
+            "vec": "u64"
+          }
+        },
+        {
+          "name": "thresholds",
+          "type": {
+            "defined": "MigrationThresholds"
+          }
+        },
+        {
+          "name": "migrationTarget",
+          "type": {
+            "defined": "MigrationTargetKind"
+          }
+        },
+        {
+          "name": "launchDeadline",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setMigrationThresholds",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "thresholds",
+          "type": {
+            "defined": "MigrationThresholds"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyTokens",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "priceObservations",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "solReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referral",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "solUsdPrice",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "solAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "buyTokensUsd",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "priceObservations",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "solReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referral",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "solUsdPrice",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "usdAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxSolIn",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "sellTokens",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sellerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "priceObservations",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "solReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "calculateCurrentPrice",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "u64"
+    },
+    {
+      "name": "calculateTokensForSol",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "solAmount",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "initializePriceObservations",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceObservations",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "getTwap",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceObservations",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "windowSecs",
+          "type": "u32"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "setReferral",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "referrer",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "updateReferralFee",
+      "accounts": [
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newFeePercentage",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "airdropTokens",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "airdropLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migrate",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "wrappedSol",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "solReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolCreator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "solTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "treasury",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryNftAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "raydiumPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpLock",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeKeyNft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "caller",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "solUsdPrice",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "dexProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lpLockMode",
+          "type": {
+            "defined": "LpLockMode"
+          }
+        }
+      ]
+    },
+    {
+      "name": "finalizeMigration",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "solReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "caller",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "thawTokenAccount",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "checkAutoMigration",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "solUsdPrice",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "enterRefundMode",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "solReserve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "caller",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimRefund",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "solReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "claimPoolFees",
+      "accounts": [
+        {
+          "name": "raydiumPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeKeyNft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holderNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "holderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holderSolAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrappedSol",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultToken",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultSol",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpLock",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "solVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "raydiumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "token2022Program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memoProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "splitFeeKey",
+      "accounts": [
+        {
+          "name": "raydiumPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeKeyNft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holderNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newNftMint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "newNftAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newFeeKeyNft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrappedSol",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultToken",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultSol",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpLock",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "solVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "raydiumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "splTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "token2022Program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memoProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "shareBps",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "RaydiumPool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenAMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenBMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenAAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenBAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "lpMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "poolState",
+            "type": "publicKey"
+          },
+          {
+            "name": "initTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "isInitialized",
+            "type": "bool"
+          },
+          {
+            "name": "lpAmount",
+            "type": "u64"
+          },
+          {
+            "name": "lpLockMode",
+            "type": {
+              "defined": "LpLockMode"
+            }
+          },
+          {
+            "name": "lpLock",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalFeesToken",
+            "type": "u64"
+          },
+          {
+            "name": "totalFeesSol",
+            "type": "u64"
+          },
+          {
+            "name": "target",
+            "type": {
+              "defined": "MigrationTargetKind"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeKeyNft",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "feePercentage",
+            "type": "u64"
+          },
+          {
+            "name": "lastClaimed",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "feesCheckpointToken",
+            "type": "u64"
+          },
+          {
+            "name": "feesCheckpointSol",
+            "type": "u64"
+          },
+          {
+            "name": "unclaimedToken",
+            "type": "u64"
+          },
+          {
+            "name": "unclaimedSol",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pauseFlags",
+            "type": "u8"
+          },
+          {
+            "name": "treasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "pendingAdminDeadline",
+            "type": "i64"
+          },
+          {
+            "name": "totalValue",
+            "type": "u64"
+          },
+          {
+            "name": "totalSupply",
+            "type": "u64"
+          },
+          {
+            "name": "frozenParams",
+            "type": "u8"
+          },
+          {
+            "name": "migrationBounty",
+            "type": "u64"
+          },
+          {
+            "name": "oracleFeeds",
+            "type": {
+              "array": [
+                "publicKey",
+                3
+              ]
+            }
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          },
+          {
+            "name": "maxSupply",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                15
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Launchpad",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeRecipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "creationFee",
+            "type": "u64"
+          },
+          {
+            "name": "creators",
+            "type": {
+              "array": [
+                "publicKey",
+                32
+              ]
+            }
+          },
+          {
+            "name": "launchCount",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "legacyMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BondingCurve",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "totalSolRaised",
+            "type": "u64"
+          },
+          {
+            "name": "totalSoldSupply",
+            "type": "u64"
+          },
+          {
+            "name": "pricePoints",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "isMigrated",
+            "type": "bool"
+          },
+          {
+            "name": "thresholds",
+            "type": {
+              "defined": "MigrationThresholds"
+            }
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "CurveState"
+            }
+          },
+          {
+            "name": "migrationTarget",
+            "type": {
+              "defined": "MigrationTargetKind"
+            }
+          },
+          {
+            "name": "launchDeadline",
+            "type": "i64"
+          },
+          {
+            "name": "readySince",
+            "type": "i64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                14
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Referral",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "referrer",
+            "type": "publicKey"
+          },
+          {
+            "name": "feePercentage",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AirdropLedger",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "totalAirdropped",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceObservations",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "cumulativePrice",
+            "type": "u128"
+          },
+          {
+            "name": "lastTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "head",
+            "type": "u16"
+          },
+          {
+            "name": "count",
+            "type": "u16"
+          },
+          {
+            "name": "observations",
+            "type": {
+              "array": [
+                {
+                  "defined": "Observation"
+                },
+                64
+              ]
+            }
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "MigrationThresholds",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "solMin",
+            "type": "u64"
+          },
+          {
+            "name": "solMax",
+            "type": "u64"
+          },
+          {
+            "name": "supplyThreshold",
+            "type": "u64"
+          },
+          {
+            "name": "usdMin",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintExtensions",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "transferFee",
+            "type": {
+              "option": {
+                "defined": "TransferFeeParams"
+              }
+            }
+          },
+          {
+            "name": "metadataPointer",
+            "type": "bool"
+          },
+          {
+            "name": "nonTransferableUntilMigration",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferFeeParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "basisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "maximumFee",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Observation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "cumulativePrice",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MigrationTargetKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "RaydiumCpmm"
+          },
+          {
+            "name": "OrcaWhirlpool"
+          },
+          {
+            "name": "MeteoraDamm"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LpLockMode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Burn"
+          },
+          {
+            "name": "Lock"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CurveState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Trading"
+          },
+          {
+            "name": "ReadyToMigrate"
+          },
+          {
+            "name": "Migrated"
+          },
+          {
+            "name": "Refunding"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "TokenPurchaseEvent",
+      "fields": [
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "netSol",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokens",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "usdPrice",
+          "type": {
+            "option": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "refundedSol",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PriceCalculatedEvent",
+      "fields": [
+        {
+          "name": "supply",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenCalculationEvent",
+      "fields": [
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "netSol",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokens",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReferralCreatedEvent",
+      "fields": [
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "feePercentage",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AirdropEvent",
+      "fields": [
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigrationCompletedEvent",
+      "fields": [
+        {
+          "name": "target",
+          "type": {
+            "defined": "MigrationTargetKind"
+          },
+          "index": false
+        },
+        {
+          "name": "poolState",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "solValue",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokensSold",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "caller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bounty",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigrationFinalizedEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "treasury",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "reclaimedLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "caller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LiquidityLockedEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "poolState",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lpMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lpAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "mode",
+          "type": {
+            "defined": "LpLockMode"
+          },
+          "index": false
+        },
+        {
+          "name": "lpLock",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PoolFeesClaimedEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "feeKeyMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "holder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "shareBps",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FeeKeySplitEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "parentMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "parentShareBps",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "newMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newShareBps",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "holder",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigrationBountyUpdatedEvent",
+      "fields": [
+        {
+          "name": "oldBounty",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "newBounty",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigrationThresholdsUpdatedEvent",
+      "fields": [
+        {
+          "name": "oldThresholds",
+          "type": {
+            "defined": "MigrationThresholds"
+          },
+          "index": false
+        },
+        {
+          "name": "newThresholds",
+          "type": {
+            "defined": "MigrationThresholds"
+          },
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CurveReadyToMigrateEvent",
+      "fields": [
+        {
+          "name": "totalSol",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalSupply",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RefundModeEnteredEvent",
+      "fields": [
+        {
+          "name": "caller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "byAdmin",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "reserve",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokenSupply",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RefundClaimedEvent",
+      "fields": [
+        {
+          "name": "holder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AdminTransferInitiatedEvent",
+      "fields": [
+        {
+          "name": "currentAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposedAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AdminTransferCancelledEvent",
+      "fields": [
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "cancelledAdmin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AdminTransferCompletedEvent",
+      "fields": [
+        {
+          "name": "previousAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newAdmin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PauseStateChangedEvent",
+      "fields": [
+        {
+          "name": "pauseFlags",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TreasuryUpdatedEvent",
+      "fields": [
+        {
+          "name": "oldTreasury",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newTreasury",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OracleFeedsUpdatedEvent",
+      "fields": [
+        {
+          "name": "feeds",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MetadataUpdatedEvent",
+      "fields": [
+        {
+          "name": "name",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "symbol",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "uri",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ParametersFrozenEvent",
+      "fields": [
+        {
+          "name": "newlyFrozen",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "frozenParams",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AdminRenouncedEvent",
+      "fields": [
+        {
+          "name": "formerAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "frozenParams",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AccountLayoutMigratedEvent",
+      "fields": [
+        {
+          "name": "account",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "fromVersion",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "toVersion",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "dataLen",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MintInitializedEvent",
+      "fields": [
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AdminTransferredEvent",
+      "fields": [
+        {
+          "name": "oldAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AdminAcceptedEvent",
+      "fields": [
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BondingCurveInitializedEvent",
+      "fields": [
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pricePoints",
+          "type": {
+            "vec": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokensPurchasedEvent",
+      "fields": [
+        {
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReferralSetEvent",
+      "fields": [
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReferralFeeUpdatedEvent",
+      "fields": [
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oldFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "newFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigrationEvent",
+      "fields": [
+        {
+          "name": "totalSol",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalUsd",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalSupply",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
     {
-      "name": "Config",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "admin",
-            "type": "publicKey"
-          },
-          {
-            "name": "treasury",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "defaultReferralFee",
-            "type": "u64"
-          },
-          {
-            "name": "isPaused",
-            "type": "bool"
-          }
-        ]
-      }
+      "name": "TokenSaleEvent",
+      "fields": [
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "BondingCurve",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "totalSoldSupply",
-            "type": "u64"
-          },
-          {
-            "name": "totalSolRaised",
-            "type": "u64"
-          },
-          {
-            "name": "pricePoints",
-            "type": {
-              "vec": {
-                "defined": "PricePoint"
-              }
-            }
-          },
-          {
-            "name": "isMigrated",
-            "type": "bool"
-          }
-        ]
-      }
+      "name": "LaunchCreatedEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creationFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "launchCount",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "Referral",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "referrer",
-            "type": "publicKey"
-          },
-          {
-            "name": "fee",
-            "type": "u64"
-          },
-          {
-            "name": "totalEarned",
-            "type": "u64"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
+      "name": "LegacyLaunchRekeyedEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "legacyConfig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "config",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "reserveLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "caller",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
     {
-      "name": "PricePoint",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "supply",
-            "type": "u64"
+      "name": "LaunchpadUpdatedEvent",
+      "fields": [
+        {
+          "name": "creationFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeRecipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creators",
+          "type": {
+            "vec": "publicKey"
           },
-          {
-            "name": "pricePerToken",
-            "type": "u64"
-          }
-        ]
-      }
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LaunchpadAuthorityTransferInitiatedEvent",
+      "fields": [
+        {
+          "name": "currentAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposedAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LaunchpadAuthorityTransferCancelledEvent",
+      "fields": [
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "cancelledAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LaunchpadAuthorityTransferCompletedEvent",
+      "fields": [
+        {
+          "name": "previousAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigrationReadyEvent",
+      "fields": [
+        {
+          "name": "totalSol",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalUsd",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalSupply",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
     }
   ],
   "errors": [
     {
       "code": 6000,
-      "name": "InvalidPricePoints",
-      "msg": "Invalid price points, must have at least 2 points in ascending order"
+      "name": "Unauthorized",
+      "msg": "Unauthorized"
     },
     {
       "code": 6001,
-      "name": "AdminOnly",
-      "msg": "This action can only be performed by the admin"
+      "name": "ProtocolPaused",
+      "msg": "Protocol is paused"
     },
     {
       "code": 6002,
-      "name": "ProtocolPaused",
-      "msg": "Protocol is currently paused"
+      "name": "InvalidParameter",
+      "msg": "Invalid parameter"
     },
     {
       "code": 6003,
-      "name": "MinimumPurchaseAmount",
-      "msg": "Purchase amount is below minimum threshold"
+      "name": "TooManyPricePoints",
+      "msg": "Too many price points"
     },
     {
       "code": 6004,
-      "name": "MinimumSaleAmount",
-      "msg": "Sale amount is below minimum threshold"
+      "name": "AmountTooSmall",
+      "msg": "Amount too small"
     },
     {
       "code": 6005,
-      "name": "InvalidReferralFee",
-      "msg": "Referral fee exceeds maximum allowed value"
+      "name": "Migrated",
+      "msg": "Protocol has been migrated"
     },
     {
       "code": 6006,
-      "name": "InsufficientReserve",
-      "msg": "Insufficient reserve balance for this transaction"
+      "name": "DustAmount",
+      "msg": "Dust amount"
     },
     {
       "code": 6007,
-      "name": "MigrationThresholdNotMet",
-      "msg": "Migration threshold has not been met yet"
+      "name": "SupplyExceeded",
+      "msg": "Supply exceeded"
     },
     {
       "code": 6008,
+      "name": "SelfReferral",
+      "msg": "Self referral not allowed"
+    },
+    {
+      "code": 6009,
+      "name": "FeeTooHigh",
+      "msg": "Referral fee too high"
+    },
+    {
+      "code": 6010,
+      "name": "InsufficientReserve",
+      "msg": "Insufficient reserve"
+    },
+    {
+      "code": 6011,
+      "name": "MigrationThresholdNotReached",
+      "msg": "Migration threshold not reached"
+    },
+    {
+      "code": 6012,
       "name": "AlreadyMigrated",
-      "msg": "Bonding curve has already been migrated"
+      "msg": "Protocol already migrated to Raydium"
+    },
+    {
+      "code": 6013,
+      "name": "RaydiumPoolCreationFailed",
+      "msg": "Failed to create Raydium pool"
+    },
+    {
+      "code": 6014,
+      "name": "NftFeeKeyCreationFailed",
+      "msg": "Failed to create NFT fee key"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidMigrationAmount",
+      "msg": "Invalid SOL amount for migration"
+    },
+    {
+      "code": 6016,
+      "name": "LiquidityLockFailed",
+      "msg": "Raydium liquidity lock failed"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidTreasury",
+      "msg": "Treasury account does not match config"
+    },
+    {
+      "code": 6018,
+      "name": "ParametersFrozen",
+      "msg": "Parameter is permanently frozen"
+    },
+    {
+      "code": 6019,
+      "name": "NoPendingAdmin",
+      "msg": "No admin transfer is pending"
+    },
+    {
+      "code": 6020,
+      "name": "AdminTransferExpired",
+      "msg": "Admin transfer nomination has expired"
+    },
+    {
+      "code": 6021,
+      "name": "UnsupportedAccountVersion",
+      "msg": "Unsupported account layout version"
+    },
+    {
+      "code": 6022,
+      "name": "AccountLayoutCurrent",
+      "msg": "Account layout is already current"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidPoolAccount",
+      "msg": "Pool account does not match the derived address"
+    },
+    {
+      "code": 6024,
+      "name": "MathOverflow",
+      "msg": "Arithmetic overflow"
+    },
+    {
+      "code": 6025,
+      "name": "LiquidityDepositMismatch",
+      "msg": "Pool deposit does not match the curve reserves"
+    },
+    {
+      "code": 6026,
+      "name": "MigrationPending",
+      "msg": "Trading is frozen until the curve migrates"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidOracleAccount",
+      "msg": "Oracle account is not a valid SOL/USD price feed"
+    },
+    {
+      "code": 6028,
+      "name": "StalePrice",
+      "msg": "Oracle price is stale"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidOraclePrice",
+      "msg": "Oracle price is not positive"
+    },
+    {
+      "code": 6030,
+      "name": "PriceConfidenceTooLow",
+      "msg": "Oracle price confidence interval is too wide"
+    },
+    {
+      "code": 6031,
+      "name": "MigrationUsdThresholdNotReached",
+      "msg": "Migration USD threshold not reached"
+    },
+    {
+      "code": 6032,
+      "name": "NotFeeKeyHolder",
+      "msg": "Signer does not hold the fee key NFT"
+    },
+    {
+      "code": 6033,
+      "name": "LpNotLocked",
+      "msg": "Pool LP tokens were burned, so there are no fees to claim"
+    },
+    {
+      "code": 6034,
+      "name": "UnsupportedMigrationTarget",
+      "msg": "Operation is not supported on this migration target"
+    },
+    {
+      "code": 6035,
+      "name": "RefundModeActive",
+      "msg": "Curve is in refund mode"
+    },
+    {
+      "code": 6036,
+      "name": "RefundModeNotActive",
+      "msg": "Curve is not in refund mode"
+    },
+    {
+      "code": 6037,
+      "name": "RefundNotAvailable",
+      "msg": "Refund mode needs a passed launch deadline, or the admin while migration is pending"
+    },
+    {
+      "code": 6038,
+      "name": "NotMigrated",
+      "msg": "Curve has not migrated yet"
+    },
+    {
+      "code": 6039,
+      "name": "MintAuthorityRevoked",
+      "msg": "Mint authority has been revoked"
+    },
+    {
+      "code": 6040,
+      "name": "InsufficientObservations",
+      "msg": "Not enough price history for the requested window"
+    },
+    {
+      "code": 6041,
+      "name": "SlippageExceeded",
+      "msg": "Buy would cost more SOL than the given limit"
+    },
+    {
+      "code": 6042,
+      "name": "FuturePrice",
+      "msg": "Oracle price is dated in the future"
+    },
+    {
+      "code": 6043,
+      "name": "OracleFeedNotAllowed",
+      "msg": "Oracle feed is not on the allowlist"
+    },
+    {
+      "code": 6044,
+      "name": "MetadataTooLong",
+      "msg": "Token name, symbol or URI is too long"
+    },
+    {
+      "code": 6045,
+      "name": "Token2022Required",
+      "msg": "Mint extensions need the Token-2022 program"
+    },
+    {
+      "code": 6046,
+      "name": "CreatorNotAllowed",
+      "msg": "Signer is not on the launchpad's creator allowlist"
+    },
+    {
+      "code": 6047,
+      "name": "SellExceedsSoldSupply",
+      "msg": "Sell exceeds the supply sold through the curve"
+    },
+    {
+      "code": 6048,
+      "name": "ReferrerMismatch",
+      "msg": "Referrer does not match the referral"
+    },
+    {
+      "code": 6049,
+      "name": "PausedOnRenounce",
+      "msg": "Unpause every operation before renouncing admin"
+    },
+    {
+      "code": 6050,
+      "name": "RefundExceedsSoldSupply",
+      "msg": "Refund exceeds the supply sold through the curve"
+    },
+    {
+      "code": 6051,
+      "name": "NoPendingAuthority",
+      "msg": "No launchpad authority transfer is pending"
     }
   ]
-}; 
\ No newline at end of file
+};
\ No newline at end of file
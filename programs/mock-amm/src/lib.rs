@@ -0,0 +1,430 @@
+//! Mock Raydium CPMM program for local migration tests.
+//!
+//! Mirrors the CPMM `initialize` interface that `migrate` calls through its
+//! Raydium CPMM adapter: the same instruction discriminator, account order and
+//! PDA seeds. It creates the pool, moves the initial deposits into the vaults,
+//! mints LP tokens to the creator and permanently locks a small slice of LP like
+//! the real program.
+//!
+//! Trading fees are simulated with `accrue_fees`, which grows the reserves the
+//! way swap fees do. `withdraw` mirrors the real instruction's interface and
+//! burns LP for its pro-rata share of the reserves; `claim_pool_fees` calls it to
+//! take out the locked LP's fee growth.
+
+// Anchor's `Error` is large by design and every handler returns it
+#![allow(clippy::result_large_err)]
+
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+declare_id!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
+
+pub const AUTH_SEED: &[u8] = b"vault_and_lp_mint_auth_seed";
+pub const POOL_SEED: &[u8] = b"pool";
+pub const POOL_LP_MINT_SEED: &[u8] = b"pool_lp_mint";
+pub const POOL_VAULT_SEED: &[u8] = b"pool_vault";
+pub const OBSERVATION_SEED: &[u8] = b"observation";
+
+/// LP amount withheld from the creator and locked forever (as in CPMM)
+pub const LOCKED_LP_AMOUNT: u64 = 100;
+
+#[program]
+pub mod mock_amm {
+    use super::*;
+
+    /// Create a pool and deposit the initial liquidity
+    pub fn initialize(
+        ctx: Context<Initialize>,
+        init_amount_0: u64,
+        init_amount_1: u64,
+        open_time: u64,
+    ) -> Result<()> {
+        require!(init_amount_0 > 0 && init_amount_1 > 0, MockAmmError::InvalidAmount);
+
+        // Move the initial deposits into the vaults
+        for (from, to, amount) in [
+            (&ctx.accounts.creator_token_0, &ctx.accounts.token_0_vault, init_amount_0),
+            (&ctx.accounts.creator_token_1, &ctx.accounts.token_1_vault, init_amount_1),
+        ] {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: from.to_account_info(),
+                        to: to.to_account_info(),
+                        authority: ctx.accounts.creator.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+
+        // Mint sqrt(amount_0 * amount_1) LP, keeping the locked slice back
+        let liquidity = integer_sqrt(init_amount_0 as u128 * init_amount_1 as u128) as u64;
+        require!(liquidity > LOCKED_LP_AMOUNT, MockAmmError::InvalidAmount);
+        let auth_bump = *ctx.bumps.get("authority").unwrap();
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::MintTo {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    to: ctx.accounts.creator_lp_token.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+                &[&[AUTH_SEED, &[auth_bump]]],
+            ),
+            liquidity - LOCKED_LP_AMOUNT,
+        )?;
+
+        let pool = &mut ctx.accounts.pool_state;
+        pool.amm_config = ctx.accounts.amm_config.key();
+        pool.pool_creator = ctx.accounts.creator.key();
+        pool.token_0_vault = ctx.accounts.token_0_vault.key();
+        pool.token_1_vault = ctx.accounts.token_1_vault.key();
+        pool.lp_mint = ctx.accounts.lp_mint.key();
+        pool.token_0_mint = ctx.accounts.token_0_mint.key();
+        pool.token_1_mint = ctx.accounts.token_1_mint.key();
+        pool.token_0_program = ctx.accounts.token_0_program.key();
+        pool.token_1_program = ctx.accounts.token_1_program.key();
+        pool.observation_key = ctx.accounts.observation_state.key();
+        pool.auth_bump = auth_bump;
+        pool.lp_mint_decimals = ctx.accounts.lp_mint.decimals;
+        pool.mint_0_decimals = ctx.accounts.token_0_mint.decimals;
+        pool.mint_1_decimals = ctx.accounts.token_1_mint.decimals;
+        pool.lp_supply = liquidity;
+        pool.open_time = open_time;
+        pool.locked_lp = LOCKED_LP_AMOUNT;
+
+        ctx.accounts.observation_state.pool_id = pool.key();
+
+        msg!("Mock pool initialized with {} LP", liquidity);
+        Ok(())
+    }
+
+    /// Stand-in for swap fees: move tokens into the vaults, growing the reserves
+    pub fn accrue_fees(ctx: Context<AccrueFees>, amount_0: u64, amount_1: u64) -> Result<()> {
+        for (from, to, amount) in [
+            (&ctx.accounts.payer_token_0, &ctx.accounts.token_0_vault, amount_0),
+            (&ctx.accounts.payer_token_1, &ctx.accounts.token_1_vault, amount_1),
+        ] {
+            if amount > 0 {
+                token::transfer(
+                    CpiContext::new(
+                        ctx.accounts.token_program.to_account_info(),
+                        token::Transfer {
+                            from: from.to_account_info(),
+                            to: to.to_account_info(),
+                            authority: ctx.accounts.payer.to_account_info(),
+                        },
+                    ),
+                    amount,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Burn LP for its pro-rata share of both reserves
+    ///
+    /// The mock charges no protocol or fund fees, so the reserves are the vault balances.
+    pub fn withdraw(
+        ctx: Context<Withdraw>,
+        lp_token_amount: u64,
+        minimum_token_0_amount: u64,
+        minimum_token_1_amount: u64,
+    ) -> Result<()> {
+        let pool = &ctx.accounts.pool_state;
+        require!(
+            lp_token_amount > 0 && lp_token_amount < pool.lp_supply,
+            MockAmmError::InvalidAmount
+        );
+        let share = |reserve: u64| (reserve as u128 * lp_token_amount as u128 / pool.lp_supply as u128) as u64;
+        let amount_0 = share(ctx.accounts.token_0_vault.amount);
+        let amount_1 = share(ctx.accounts.token_1_vault.amount);
+        require!(
+            amount_0 >= minimum_token_0_amount && amount_1 >= minimum_token_1_amount,
+            MockAmmError::ExceededSlippage
+        );
+        let auth_bump = pool.auth_bump;
+
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Burn {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    from: ctx.accounts.owner_lp_token.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            lp_token_amount,
+        )?;
+        for (from, to, amount) in [
+            (&ctx.accounts.token_0_vault, &ctx.accounts.token_0_account, amount_0),
+            (&ctx.accounts.token_1_vault, &ctx.accounts.token_1_account, amount_1),
+        ] {
+            if amount > 0 {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        token::Transfer {
+                            from: from.to_account_info(),
+                            to: to.to_account_info(),
+                            authority: ctx.accounts.authority.to_account_info(),
+                        },
+                        &[&[AUTH_SEED, &[auth_bump]]],
+                    ),
+                    amount,
+                )?;
+            }
+        }
+
+        ctx.accounts.pool_state.lp_supply -= lp_token_amount;
+
+        msg!("Withdrew {} / {} for {} LP", amount_0, amount_1, lp_token_amount);
+        Ok(())
+    }
+}
+
+/// Accounts of CPMM `initialize`, in the same order as the real program
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: Fee tier config; only its address is used by the mock
+    pub amm_config: UncheckedAccount<'info>,
+
+    /// CHECK: Vault and LP mint authority PDA
+    #[account(seeds = [AUTH_SEED], bump)]
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + PoolState::LEN,
+        seeds = [
+            POOL_SEED,
+            amm_config.key().as_ref(),
+            token_0_mint.key().as_ref(),
+            token_1_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub pool_state: Box<Account<'info, PoolState>>,
+
+    #[account(constraint = token_0_mint.key() < token_1_mint.key() @ MockAmmError::InvalidMintOrder)]
+    pub token_0_mint: Box<Account<'info, Mint>>,
+
+    pub token_1_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [POOL_LP_MINT_SEED, pool_state.key().as_ref()],
+        bump,
+        mint::decimals = 9,
+        mint::authority = authority
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = token_0_mint,
+        token::authority = creator
+    )]
+    pub creator_token_0: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_1_mint,
+        token::authority = creator
+    )]
+    pub creator_token_1: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = lp_mint,
+        associated_token::authority = creator
+    )]
+    pub creator_lp_token: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [POOL_VAULT_SEED, pool_state.key().as_ref(), token_0_mint.key().as_ref()],
+        bump,
+        token::mint = token_0_mint,
+        token::authority = authority
+    )]
+    pub token_0_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [POOL_VAULT_SEED, pool_state.key().as_ref(), token_1_mint.key().as_ref()],
+        bump,
+        token::mint = token_1_mint,
+        token::authority = authority
+    )]
+    pub token_1_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Pool creation fee receiver; the mock charges no fee
+    #[account(mut)]
+    pub create_pool_fee: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + ObservationState::LEN,
+        seeds = [OBSERVATION_SEED, pool_state.key().as_ref()],
+        bump
+    )]
+    pub observation_state: Box<Account<'info, ObservationState>>,
+
+    pub token_program: Program<'info, Token>,
+    pub token_0_program: Program<'info, Token>,
+    pub token_1_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Accounts of the test-only `accrue_fees` instruction
+#[derive(Accounts)]
+pub struct AccrueFees<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub pool_state: Box<Account<'info, PoolState>>,
+
+    #[account(mut, token::mint = pool_state.token_0_mint, token::authority = payer)]
+    pub payer_token_0: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = pool_state.token_1_mint, token::authority = payer)]
+    pub payer_token_1: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, address = pool_state.token_0_vault)]
+    pub token_0_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, address = pool_state.token_1_vault)]
+    pub token_1_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Accounts of CPMM `withdraw`, in the same order as the real program
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    /// Authority of the LP position
+    pub owner: Signer<'info>,
+
+    /// CHECK: Vault and LP mint authority PDA
+    #[account(seeds = [AUTH_SEED], bump = pool_state.auth_bump)]
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub pool_state: Box<Account<'info, PoolState>>,
+
+    #[account(mut, token::mint = pool_state.lp_mint, token::authority = owner)]
+    pub owner_lp_token: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = pool_state.token_0_mint)]
+    pub token_0_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = pool_state.token_1_mint)]
+    pub token_1_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, address = pool_state.token_0_vault)]
+    pub token_0_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, address = pool_state.token_1_vault)]
+    pub token_1_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: Token-2022 program; the mock only handles SPL Token mints
+    pub token_program_2022: UncheckedAccount<'info>,
+
+    #[account(address = pool_state.token_0_mint)]
+    pub vault_0_mint: Box<Account<'info, Mint>>,
+
+    #[account(address = pool_state.token_1_mint)]
+    pub vault_1_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut, address = pool_state.lp_mint)]
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: SPL Memo program; the mock writes no memos
+    pub memo_program: UncheckedAccount<'info>,
+}
+
+/// Pool state, laid out like the real CPMM `PoolState` up to `open_time` so code
+/// reading the real account at fixed offsets works on the mock
+#[account]
+pub struct PoolState {
+    pub amm_config: Pubkey,
+    pub pool_creator: Pubkey,
+    pub token_0_vault: Pubkey,
+    pub token_1_vault: Pubkey,
+    pub lp_mint: Pubkey,
+    pub token_0_mint: Pubkey,
+    pub token_1_mint: Pubkey,
+    pub token_0_program: Pubkey,
+    pub token_1_program: Pubkey,
+    pub observation_key: Pubkey,
+    pub auth_bump: u8,
+    pub status: u8,
+    pub lp_mint_decimals: u8,
+    pub mint_0_decimals: u8,
+    pub mint_1_decimals: u8,
+    /// Total LP issued, including the locked slice
+    pub lp_supply: u64,
+    /// Fees owed to the protocol and the fund; always zero in the mock
+    pub protocol_fees_token_0: u64,
+    pub protocol_fees_token_1: u64,
+    pub fund_fees_token_0: u64,
+    pub fund_fees_token_1: u64,
+    pub open_time: u64,
+    /// LP permanently locked in the pool (mock only)
+    pub locked_lp: u64,
+}
+
+impl PoolState {
+    pub const LEN: usize = 32 * 10 + 5 + 8 * 6 + 8;
+}
+
+/// Observation state placeholder (the mock keeps no price history)
+#[account]
+pub struct ObservationState {
+    pub pool_id: Pubkey,
+}
+
+impl ObservationState {
+    pub const LEN: usize = 32;
+}
+
+#[error_code]
+pub enum MockAmmError {
+    #[msg("Invalid amount")]
+    InvalidAmount,
+    #[msg("Token 0 mint must sort before token 1 mint")]
+    InvalidMintOrder,
+    #[msg("Withdrawal pays out less than the minimum")]
+    ExceededSlippage,
+}
+
+/// Integer square root (floor)
+fn integer_sqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut x = value;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
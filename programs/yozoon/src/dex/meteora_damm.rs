@@ -0,0 +1,283 @@
+//! Meteora DAMM v2 target: one full-range position, locked permanently
+//!
+//! DAMM pools are priced over the range set by their config, so the whole
+//! deposit goes into the position the pool opens for its creator. The position
+//! is then locked with the pool's own `permanent_lock_position`, which keeps the
+//! fees claimable but the liquidity out of reach for good.
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use std::str::FromStr;
+use crate::dex::{
+    full_range_liquidity, require_address, sqrt_price_x64, CreatedPool, LpPosition, MigrationTarget,
+    MigrationTargetKind, PoolDeposit,
+};
+use crate::errors::YozoonError;
+use crate::utils::constants::*;
+
+/// Anchor discriminator of `initialize_pool` (sha256("global:initialize_pool")[..8])
+pub const INITIALIZE_POOL_DISCRIMINATOR: [u8; 8] = [95, 180, 10, 172, 84, 174, 232, 40];
+
+/// Anchor discriminator of `permanent_lock_position`
+pub const PERMANENT_LOCK_POSITION_DISCRIMINATOR: [u8; 8] = [165, 176, 125, 6, 231, 171, 186, 213];
+
+/// Token-2022 program, which owns DAMM position NFTs
+pub const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+
+/// Seeds used by the DAMM program for its PDAs
+pub mod seeds {
+    pub const POOL: &[u8] = b"pool";
+    pub const POOL_AUTHORITY: &[u8] = b"pool_authority";
+    pub const POSITION: &[u8] = b"position";
+    pub const POSITION_NFT_ACCOUNT: &[u8] = b"position_nft_account";
+    pub const TOKEN_VAULT: &[u8] = b"token_vault";
+    pub const EVENT_AUTHORITY: &[u8] = b"__event_authority";
+}
+
+/// Arguments of `initialize_pool`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InitializePoolArgs {
+    /// Position liquidity in Q64.64 pool units
+    pub liquidity: u128,
+    /// Initial square root price in Q64.64
+    pub sqrt_price: u128,
+    /// Slot or timestamp at which trading opens (`None` = immediately)
+    pub activation_point: Option<u64>,
+}
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &MeteoraDamm::program_id()).0
+}
+
+/// Derive the pool address of a pair; DAMM orders the mints inside the seeds
+pub fn pool_address(config: &Pubkey, mint_a: &Pubkey, mint_b: &Pubkey) -> Pubkey {
+    let (first, second) = if mint_a > mint_b { (mint_a, mint_b) } else { (mint_b, mint_a) };
+    pda(&[seeds::POOL, config.as_ref(), first.as_ref(), second.as_ref()])
+}
+
+fn instruction<T: AnchorSerialize>(discriminator: [u8; 8], args: &T, accounts: Vec<AccountMeta>) -> Instruction {
+    let mut data = discriminator.to_vec();
+    data.extend(args.try_to_vec().unwrap());
+    Instruction {
+        program_id: MeteoraDamm::program_id(),
+        accounts,
+        data,
+    }
+}
+
+/// Meteora DAMM v2 adapter
+pub struct MeteoraDamm;
+
+/// Remaining accounts of a DAMM migration, in order
+///
+/// `position_nft_mint` is a fresh keypair that must sign the transaction.
+#[derive(Clone, Copy, Debug)]
+pub struct DammAccounts {
+    pub config: Pubkey,
+    pub pool_authority: Pubkey,
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub position_nft_mint: Pubkey,
+    pub position_nft_account: Pubkey,
+    pub token_a_vault: Pubkey,
+    pub token_b_vault: Pubkey,
+    pub token_2022_program: Pubkey,
+    pub event_authority: Pubkey,
+}
+
+impl DammAccounts {
+    pub const COUNT: usize = 10;
+
+    /// Derive every account for the pool of `mint`/WSOL under `config`
+    pub fn derive(config: Pubkey, mint: &Pubkey, position_nft_mint: Pubkey) -> Self {
+        let wsol = Pubkey::from_str(WRAPPED_SOL_MINT).unwrap();
+        let pool = pool_address(&config, mint, &wsol);
+        Self {
+            config,
+            pool_authority: pda(&[seeds::POOL_AUTHORITY]),
+            pool,
+            position: pda(&[seeds::POSITION, position_nft_mint.as_ref()]),
+            position_nft_mint,
+            position_nft_account: pda(&[seeds::POSITION_NFT_ACCOUNT, position_nft_mint.as_ref()]),
+            token_a_vault: pda(&[seeds::TOKEN_VAULT, mint.as_ref(), pool.as_ref()]),
+            token_b_vault: pda(&[seeds::TOKEN_VAULT, wsol.as_ref(), pool.as_ref()]),
+            token_2022_program: Pubkey::from_str(TOKEN_2022_PROGRAM_ID).unwrap(),
+            event_authority: pda(&[seeds::EVENT_AUTHORITY]),
+        }
+    }
+
+    /// Remaining account metas for `migrate`
+    pub fn account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.config, false),
+            AccountMeta::new_readonly(self.pool_authority, false),
+            AccountMeta::new(self.pool, false),
+            AccountMeta::new(self.position, false),
+            AccountMeta::new(self.position_nft_mint, true),
+            AccountMeta::new(self.position_nft_account, false),
+            AccountMeta::new(self.token_a_vault, false),
+            AccountMeta::new(self.token_b_vault, false),
+            AccountMeta::new_readonly(self.token_2022_program, false),
+            AccountMeta::new_readonly(self.event_authority, false),
+        ]
+    }
+}
+
+impl MigrationTarget for MeteoraDamm {
+    const KIND: MigrationTargetKind = MigrationTargetKind::MeteoraDamm;
+    const ACCOUNT_COUNT: usize = DammAccounts::COUNT;
+
+    fn program_id() -> Pubkey {
+        Pubkey::from_str(METEORA_DAMM_PROGRAM_ID).unwrap()
+    }
+
+    fn create_pool<'info>(
+        deposit: &PoolDeposit<'_, 'info>,
+        accounts: &[AccountInfo<'info>],
+    ) -> Result<CreatedPool<'info>> {
+        let [config, pool_authority, pool, position, position_nft_mint, position_nft_account, token_a_vault, token_b_vault, token_2022_program, event_authority] =
+            accounts
+        else {
+            return err!(YozoonError::InvalidPoolAccount);
+        };
+
+        require_keys_eq!(*config.owner, Self::program_id(), YozoonError::InvalidPoolAccount);
+        let expected = DammAccounts::derive(config.key(), deposit.token_mint.key, position_nft_mint.key());
+        for (account, address) in [
+            (pool_authority, expected.pool_authority),
+            (pool, expected.pool),
+            (position, expected.position),
+            (position_nft_account, expected.position_nft_account),
+            (token_a_vault, expected.token_a_vault),
+            (token_b_vault, expected.token_b_vault),
+            (token_2022_program, expected.token_2022_program),
+            (event_authority, expected.event_authority),
+        ] {
+            require_address(account, &address)?;
+        }
+
+        // The project token is token A, priced in wrapped SOL
+        let sqrt_price = sqrt_price_x64(deposit.token_amount, deposit.sol_amount).ok_or(YozoonError::MathOverflow)?;
+        let liquidity = full_range_liquidity(deposit.token_amount, deposit.sol_amount, sqrt_price)
+            .and_then(|liquidity| liquidity.checked_mul(1 << 64))
+            .ok_or(YozoonError::MathOverflow)?;
+        let creator = deposit.creator.key();
+        let signer = &[deposit.creator_seeds];
+
+        // 1. Pool and the creator's position, funded in one go
+        let ix = instruction(
+            INITIALIZE_POOL_DISCRIMINATOR,
+            &InitializePoolArgs {
+                liquidity,
+                sqrt_price,
+                activation_point: None,
+            },
+            vec![
+                AccountMeta::new_readonly(creator, false),
+                AccountMeta::new(position_nft_mint.key(), true),
+                AccountMeta::new(position_nft_account.key(), false),
+                AccountMeta::new(creator, true),
+                AccountMeta::new_readonly(config.key(), false),
+                AccountMeta::new_readonly(pool_authority.key(), false),
+                AccountMeta::new(pool.key(), false),
+                AccountMeta::new(position.key(), false),
+                AccountMeta::new_readonly(deposit.token_mint.key(), false),
+                AccountMeta::new_readonly(deposit.sol_mint.key(), false),
+                AccountMeta::new(token_a_vault.key(), false),
+                AccountMeta::new(token_b_vault.key(), false),
+                AccountMeta::new(deposit.creator_token_account.key(), false),
+                AccountMeta::new(deposit.creator_sol_account.key(), false),
+                AccountMeta::new_readonly(deposit.token_program.key(), false),
+                AccountMeta::new_readonly(deposit.spl_token_program.key(), false),
+                AccountMeta::new_readonly(token_2022_program.key(), false),
+                AccountMeta::new_readonly(deposit.system_program.key(), false),
+                AccountMeta::new_readonly(event_authority.key(), false),
+                AccountMeta::new_readonly(Self::program_id(), false),
+            ],
+        );
+        invoke_signed(
+            &ix,
+            &[
+                deposit.creator.clone(),
+                position_nft_mint.clone(),
+                position_nft_account.clone(),
+                config.clone(),
+                pool_authority.clone(),
+                pool.clone(),
+                position.clone(),
+                deposit.token_mint.clone(),
+                deposit.sol_mint.clone(),
+                token_a_vault.clone(),
+                token_b_vault.clone(),
+                deposit.creator_token_account.clone(),
+                deposit.creator_sol_account.clone(),
+                deposit.token_program.clone(),
+                deposit.spl_token_program.clone(),
+                token_2022_program.clone(),
+                deposit.system_program.clone(),
+                event_authority.clone(),
+                deposit.program.clone(),
+            ],
+            signer,
+        )?;
+
+        // 2. Lock all of it
+        let ix = instruction(
+            PERMANENT_LOCK_POSITION_DISCRIMINATOR,
+            &liquidity,
+            vec![
+                AccountMeta::new(pool.key(), false),
+                AccountMeta::new(position.key(), false),
+                AccountMeta::new_readonly(position_nft_account.key(), false),
+                AccountMeta::new_readonly(creator, true),
+                AccountMeta::new_readonly(event_authority.key(), false),
+                AccountMeta::new_readonly(Self::program_id(), false),
+            ],
+        );
+        invoke_signed(
+            &ix,
+            &[
+                pool.clone(),
+                position.clone(),
+                position_nft_account.clone(),
+                deposit.creator.clone(),
+                event_authority.clone(),
+                deposit.program.clone(),
+            ],
+            signer,
+        )?;
+
+        Ok(CreatedPool {
+            pool_state: pool.key(),
+            token_vault: token_a_vault.clone(),
+            sol_vault: token_b_vault.clone(),
+            lp: LpPosition::Locked {
+                position: position.key(),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::hash::hash;
+
+    #[test]
+    fn discriminators_match_anchor_sighash() {
+        for (name, discriminator) in [
+            ("initialize_pool", INITIALIZE_POOL_DISCRIMINATOR),
+            ("permanent_lock_position", PERMANENT_LOCK_POSITION_DISCRIMINATOR),
+        ] {
+            let sighash = hash(format!("global:{}", name).as_bytes());
+            assert_eq!(discriminator, sighash.to_bytes()[..8], "{}", name);
+        }
+    }
+
+    #[test]
+    fn pool_address_ignores_mint_order() {
+        let config = Pubkey::new_unique();
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        assert_eq!(pool_address(&config, &a, &b), pool_address(&config, &b, &a));
+    }
+}
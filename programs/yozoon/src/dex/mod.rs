@@ -0,0 +1,207 @@
+//! DEX adapters that `migrate` uses to seed a pool with the curve's liquidity
+//!
+//! Each target reads its own accounts from the instruction's remaining accounts,
+//! in the order given by its `account_metas` helper.
+use anchor_lang::prelude::*;
+use crate::errors::YozoonError;
+
+pub mod meteora_damm;
+pub mod orca_whirlpool;
+pub mod raydium_cpmm;
+
+/// DEX a bonding curve migrates to, chosen at `initialize_bonding_curve`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MigrationTargetKind {
+    /// Raydium CPMM (constant product, fungible LP tokens)
+    RaydiumCpmm,
+    /// Orca Whirlpool, one full-range position
+    OrcaWhirlpool,
+    /// Meteora DAMM v2, one full-range position locked permanently
+    MeteoraDamm,
+}
+
+impl MigrationTargetKind {
+    /// Program that owns the target's pools
+    pub fn program_id(&self) -> Pubkey {
+        match self {
+            Self::RaydiumCpmm => raydium_cpmm::RaydiumCpmm::program_id(),
+            Self::OrcaWhirlpool => orca_whirlpool::OrcaWhirlpool::program_id(),
+            Self::MeteoraDamm => meteora_damm::MeteoraDamm::program_id(),
+        }
+    }
+}
+
+/// Liquidity the pool creator PDA hands to a target
+pub struct PoolDeposit<'a, 'info> {
+    /// Program of the target DEX
+    pub program: &'a AccountInfo<'info>,
+    /// Pool creator PDA; owns the deposit accounts and signs every CPI
+    pub creator: &'a AccountInfo<'info>,
+    /// Signer seeds of `creator`
+    pub creator_seeds: &'a [&'a [u8]],
+    /// Project token mint
+    pub token_mint: &'a AccountInfo<'info>,
+    /// Wrapped SOL mint
+    pub sol_mint: &'a AccountInfo<'info>,
+    /// Creator's project token account
+    pub creator_token_account: &'a AccountInfo<'info>,
+    /// Creator's wrapped SOL account
+    pub creator_sol_account: &'a AccountInfo<'info>,
+    /// Project tokens to deposit
+    pub token_amount: u64,
+    /// Wrapped SOL to deposit (lamports)
+    pub sol_amount: u64,
+    /// Token program of the project mint (SPL Token or Token-2022)
+    pub token_program: &'a AccountInfo<'info>,
+    /// SPL Token program, which owns wrapped SOL
+    pub spl_token_program: &'a AccountInfo<'info>,
+    /// System program
+    pub system_program: &'a AccountInfo<'info>,
+    /// Associated token program
+    pub associated_token_program: &'a AccountInfo<'info>,
+    /// Rent sysvar
+    pub rent: &'a AccountInfo<'info>,
+}
+
+/// Pool created by a target
+pub struct CreatedPool<'info> {
+    /// Target's pool state account
+    pub pool_state: Pubkey,
+    /// Pool vault holding the project token
+    pub token_vault: AccountInfo<'info>,
+    /// Pool vault holding wrapped SOL
+    pub sol_vault: AccountInfo<'info>,
+    /// What the creator got back for its deposit
+    pub lp: LpPosition<'info>,
+}
+
+/// Claim on the pool liquidity returned to the pool creator
+pub enum LpPosition<'info> {
+    /// SPL tokens held by the creator: fungible LP tokens or a position NFT
+    Tokens {
+        mint: AccountInfo<'info>,
+        account: AccountInfo<'info>,
+    },
+    /// Position the target has already locked permanently
+    Locked { position: Pubkey },
+}
+
+/// A DEX the curve can migrate to
+pub trait MigrationTarget {
+    /// Kind stored in `BondingCurve::migration_target`
+    const KIND: MigrationTargetKind;
+
+    /// Number of target accounts `create_pool` reads from the remaining accounts
+    const ACCOUNT_COUNT: usize;
+
+    /// Program that owns the target's pools
+    fn program_id() -> Pubkey;
+
+    /// Create the pool and deposit the creator's liquidity over the full price range
+    fn create_pool<'info>(
+        deposit: &PoolDeposit<'_, 'info>,
+        accounts: &[AccountInfo<'info>],
+    ) -> Result<CreatedPool<'info>>;
+}
+
+/// Create the pool on the target chosen for the curve
+pub fn create_pool<'info>(
+    kind: MigrationTargetKind,
+    deposit: &PoolDeposit<'_, 'info>,
+    accounts: &[AccountInfo<'info>],
+) -> Result<CreatedPool<'info>> {
+    match kind {
+        MigrationTargetKind::RaydiumCpmm => create_with::<raydium_cpmm::RaydiumCpmm>(deposit, accounts),
+        MigrationTargetKind::OrcaWhirlpool => create_with::<orca_whirlpool::OrcaWhirlpool>(deposit, accounts),
+        MigrationTargetKind::MeteoraDamm => create_with::<meteora_damm::MeteoraDamm>(deposit, accounts),
+    }
+}
+
+fn create_with<'info, T: MigrationTarget>(
+    deposit: &PoolDeposit<'_, 'info>,
+    accounts: &[AccountInfo<'info>],
+) -> Result<CreatedPool<'info>> {
+    require!(accounts.len() == T::ACCOUNT_COUNT, YozoonError::InvalidPoolAccount);
+    require_keys_eq!(deposit.program.key(), T::program_id(), YozoonError::InvalidPoolAccount);
+    msg!("Creating {:?} pool", T::KIND);
+    T::create_pool(deposit, accounts)
+}
+
+/// Check a passed account against the address the target will use
+fn require_address(account: &AccountInfo, expected: &Pubkey) -> Result<()> {
+    require_keys_eq!(account.key(), *expected, YozoonError::InvalidPoolAccount);
+    Ok(())
+}
+
+/// Integer square root (floor)
+pub fn integer_sqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut x = value;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+/// Square root of the price `amount_b / amount_a` in Q64.64, as concentrated-liquidity pools store it
+pub fn sqrt_price_x64(amount_a: u64, amount_b: u64) -> Option<u128> {
+    if amount_a == 0 || amount_b == 0 {
+        return None;
+    }
+    // Keep as many bits under the root as fit in a u128
+    let k = (amount_b.leading_zeros() / 2).min(32);
+    let ratio = ((amount_b as u128) << (64 + 2 * k)) / amount_a as u128;
+    Some(integer_sqrt(ratio) << (32 - k))
+}
+
+/// Liquidity (Q64.64 pool units) of a full-range position holding at most `amount_a` and `amount_b`
+///
+/// Treats the range as unbounded, which slightly overstates the tokens needed, and
+/// shaves off a millionth so the pool's round-up never asks for more than the amounts.
+pub fn full_range_liquidity(amount_a: u64, amount_b: u64, sqrt_price_x64: u128) -> Option<u128> {
+    if sqrt_price_x64 == 0 {
+        return None;
+    }
+    let from_a = (amount_a as u128).checked_mul(sqrt_price_x64 >> 32)? >> 32;
+    let from_b = ((amount_b as u128) << 64) / sqrt_price_x64;
+    let liquidity = from_a.min(from_b);
+    Some(liquidity.saturating_sub(liquidity / 1_000_000 + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sqrt_price_matches_the_amount_ratio() {
+        // Price 4 → sqrt 2
+        assert_eq!(sqrt_price_x64(1_000, 4_000).unwrap(), 2u128 << 64);
+        // Price 1/4 → sqrt 1/2
+        assert_eq!(sqrt_price_x64(4_000, 1_000).unwrap(), 1u128 << 63);
+        assert!(sqrt_price_x64(0, 1).is_none());
+
+        // A realistic curve: 63,000 SOL against 1e15 base units stays accurate to a millionth
+        let (tokens, lamports) = (1_000_000_000_000_000u64, 63_000_000_000_000u64);
+        let sqrt_price = sqrt_price_x64(tokens, lamports).unwrap();
+        let exact = ((lamports as f64 / tokens as f64).sqrt() * 2f64.powi(64)) as u128;
+        assert!(sqrt_price.abs_diff(exact) * 1_000_000 < exact);
+    }
+
+    #[test]
+    fn full_range_liquidity_fits_both_amounts() {
+        let (tokens, lamports) = (1_000_000_000_000_000u64, 63_000_000_000_000u64);
+        let sqrt_price = sqrt_price_x64(tokens, lamports).unwrap();
+        let liquidity = full_range_liquidity(tokens, lamports, sqrt_price).unwrap();
+
+        // Tokens an unbounded position needs: a = L / sqrtP, b = L * sqrtP
+        let needed_a = (liquidity << 64) / sqrt_price + 1;
+        let needed_b = liquidity * (sqrt_price >> 32) / (1u128 << 32) + 1;
+        assert!(needed_a <= tokens as u128 && needed_b <= lamports as u128);
+        // ...and uses nearly all of the scarcer side
+        assert!(needed_b * 10_000 >= lamports as u128 * 9_999);
+    }
+}
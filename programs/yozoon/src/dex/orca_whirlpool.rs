@@ -0,0 +1,453 @@
+//! Orca Whirlpool target: one full-range concentrated liquidity position
+//!
+//! The pool is created, its two boundary tick arrays initialized and a position
+//! opened over the widest range the tick spacing allows. The position NFT is what
+//! `migrate` burns or locks.
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::associated_token::get_associated_token_address;
+use std::str::FromStr;
+use crate::dex::{
+    full_range_liquidity, require_address, sqrt_price_x64, CreatedPool, LpPosition, MigrationTarget,
+    MigrationTargetKind, PoolDeposit,
+};
+use crate::errors::YozoonError;
+use crate::utils::constants::*;
+
+/// Anchor discriminator of `initialize_pool` (sha256("global:initialize_pool")[..8])
+pub const INITIALIZE_POOL_DISCRIMINATOR: [u8; 8] = [95, 180, 10, 172, 84, 174, 232, 40];
+
+/// Anchor discriminator of `initialize_tick_array`
+pub const INITIALIZE_TICK_ARRAY_DISCRIMINATOR: [u8; 8] = [11, 188, 193, 214, 141, 91, 149, 184];
+
+/// Anchor discriminator of `open_position`
+pub const OPEN_POSITION_DISCRIMINATOR: [u8; 8] = [135, 128, 47, 77, 15, 152, 240, 49];
+
+/// Anchor discriminator of `increase_liquidity`
+pub const INCREASE_LIQUIDITY_DISCRIMINATOR: [u8; 8] = [46, 156, 243, 118, 13, 205, 251, 178];
+
+/// Largest tick a Whirlpool supports
+pub const MAX_TICK_INDEX: i32 = 443_636;
+
+/// Ticks held by one tick array
+pub const TICK_ARRAY_SIZE: i32 = 88;
+
+/// Seeds used by the Whirlpool program for its PDAs
+pub mod seeds {
+    pub const WHIRLPOOL: &[u8] = b"whirlpool";
+    pub const FEE_TIER: &[u8] = b"fee_tier";
+    pub const TICK_ARRAY: &[u8] = b"tick_array";
+    pub const POSITION: &[u8] = b"position";
+}
+
+/// Arguments of `initialize_pool`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InitializePoolArgs {
+    pub whirlpool_bump: u8,
+    pub tick_spacing: u16,
+    pub initial_sqrt_price: u128,
+}
+
+/// Arguments of `open_position`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OpenPositionArgs {
+    pub position_bump: u8,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+}
+
+/// Arguments of `increase_liquidity`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IncreaseLiquidityArgs {
+    pub liquidity_amount: u128,
+    pub token_max_a: u64,
+    pub token_max_b: u64,
+}
+
+/// Lowest and highest initializable ticks for `tick_spacing`
+pub fn full_range_ticks(tick_spacing: u16) -> (i32, i32) {
+    let spacing = tick_spacing as i32;
+    let upper = MAX_TICK_INDEX / spacing * spacing;
+    (-upper, upper)
+}
+
+/// Start index of the tick array holding `tick`
+pub fn tick_array_start_index(tick: i32, tick_spacing: u16) -> i32 {
+    let ticks_per_array = TICK_ARRAY_SIZE * tick_spacing as i32;
+    tick.div_euclid(ticks_per_array) * ticks_per_array
+}
+
+/// Derive the Whirlpool address of a sorted pair
+pub fn whirlpool_address(config: &Pubkey, mint_a: &Pubkey, mint_b: &Pubkey, tick_spacing: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::WHIRLPOOL, config.as_ref(), mint_a.as_ref(), mint_b.as_ref(), &tick_spacing.to_le_bytes()],
+        &OrcaWhirlpool::program_id(),
+    )
+}
+
+/// Derive the fee tier address of `tick_spacing`
+pub fn fee_tier_address(config: &Pubkey, tick_spacing: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[seeds::FEE_TIER, config.as_ref(), &tick_spacing.to_le_bytes()],
+        &OrcaWhirlpool::program_id(),
+    )
+    .0
+}
+
+/// Derive the tick array address starting at `start_index`
+pub fn tick_array_address(whirlpool: &Pubkey, start_index: i32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[seeds::TICK_ARRAY, whirlpool.as_ref(), start_index.to_string().as_bytes()],
+        &OrcaWhirlpool::program_id(),
+    )
+    .0
+}
+
+/// Derive the position address of a position NFT mint
+pub fn position_address(position_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seeds::POSITION, position_mint.as_ref()], &OrcaWhirlpool::program_id())
+}
+
+fn instruction<T: AnchorSerialize>(discriminator: [u8; 8], args: &T, accounts: Vec<AccountMeta>) -> Instruction {
+    let mut data = discriminator.to_vec();
+    data.extend(args.try_to_vec().unwrap());
+    Instruction {
+        program_id: OrcaWhirlpool::program_id(),
+        accounts,
+        data,
+    }
+}
+
+/// Orca Whirlpool adapter
+pub struct OrcaWhirlpool;
+
+/// Remaining accounts of a Whirlpool migration, in order
+///
+/// `token_vault_a`, `token_vault_b` and `position_mint` are fresh keypairs that
+/// must sign the transaction.
+#[derive(Clone, Copy, Debug)]
+pub struct WhirlpoolAccounts {
+    pub whirlpools_config: Pubkey,
+    pub fee_tier: Pubkey,
+    pub whirlpool: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub tick_array_lower: Pubkey,
+    pub tick_array_upper: Pubkey,
+    pub position: Pubkey,
+    pub position_mint: Pubkey,
+    pub position_token_account: Pubkey,
+}
+
+impl WhirlpoolAccounts {
+    pub const COUNT: usize = 10;
+
+    /// Derive every account for the pool of `mint`/WSOL under `whirlpools_config`
+    pub fn derive(
+        whirlpools_config: Pubkey,
+        mint: &Pubkey,
+        pool_creator: &Pubkey,
+        token_vault_a: Pubkey,
+        token_vault_b: Pubkey,
+        position_mint: Pubkey,
+    ) -> Self {
+        let wsol = Pubkey::from_str(WRAPPED_SOL_MINT).unwrap();
+        let (mint_a, mint_b) = if *mint < wsol { (*mint, wsol) } else { (wsol, *mint) };
+        let tick_spacing = ORCA_WHIRLPOOL_TICK_SPACING;
+        let (whirlpool, _) = whirlpool_address(&whirlpools_config, &mint_a, &mint_b, tick_spacing);
+        let (tick_lower, tick_upper) = full_range_ticks(tick_spacing);
+        Self {
+            whirlpools_config,
+            fee_tier: fee_tier_address(&whirlpools_config, tick_spacing),
+            whirlpool,
+            token_vault_a,
+            token_vault_b,
+            tick_array_lower: tick_array_address(&whirlpool, tick_array_start_index(tick_lower, tick_spacing)),
+            tick_array_upper: tick_array_address(&whirlpool, tick_array_start_index(tick_upper, tick_spacing)),
+            position: position_address(&position_mint).0,
+            position_mint,
+            position_token_account: get_associated_token_address(pool_creator, &position_mint),
+        }
+    }
+
+    /// Remaining account metas for `migrate`
+    pub fn account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.whirlpools_config, false),
+            AccountMeta::new_readonly(self.fee_tier, false),
+            AccountMeta::new(self.whirlpool, false),
+            AccountMeta::new(self.token_vault_a, true),
+            AccountMeta::new(self.token_vault_b, true),
+            AccountMeta::new(self.tick_array_lower, false),
+            AccountMeta::new(self.tick_array_upper, false),
+            AccountMeta::new(self.position, false),
+            AccountMeta::new(self.position_mint, true),
+            AccountMeta::new(self.position_token_account, false),
+        ]
+    }
+}
+
+impl MigrationTarget for OrcaWhirlpool {
+    const KIND: MigrationTargetKind = MigrationTargetKind::OrcaWhirlpool;
+    const ACCOUNT_COUNT: usize = WhirlpoolAccounts::COUNT;
+
+    fn program_id() -> Pubkey {
+        Pubkey::from_str(ORCA_WHIRLPOOL_PROGRAM_ID).unwrap()
+    }
+
+    fn create_pool<'info>(
+        deposit: &PoolDeposit<'_, 'info>,
+        accounts: &[AccountInfo<'info>],
+    ) -> Result<CreatedPool<'info>> {
+        let [whirlpools_config, fee_tier, whirlpool, token_vault_a, token_vault_b, tick_array_lower, tick_array_upper, position, position_mint, position_token_account] =
+            accounts
+        else {
+            return err!(YozoonError::InvalidPoolAccount);
+        };
+
+        require_keys_eq!(*whirlpools_config.owner, Self::program_id(), YozoonError::InvalidPoolAccount);
+        // These instructions only take SPL Token mints; Token-2022 pools need their `_v2` versions
+        require_keys_eq!(
+            deposit.token_program.key(),
+            anchor_spl::token::ID,
+            YozoonError::UnsupportedMigrationTarget
+        );
+        let expected = WhirlpoolAccounts::derive(
+            whirlpools_config.key(),
+            deposit.token_mint.key,
+            deposit.creator.key,
+            token_vault_a.key(),
+            token_vault_b.key(),
+            position_mint.key(),
+        );
+        for (account, address) in [
+            (fee_tier, expected.fee_tier),
+            (whirlpool, expected.whirlpool),
+            (tick_array_lower, expected.tick_array_lower),
+            (tick_array_upper, expected.tick_array_upper),
+            (position, expected.position),
+            (position_token_account, expected.position_token_account),
+        ] {
+            require_address(account, &address)?;
+        }
+
+        // Whirlpools order the pair by mint address and price token B in token A
+        let token_is_a = deposit.token_mint.key() < deposit.sol_mint.key();
+        let (mint_a, mint_b) = if token_is_a {
+            (deposit.token_mint, deposit.sol_mint)
+        } else {
+            (deposit.sol_mint, deposit.token_mint)
+        };
+        let (owner_account_a, owner_account_b) = if token_is_a {
+            (deposit.creator_token_account, deposit.creator_sol_account)
+        } else {
+            (deposit.creator_sol_account, deposit.creator_token_account)
+        };
+        let (amount_a, amount_b) = if token_is_a {
+            (deposit.token_amount, deposit.sol_amount)
+        } else {
+            (deposit.sol_amount, deposit.token_amount)
+        };
+
+        let tick_spacing = ORCA_WHIRLPOOL_TICK_SPACING;
+        let sqrt_price = sqrt_price_x64(amount_a, amount_b).ok_or(YozoonError::MathOverflow)?;
+        let liquidity = full_range_liquidity(amount_a, amount_b, sqrt_price).ok_or(YozoonError::MathOverflow)?;
+        let (tick_lower, tick_upper) = full_range_ticks(tick_spacing);
+        let creator = deposit.creator.key();
+        let signer = &[deposit.creator_seeds];
+
+        // 1. Pool at the curve's closing price
+        let (_, whirlpool_bump) = whirlpool_address(&whirlpools_config.key(), &mint_a.key(), &mint_b.key(), tick_spacing);
+        let ix = instruction(
+            INITIALIZE_POOL_DISCRIMINATOR,
+            &InitializePoolArgs {
+                whirlpool_bump,
+                tick_spacing,
+                initial_sqrt_price: sqrt_price,
+            },
+            vec![
+                AccountMeta::new_readonly(whirlpools_config.key(), false),
+                AccountMeta::new_readonly(mint_a.key(), false),
+                AccountMeta::new_readonly(mint_b.key(), false),
+                AccountMeta::new(creator, true),
+                AccountMeta::new(whirlpool.key(), false),
+                AccountMeta::new(token_vault_a.key(), true),
+                AccountMeta::new(token_vault_b.key(), true),
+                AccountMeta::new_readonly(fee_tier.key(), false),
+                AccountMeta::new_readonly(deposit.token_program.key(), false),
+                AccountMeta::new_readonly(deposit.system_program.key(), false),
+                AccountMeta::new_readonly(deposit.rent.key(), false),
+            ],
+        );
+        invoke_signed(
+            &ix,
+            &[
+                whirlpools_config.clone(),
+                mint_a.clone(),
+                mint_b.clone(),
+                deposit.creator.clone(),
+                whirlpool.clone(),
+                token_vault_a.clone(),
+                token_vault_b.clone(),
+                fee_tier.clone(),
+                deposit.token_program.clone(),
+                deposit.system_program.clone(),
+                deposit.rent.clone(),
+                deposit.program.clone(),
+            ],
+            signer,
+        )?;
+
+        // 2. Tick arrays holding the range bounds
+        for (tick_array, tick) in [(tick_array_lower, tick_lower), (tick_array_upper, tick_upper)] {
+            let ix = instruction(
+                INITIALIZE_TICK_ARRAY_DISCRIMINATOR,
+                &tick_array_start_index(tick, tick_spacing),
+                vec![
+                    AccountMeta::new_readonly(whirlpool.key(), false),
+                    AccountMeta::new(creator, true),
+                    AccountMeta::new(tick_array.key(), false),
+                    AccountMeta::new_readonly(deposit.system_program.key(), false),
+                ],
+            );
+            invoke_signed(
+                &ix,
+                &[
+                    whirlpool.clone(),
+                    deposit.creator.clone(),
+                    tick_array.clone(),
+                    deposit.system_program.clone(),
+                    deposit.program.clone(),
+                ],
+                signer,
+            )?;
+        }
+
+        // 3. Full-range position owned by the pool creator
+        let (_, position_bump) = position_address(&position_mint.key());
+        let ix = instruction(
+            OPEN_POSITION_DISCRIMINATOR,
+            &OpenPositionArgs {
+                position_bump,
+                tick_lower_index: tick_lower,
+                tick_upper_index: tick_upper,
+            },
+            vec![
+                AccountMeta::new(creator, true),
+                AccountMeta::new_readonly(creator, false),
+                AccountMeta::new(position.key(), false),
+                AccountMeta::new(position_mint.key(), true),
+                AccountMeta::new(position_token_account.key(), false),
+                AccountMeta::new_readonly(whirlpool.key(), false),
+                AccountMeta::new_readonly(deposit.token_program.key(), false),
+                AccountMeta::new_readonly(deposit.system_program.key(), false),
+                AccountMeta::new_readonly(deposit.rent.key(), false),
+                AccountMeta::new_readonly(deposit.associated_token_program.key(), false),
+            ],
+        );
+        invoke_signed(
+            &ix,
+            &[
+                deposit.creator.clone(),
+                position.clone(),
+                position_mint.clone(),
+                position_token_account.clone(),
+                whirlpool.clone(),
+                deposit.token_program.clone(),
+                deposit.system_program.clone(),
+                deposit.rent.clone(),
+                deposit.associated_token_program.clone(),
+                deposit.program.clone(),
+            ],
+            signer,
+        )?;
+
+        // 4. Deposit the liquidity
+        let ix = instruction(
+            INCREASE_LIQUIDITY_DISCRIMINATOR,
+            &IncreaseLiquidityArgs {
+                liquidity_amount: liquidity,
+                token_max_a: amount_a,
+                token_max_b: amount_b,
+            },
+            vec![
+                AccountMeta::new(whirlpool.key(), false),
+                AccountMeta::new_readonly(deposit.token_program.key(), false),
+                AccountMeta::new_readonly(creator, true),
+                AccountMeta::new(position.key(), false),
+                AccountMeta::new_readonly(position_token_account.key(), false),
+                AccountMeta::new(owner_account_a.key(), false),
+                AccountMeta::new(owner_account_b.key(), false),
+                AccountMeta::new(token_vault_a.key(), false),
+                AccountMeta::new(token_vault_b.key(), false),
+                AccountMeta::new(tick_array_lower.key(), false),
+                AccountMeta::new(tick_array_upper.key(), false),
+            ],
+        );
+        invoke_signed(
+            &ix,
+            &[
+                whirlpool.clone(),
+                deposit.token_program.clone(),
+                deposit.creator.clone(),
+                position.clone(),
+                position_token_account.clone(),
+                owner_account_a.clone(),
+                owner_account_b.clone(),
+                token_vault_a.clone(),
+                token_vault_b.clone(),
+                tick_array_lower.clone(),
+                tick_array_upper.clone(),
+                deposit.program.clone(),
+            ],
+            signer,
+        )?;
+
+        let (token_vault, sol_vault) = if token_is_a {
+            (token_vault_a.clone(), token_vault_b.clone())
+        } else {
+            (token_vault_b.clone(), token_vault_a.clone())
+        };
+        Ok(CreatedPool {
+            pool_state: whirlpool.key(),
+            token_vault,
+            sol_vault,
+            lp: LpPosition::Tokens {
+                mint: position_mint.clone(),
+                account: position_token_account.clone(),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::hash::hash;
+
+    #[test]
+    fn discriminators_match_anchor_sighash() {
+        for (name, discriminator) in [
+            ("initialize_pool", INITIALIZE_POOL_DISCRIMINATOR),
+            ("initialize_tick_array", INITIALIZE_TICK_ARRAY_DISCRIMINATOR),
+            ("open_position", OPEN_POSITION_DISCRIMINATOR),
+            ("increase_liquidity", INCREASE_LIQUIDITY_DISCRIMINATOR),
+        ] {
+            let sighash = hash(format!("global:{}", name).as_bytes());
+            assert_eq!(discriminator, sighash.to_bytes()[..8], "{}", name);
+        }
+    }
+
+    #[test]
+    fn full_range_covers_the_widest_aligned_ticks() {
+        assert_eq!(full_range_ticks(64), (-443_584, 443_584));
+        assert_eq!(full_range_ticks(1), (-443_636, 443_636));
+
+        // Tick arrays of spacing 64 span 5,632 ticks and round toward negative infinity
+        assert_eq!(tick_array_start_index(443_584, 64), 439_296);
+        assert_eq!(tick_array_start_index(-443_584, 64), -444_928);
+        assert_eq!(tick_array_start_index(0, 64), 0);
+        assert_eq!(tick_array_start_index(-1, 64), -5_632);
+    }
+}
@@ -0,0 +1,197 @@
+//! Raydium CPMM target: a constant-product pool with fungible LP tokens
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::associated_token::get_associated_token_address;
+use std::str::FromStr;
+use crate::dex::{require_address, CreatedPool, LpPosition, MigrationTarget, MigrationTargetKind, PoolDeposit};
+use crate::errors::YozoonError;
+use crate::raydium::cpmm;
+use crate::utils::constants::*;
+
+/// Raydium CPMM adapter
+pub struct RaydiumCpmm;
+
+/// Remaining accounts of a CPMM migration, in order
+#[derive(Clone, Copy, Debug)]
+pub struct CpmmAccounts {
+    pub amm_config: Pubkey,
+    pub authority: Pubkey,
+    pub pool_state: Pubkey,
+    pub lp_mint: Pubkey,
+    pub creator_lp_token: Pubkey,
+    pub token_0_vault: Pubkey,
+    pub token_1_vault: Pubkey,
+    pub observation_state: Pubkey,
+    pub create_pool_fee: Pubkey,
+}
+
+impl CpmmAccounts {
+    pub const COUNT: usize = 9;
+
+    /// Derive every account for the pool of `mint`/WSOL under the `amm_config` fee tier
+    pub fn derive(amm_config: Pubkey, mint: &Pubkey, pool_creator: &Pubkey) -> Self {
+        let program_id = RaydiumCpmm::program_id();
+        let wsol = Pubkey::from_str(WRAPPED_SOL_MINT).unwrap();
+        let (token_0_mint, token_1_mint) = cpmm::sort_mints(*mint, wsol);
+        let addresses = cpmm::PoolAddresses::derive(&program_id, &amm_config, &token_0_mint, &token_1_mint);
+        Self {
+            amm_config,
+            authority: addresses.authority,
+            pool_state: addresses.pool_state,
+            lp_mint: addresses.lp_mint,
+            creator_lp_token: get_associated_token_address(pool_creator, &addresses.lp_mint),
+            token_0_vault: addresses.token_0_vault,
+            token_1_vault: addresses.token_1_vault,
+            observation_state: addresses.observation_state,
+            create_pool_fee: Pubkey::from_str(RAYDIUM_CPMM_CREATE_POOL_FEE_RECEIVER).unwrap(),
+        }
+    }
+
+    /// Remaining account metas for `migrate`
+    pub fn account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.amm_config, false),
+            AccountMeta::new_readonly(self.authority, false),
+            AccountMeta::new(self.pool_state, false),
+            AccountMeta::new(self.lp_mint, false),
+            AccountMeta::new(self.creator_lp_token, false),
+            AccountMeta::new(self.token_0_vault, false),
+            AccountMeta::new(self.token_1_vault, false),
+            AccountMeta::new(self.observation_state, false),
+            AccountMeta::new(self.create_pool_fee, false),
+        ]
+    }
+}
+
+impl MigrationTarget for RaydiumCpmm {
+    const KIND: MigrationTargetKind = MigrationTargetKind::RaydiumCpmm;
+    const ACCOUNT_COUNT: usize = CpmmAccounts::COUNT;
+
+    fn program_id() -> Pubkey {
+        Pubkey::from_str(RAYDIUM_CPMM_PROGRAM_ID).unwrap()
+    }
+
+    fn create_pool<'info>(
+        deposit: &PoolDeposit<'_, 'info>,
+        accounts: &[AccountInfo<'info>],
+    ) -> Result<CreatedPool<'info>> {
+        let [amm_config, authority, pool_state, lp_mint, creator_lp_token, token_0_vault, token_1_vault, observation_state, create_pool_fee] =
+            accounts
+        else {
+            return err!(YozoonError::InvalidPoolAccount);
+        };
+
+        // The fee tier must belong to the CPMM program, and every pool account must be
+        // the PDA it will derive
+        require_keys_eq!(*amm_config.owner, Self::program_id(), YozoonError::InvalidPoolAccount);
+        let expected = CpmmAccounts::derive(amm_config.key(), deposit.token_mint.key, deposit.creator.key);
+        for (account, address) in [
+            (authority, expected.authority),
+            (pool_state, expected.pool_state),
+            (lp_mint, expected.lp_mint),
+            (creator_lp_token, expected.creator_lp_token),
+            (token_0_vault, expected.token_0_vault),
+            (token_1_vault, expected.token_1_vault),
+            (observation_state, expected.observation_state),
+            (create_pool_fee, expected.create_pool_fee),
+        ] {
+            require_address(account, &address)?;
+        }
+
+        // CPMM orders the pair by mint address
+        let token_is_0 = deposit.token_mint.key() < deposit.sol_mint.key();
+        let (token_0_mint, token_1_mint) = if token_is_0 {
+            (deposit.token_mint, deposit.sol_mint)
+        } else {
+            (deposit.sol_mint, deposit.token_mint)
+        };
+        let (token_0_program, token_1_program) = if token_is_0 {
+            (deposit.token_program, deposit.spl_token_program)
+        } else {
+            (deposit.spl_token_program, deposit.token_program)
+        };
+        let (creator_token_0, creator_token_1) = if token_is_0 {
+            (deposit.creator_token_account, deposit.creator_sol_account)
+        } else {
+            (deposit.creator_sol_account, deposit.creator_token_account)
+        };
+        let (init_amount_0, init_amount_1) = if token_is_0 {
+            (deposit.token_amount, deposit.sol_amount)
+        } else {
+            (deposit.sol_amount, deposit.token_amount)
+        };
+
+        let ix = cpmm::initialize_instruction(
+            Self::program_id(),
+            &cpmm::InitializeAccounts {
+                creator: deposit.creator.key(),
+                amm_config: amm_config.key(),
+                authority: authority.key(),
+                pool_state: pool_state.key(),
+                token_0_mint: token_0_mint.key(),
+                token_1_mint: token_1_mint.key(),
+                lp_mint: lp_mint.key(),
+                creator_token_0: creator_token_0.key(),
+                creator_token_1: creator_token_1.key(),
+                creator_lp_token: creator_lp_token.key(),
+                token_0_vault: token_0_vault.key(),
+                token_1_vault: token_1_vault.key(),
+                create_pool_fee: create_pool_fee.key(),
+                observation_state: observation_state.key(),
+                // The LP mint is always an SPL Token mint
+                token_program: deposit.spl_token_program.key(),
+                token_0_program: token_0_program.key(),
+                token_1_program: token_1_program.key(),
+                associated_token_program: deposit.associated_token_program.key(),
+                system_program: deposit.system_program.key(),
+                rent: deposit.rent.key(),
+            },
+            &cpmm::InitializeArgs {
+                init_amount_0,
+                init_amount_1,
+                open_time: 0,
+            },
+        );
+        invoke_signed(
+            &ix,
+            &[
+                deposit.creator.clone(),
+                amm_config.clone(),
+                authority.clone(),
+                pool_state.clone(),
+                token_0_mint.clone(),
+                token_1_mint.clone(),
+                lp_mint.clone(),
+                creator_token_0.clone(),
+                creator_token_1.clone(),
+                creator_lp_token.clone(),
+                token_0_vault.clone(),
+                token_1_vault.clone(),
+                create_pool_fee.clone(),
+                observation_state.clone(),
+                deposit.spl_token_program.clone(),
+                deposit.token_program.clone(),
+                deposit.associated_token_program.clone(),
+                deposit.system_program.clone(),
+                deposit.rent.clone(),
+                deposit.program.clone(),
+            ],
+            &[deposit.creator_seeds],
+        )?;
+
+        let (token_vault, sol_vault) = if token_is_0 {
+            (token_0_vault.clone(), token_1_vault.clone())
+        } else {
+            (token_1_vault.clone(), token_0_vault.clone())
+        };
+        Ok(CreatedPool {
+            pool_state: pool_state.key(),
+            token_vault,
+            sol_vault,
+            lp: LpPosition::Tokens {
+                mint: lp_mint.clone(),
+                account: creator_lp_token.clone(),
+            },
+        })
+    }
+}
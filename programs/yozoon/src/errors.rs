@@ -36,4 +36,74 @@ pub enum YozoonError {
     InvalidMigrationAmount,
     #[msg("Raydium liquidity lock failed")]
     LiquidityLockFailed,
+    #[msg("Treasury account does not match config")]
+    InvalidTreasury,
+    #[msg("Parameter is permanently frozen")]
+    ParametersFrozen,
+    #[msg("No admin transfer is pending")]
+    NoPendingAdmin,
+    #[msg("Admin transfer nomination has expired")]
+    AdminTransferExpired,
+    #[msg("Unsupported account layout version")]
+    UnsupportedAccountVersion,
+    #[msg("Account layout is already current")]
+    AccountLayoutCurrent,
+    #[msg("Pool account does not match the derived address")]
+    InvalidPoolAccount,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Pool deposit does not match the curve reserves")]
+    LiquidityDepositMismatch,
+    #[msg("Trading is frozen until the curve migrates")]
+    MigrationPending,
+    #[msg("Oracle account is not a valid SOL/USD price feed")]
+    InvalidOracleAccount,
+    #[msg("Oracle price is stale")]
+    StalePrice,
+    #[msg("Oracle price is not positive")]
+    InvalidOraclePrice,
+    #[msg("Oracle price confidence interval is too wide")]
+    PriceConfidenceTooLow,
+    #[msg("Migration USD threshold not reached")]
+    MigrationUsdThresholdNotReached,
+    #[msg("Signer does not hold the fee key NFT")]
+    NotFeeKeyHolder,
+    #[msg("Pool LP tokens were burned, so there are no fees to claim")]
+    LpNotLocked,
+    #[msg("Operation is not supported on this migration target")]
+    UnsupportedMigrationTarget,
+    #[msg("Curve is in refund mode")]
+    RefundModeActive,
+    #[msg("Curve is not in refund mode")]
+    RefundModeNotActive,
+    #[msg("Refund mode needs a passed launch deadline, or the admin while migration is pending")]
+    RefundNotAvailable,
+    #[msg("Curve has not migrated yet")]
+    NotMigrated,
+    #[msg("Mint authority has been revoked")]
+    MintAuthorityRevoked,
+    #[msg("Not enough price history for the requested window")]
+    InsufficientObservations,
+    #[msg("Buy would cost more SOL than the given limit")]
+    SlippageExceeded,
+    #[msg("Oracle price is dated in the future")]
+    FuturePrice,
+    #[msg("Oracle feed is not on the allowlist")]
+    OracleFeedNotAllowed,
+    #[msg("Token name, symbol or URI is too long")]
+    MetadataTooLong,
+    #[msg("Mint extensions need the Token-2022 program")]
+    Token2022Required,
+    #[msg("Signer is not on the launchpad's creator allowlist")]
+    CreatorNotAllowed,
+    #[msg("Sell exceeds the supply sold through the curve")]
+    SellExceedsSoldSupply,
+    #[msg("Referrer does not match the referral")]
+    ReferrerMismatch,
+    #[msg("Unpause every operation before renouncing admin")]
+    PausedOnRenounce,
+    #[msg("Refund exceeds the supply sold through the curve")]
+    RefundExceedsSoldSupply,
+    #[msg("No launchpad authority transfer is pending")]
+    NoPendingAuthority,
 } 
\ No newline at end of file
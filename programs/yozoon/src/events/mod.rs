@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use crate::dex::MigrationTargetKind;
+use crate::raydium::LpLockMode;
+use crate::state::MigrationThresholds;
 
 /// Event emitted when tokens are purchased
 #[event]
@@ -13,6 +16,10 @@ pub struct TokenPurchaseEvent {
     pub tokens: u64,
     /// Price per token
     pub price: u64,
+    /// Price per token in USD (`USD_DECIMALS`) when the buy was given the Pyth SOL/USD price
+    pub usd_price: Option<u64>,
+    /// Part of the requested SOL not taken because the curve hit `sol_max` (in lamports)
+    pub refunded_sol: u64,
     /// Unix timestamp of the transaction
     pub timestamp: i64,
 }
@@ -63,19 +70,159 @@ pub struct AirdropEvent {
     pub timestamp: i64,
 }
 
-/// Event emitted when migration to Raydium is completed
+/// Event emitted when migration to the target DEX is completed
 #[event]
 pub struct MigrationCompletedEvent {
+    /// DEX the liquidity moved to
+    pub target: MigrationTargetKind,
+    /// Pool state account on the target DEX
+    pub pool_state: Pubkey,
     /// Total SOL value in treasury
     pub sol_value: u64,
     /// Total tokens sold
     pub tokens_sold: u64,
-    /// Admin who performed migration
-    pub admin: Pubkey,
+    /// Account that ran the migration
+    pub caller: Pubkey,
+    /// Bounty paid to the caller from the reserve
+    pub bounty: u64,
     /// Unix timestamp of migration
     pub timestamp: i64,
 }
 
+/// Event emitted when a migrated launch gives up its mint authority and closes the curve
+#[event]
+pub struct MigrationFinalizedEvent {
+    /// Token mint, now without mint or freeze authority
+    pub mint: Pubkey,
+    /// Treasury that received the closed accounts' lamports
+    pub treasury: Pubkey,
+    /// Lamports sent to the treasury
+    pub reclaimed_lamports: u64,
+    /// Account that finalized the migration
+    pub caller: Pubkey,
+    /// Unix timestamp of finalization
+    pub timestamp: i64,
+}
+
+/// Event emitted when the migration LP tokens are burned or locked
+///
+/// Locked LP keeps its principal in the pool for good, but its fee growth is
+/// withdrawn from the lock vault by `claim_pool_fees`.
+#[event]
+pub struct LiquidityLockedEvent {
+    /// Program's pool record
+    pub pool: Pubkey,
+    /// Pool state account on the target DEX
+    pub pool_state: Pubkey,
+    /// LP token or position NFT mint
+    pub lp_mint: Pubkey,
+    /// LP tokens burned, or locked as principal that is never withdrawn
+    pub lp_amount: u64,
+    /// Burned or locked
+    pub mode: LpLockMode,
+    /// Lock vault (default pubkey when burned); signs fee growth withdrawals only
+    pub lp_lock: Pubkey,
+    /// Unix timestamp of the lock
+    pub timestamp: i64,
+}
+
+/// Event emitted when the fee key holder claims pool fees
+#[event]
+pub struct PoolFeesClaimedEvent {
+    /// Program's pool record
+    pub pool: Pubkey,
+    /// Fee key NFT mint
+    pub fee_key_mint: Pubkey,
+    /// NFT holder who received the fees
+    pub holder: Pubkey,
+    /// Key's share of the pool fees in basis points
+    pub share_bps: u64,
+    /// Project tokens received
+    pub token_amount: u64,
+    /// Wrapped SOL received (in lamports)
+    pub sol_amount: u64,
+    /// Unix timestamp of the claim
+    pub timestamp: i64,
+}
+
+/// Event emitted when a fee key is split into a new fee key NFT
+#[event]
+pub struct FeeKeySplitEvent {
+    /// Program's pool record
+    pub pool: Pubkey,
+    /// NFT mint of the key that was split
+    pub parent_mint: Pubkey,
+    /// Share the split key keeps (basis points)
+    pub parent_share_bps: u64,
+    /// NFT mint of the new key
+    pub new_mint: Pubkey,
+    /// Share moved to the new key (basis points)
+    pub new_share_bps: u64,
+    /// Holder who split the key and received the new NFT
+    pub holder: Pubkey,
+}
+
+/// Event emitted when the migration bounty is changed
+#[event]
+pub struct MigrationBountyUpdatedEvent {
+    /// Previous bounty in lamports
+    pub old_bounty: u64,
+    /// New bounty in lamports
+    pub new_bounty: u64,
+    /// Admin who made the change
+    pub admin: Pubkey,
+}
+
+/// Event emitted when the migration thresholds are changed
+#[event]
+pub struct MigrationThresholdsUpdatedEvent {
+    /// Previous thresholds
+    pub old_thresholds: MigrationThresholds,
+    /// New thresholds
+    pub new_thresholds: MigrationThresholds,
+    /// Admin who made the change
+    pub admin: Pubkey,
+}
+
+/// Event emitted when a buy reaches the migration thresholds and trading freezes
+#[event]
+pub struct CurveReadyToMigrateEvent {
+    /// Total SOL raised (in lamports, net of fees)
+    pub total_sol: u64,
+    /// Total tokens sold
+    pub total_supply: u64,
+    /// Unix timestamp of the transaction
+    pub timestamp: i64,
+}
+
+/// Event emitted when a failed launch enters refund mode
+#[event]
+pub struct RefundModeEnteredEvent {
+    /// Account that entered refund mode
+    pub caller: Pubkey,
+    /// Whether the admin entered it before the launch deadline
+    pub by_admin: bool,
+    /// Lamports available for refunds
+    pub reserve: u64,
+    /// Supply sold through the curve, which shares the reserve
+    pub token_supply: u64,
+    /// Unix timestamp of the transaction
+    pub timestamp: i64,
+}
+
+/// Event emitted when a holder burns tokens for their share of the reserve
+#[event]
+pub struct RefundClaimedEvent {
+    /// Holder who burned tokens
+    pub holder: Pubkey,
+    /// Tokens burned
+    pub token_amount: u64,
+    /// Lamports refunded
+    pub sol_amount: u64,
+    /// Unix timestamp of the transaction
+    pub timestamp: i64,
+}
+
 /// Event emitted when admin transfer is initiated
 #[event]
 pub struct AdminTransferInitiatedEvent {
@@ -83,11 +230,24 @@ pub struct AdminTransferInitiatedEvent {
     pub current_admin: Pubkey,
     /// Proposed new admin address
     pub proposed_admin: Pubkey,
+    /// Unix timestamp after which the nomination expires
+    pub expires_at: i64,
+}
+
+/// Event emitted when a pending admin transfer is cancelled
+#[event]
+pub struct AdminTransferCancelledEvent {
+    /// Admin who cancelled the transfer
+    pub admin: Pubkey,
+    /// Nominee whose transfer was cancelled
+    pub cancelled_admin: Pubkey,
 }
 
 /// Event emitted when admin transfer is completed
 #[event]
 pub struct AdminTransferCompletedEvent {
+    /// Previous admin address
+    pub previous_admin: Pubkey,
     /// New admin address
     pub new_admin: Pubkey,
 }
@@ -95,12 +255,82 @@ pub struct AdminTransferCompletedEvent {
 /// Event emitted when pause state is changed
 #[event]
 pub struct PauseStateChangedEvent {
-    /// New pause state (true = paused)
-    pub paused: bool,
+    /// New pause bitmask (set bit = operation paused)
+    pub pause_flags: u8,
     /// Admin who changed pause state
     pub admin: Pubkey,
 }
 
+/// Event emitted when the treasury account is changed
+#[event]
+pub struct TreasuryUpdatedEvent {
+    /// Previous treasury address
+    pub old_treasury: Pubkey,
+    /// New treasury address
+    pub new_treasury: Pubkey,
+    /// Admin who changed the treasury
+    pub admin: Pubkey,
+}
+
+/// Event emitted when the oracle feed allowlist is replaced
+#[event]
+pub struct OracleFeedsUpdatedEvent {
+    /// New allowlist
+    pub feeds: Vec<Pubkey>,
+    /// Admin who changed the allowlist
+    pub admin: Pubkey,
+}
+
+/// Event emitted when the token's Metaplex metadata is changed
+#[event]
+pub struct MetadataUpdatedEvent {
+    /// New token name
+    pub name: String,
+    /// New token symbol
+    pub symbol: String,
+    /// New metadata URI
+    pub uri: String,
+    /// Admin who changed the metadata
+    pub admin: Pubkey,
+}
+
+/// Event emitted when settings are permanently frozen
+#[event]
+pub struct ParametersFrozenEvent {
+    /// Bits frozen by this call
+    pub newly_frozen: u8,
+    /// Full frozen bitmask after this call
+    pub frozen_params: u8,
+    /// Admin who froze the settings
+    pub admin: Pubkey,
+}
+
+/// Event emitted when the admin permanently gives up control
+#[event]
+pub struct AdminRenouncedEvent {
+    /// Admin that renounced
+    pub former_admin: Pubkey,
+    /// Settings frozen at the time of renouncement
+    pub frozen_params: u8,
+    /// Unix timestamp of renouncement
+    pub timestamp: i64,
+}
+
+/// Event emitted when an account is upgraded to the current layout
+#[event]
+pub struct AccountLayoutMigratedEvent {
+    /// Upgraded account
+    pub account: Pubkey,
+    /// Layout version before the upgrade
+    pub from_version: u8,
+    /// Layout version after the upgrade
+    pub to_version: u8,
+    /// Account data length after the upgrade
+    pub data_len: u64,
+    /// Admin who performed the upgrade
+    pub admin: Pubkey,
+}
+
 #[event]
 pub struct MintInitializedEvent {
     pub admin: Pubkey,
@@ -155,6 +385,7 @@ pub struct ReferralFeeUpdatedEvent {
 #[event]
 pub struct MigrationEvent {
     pub total_sol: u64,
+    /// USD value of `total_sol` at the oracle price (`USD_DECIMALS` decimals)
     pub total_usd: u64,
     pub total_supply: u64,
     pub timestamp: i64,
@@ -168,3 +399,71 @@ pub struct TokenSaleEvent {
     pub price: u64,
     pub timestamp: i64,
 }
+
+/// Event emitted when a launch is created
+#[event]
+pub struct LaunchCreatedEvent {
+    /// Mint of the new launch; keys all of its accounts
+    pub mint: Pubkey,
+    /// Creator, and the launch's initial admin
+    pub creator: Pubkey,
+    /// Creation fee paid
+    pub creation_fee: u64,
+    /// Launches created so far, this one included
+    pub launch_count: u64,
+}
+
+/// Event emitted when the launch of before the launchpad moves to mint-keyed accounts
+#[event]
+pub struct LegacyLaunchRekeyedEvent {
+    /// Mint of the launch; now keys all of its accounts
+    pub mint: Pubkey,
+    /// Singleton config PDA it was read from, now closed
+    pub legacy_config: Pubkey,
+    /// Config PDA keyed by the mint
+    pub config: Pubkey,
+    /// Lamports moved from the old SOL reserve to the new one
+    pub reserve_lamports: u64,
+    /// Account that ran the re-key
+    pub caller: Pubkey,
+}
+
+/// Event emitted when the launchpad's fee or creator allowlist changes
+#[event]
+pub struct LaunchpadUpdatedEvent {
+    /// Lamports each launch pays
+    pub creation_fee: u64,
+    /// Receives the creation fees
+    pub fee_recipient: Pubkey,
+    /// Creators allowed besides the authority
+    pub creators: Vec<Pubkey>,
+    /// Authority who made the change
+    pub authority: Pubkey,
+}
+
+/// Event emitted when the launchpad authority nominates a successor
+#[event]
+pub struct LaunchpadAuthorityTransferInitiatedEvent {
+    /// Current launchpad authority
+    pub current_authority: Pubkey,
+    /// Nominee who may accept the role
+    pub proposed_authority: Pubkey,
+}
+
+/// Event emitted when a pending launchpad authority transfer is cancelled
+#[event]
+pub struct LaunchpadAuthorityTransferCancelledEvent {
+    /// Authority who cancelled the transfer
+    pub authority: Pubkey,
+    /// Nominee whose transfer was cancelled
+    pub cancelled_authority: Pubkey,
+}
+
+/// Event emitted when the nominee accepts the launchpad authority role
+#[event]
+pub struct LaunchpadAuthorityTransferCompletedEvent {
+    /// Previous launchpad authority
+    pub previous_authority: Pubkey,
+    /// New launchpad authority
+    pub new_authority: Pubkey,
+}
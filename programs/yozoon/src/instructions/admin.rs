@@ -1,57 +1,257 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::Discriminator;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    default_account_state::instruction::initialize_default_account_state,
+    transfer_fee::instruction::initialize_transfer_fee_config,
+};
+use anchor_spl::token_2022::spl_token_2022::state::AccountState;
+use anchor_spl::token_interface;
 use crate::errors::YozoonError;
 use crate::events::*;
 use crate::instructions::contexts::*;
+use crate::metadata::{create_metadata_account_v3, metadata_address, update_metadata_account_v2, DataV2};
+use crate::state::*;
+use crate::utils::token::{initialize_metadata_pointer, mint_space};
+use crate::utils::constants::{
+    freeze_flags, DEFAULT_MIGRATION_BOUNTY, MAX_DECIMALS, MAX_MIGRATION_BOUNTY, MAX_ORACLE_FEEDS,
+    seeds,
+};
 
-/// Initialize the token mint and configuration account
-pub fn initialize_mint(ctx: Context<InitializeMint>) -> Result<()> {
-    // Store treasury value before mutable borrow
-    let treasury = ctx.accounts.config.treasury;
+/// Create a launch: the token mint, its Metaplex metadata and the configuration account
+///
+/// Only the launchpad authority and its allowlisted creators may create launches,
+/// paying the launchpad's creation fee. Every launch account is keyed by the mint.
+///
+/// `max_supply` caps everything ever minted, in base units of a mint with
+/// `decimals` decimals. `extensions` need the mint to be created under Token-2022.
+pub fn initialize_mint(
+    ctx: Context<InitializeMint>,
+    name: String,
+    symbol: String,
+    uri: String,
+    decimals: u8,
+    max_supply: u64,
+    extensions: MintExtensions
+) -> Result<()> {
+    let data = DataV2::fungible(name, symbol, uri)?;
+    require!(decimals <= MAX_DECIMALS && max_supply > 0, YozoonError::InvalidParameter);
+    extensions.validate(&ctx.accounts.token_program.key())?;
+    require!(
+        ctx.accounts.launchpad.is_creator_allowed(&ctx.accounts.admin.key()),
+        YozoonError::CreatorNotAllowed
+    );
     
+    // Pay the creation fee
+    let creation_fee = ctx.accounts.launchpad.creation_fee;
+    if creation_fee > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: ctx.accounts.fee_recipient.to_account_info(),
+                },
+            ),
+            creation_fee,
+        )?;
+    }
+    
+    create_mint(&ctx, decimals, &extensions)?;
     let config = &mut ctx.accounts.config;
     
     // Set up the config account
+    config.version = Config::VERSION;
     config.admin = ctx.accounts.admin.key();
     config.mint = ctx.accounts.mint.key();
     config.bump = *ctx.bumps.get("config").unwrap();
-    config.paused = false;
-    config.treasury = treasury;
+    config.pause_flags = 0;
+    // Treasury defaults to the admin until changed with `set_treasury`
+    config.treasury = ctx.accounts.admin.key();
     config.pending_admin = None;
+    config.pending_admin_deadline = 0;
+    config.frozen_params = 0;
+    config.migration_bounty = DEFAULT_MIGRATION_BOUNTY;
+    config.oracle_feeds = Config::default_oracle_feeds();
+    config.decimals = decimals;
+    config.max_supply = max_supply;
+    
+    // The config PDA is both mint authority and metadata update authority
+    let config_key = config.key();
+    invoke_signed(
+        &create_metadata_account_v3(
+            ctx.accounts.metadata.key(),
+            ctx.accounts.mint.key(),
+            config_key,
+            ctx.accounts.admin.key(),
+            config_key,
+            data,
+            true,
+        ),
+        &[
+            ctx.accounts.metadata.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            config.to_account_info(),
+            ctx.accounts.admin.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+        ],
+        &[&config.signer_seeds()],
+    )?;
+    
+    let launchpad = &mut ctx.accounts.launchpad;
+    launchpad.launch_count += 1;
+    
+    // Emit event for frontend tracking
+    emit!(LaunchCreatedEvent {
+        mint: config.mint,
+        creator: config.admin,
+        creation_fee,
+        launch_count: launchpad.launch_count,
+    });
     
-    msg!("Yozoon token initialized with admin: {}", config.admin);
+    msg!("Launch {} created with admin: {}", config.mint, config.admin);
     Ok(())
 }
 
-/// Transfer admin role to a new account (two-step process)
-pub fn transfer_admin(ctx: Context<AdminAction>, new_admin: Pubkey) -> Result<()> {
-    let config = &mut ctx.accounts.config;
+/// Create the mint account, initialize its extensions, then the mint itself
+///
+/// The config PDA is mint authority, and freeze authority when accounts start frozen.
+fn create_mint(ctx: &Context<InitializeMint>, decimals: u8, extensions: &MintExtensions) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let token_program = accounts.token_program.key();
+    let mint = accounts.mint.key();
+    let config = accounts.config.key();
+    
+    let space = mint_space(extensions);
+    anchor_lang::system_program::create_account(
+        CpiContext::new(
+            accounts.system_program.to_account_info(),
+            anchor_lang::system_program::CreateAccount {
+                from: accounts.admin.to_account_info(),
+                to: accounts.mint.to_account_info(),
+            },
+        ),
+        accounts.rent.minimum_balance(space),
+        space as u64,
+        &token_program,
+    )?;
     
-    // Verify not setting to the same admin
-    require!(config.admin != new_admin, YozoonError::InvalidParameter);
+    // Extensions go in before the mint is initialized; the metadata pointer last, so
+    // older readers find the others before reaching a type they do not know
+    let mint_info = [accounts.mint.to_account_info()];
+    if let Some(fee) = extensions.transfer_fee {
+        // No fee authority, so the rate is fixed for good; the admin withdraws withheld fees
+        invoke(
+            &initialize_transfer_fee_config(
+                &token_program,
+                &mint,
+                None,
+                Some(&accounts.admin.key()),
+                fee.basis_points,
+                fee.maximum_fee,
+            )?,
+            &mint_info,
+        )?;
+    }
+    if extensions.non_transferable_until_migration {
+        invoke(
+            &initialize_default_account_state(&token_program, &mint, &AccountState::Frozen)?,
+            &mint_info,
+        )?;
+    }
+    if extensions.metadata_pointer {
+        invoke(
+            &initialize_metadata_pointer(&token_program, &mint, &config, &metadata_address(&mint)),
+            &mint_info,
+        )?;
+    }
     
-    // Set pending admin
-    config.pending_admin = Some(new_admin);
+    token_interface::initialize_mint2(
+        CpiContext::new(
+            accounts.token_program.to_account_info(),
+            token_interface::InitializeMint2 {
+                mint: accounts.mint.to_account_info(),
+            },
+        ),
+        decimals,
+        &config,
+        extensions.non_transferable_until_migration.then_some(&config),
+    )
+}
+
+/// Update the token's name, symbol and URI until metadata is frozen
+pub fn update_metadata(ctx: Context<UpdateMetadata>, name: String, symbol: String, uri: String) -> Result<()> {
+    let config = &ctx.accounts.config;
+    
+    require!(!config.is_frozen(freeze_flags::METADATA), YozoonError::ParametersFrozen);
+    
+    let data = DataV2::fungible(name, symbol, uri)?;
+    let (name, symbol, uri) = (data.name.clone(), data.symbol.clone(), data.uri.clone());
+    invoke_signed(
+        &update_metadata_account_v2(ctx.accounts.metadata.key(), config.key(), data),
+        &[
+            ctx.accounts.metadata.to_account_info(),
+            config.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+        ],
+        &[&config.signer_seeds()],
+    )?;
+    
+    // Emit event for frontend tracking
+    emit!(MetadataUpdatedEvent {
+        name,
+        symbol,
+        uri,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    msg!("Token metadata updated");
+    Ok(())
+}
+
+/// Transfer admin role to a new account (two-step process)
+pub fn transfer_admin(ctx: Context<AdminAction>, new_admin: Pubkey) -> Result<()> {
+    // Set pending admin with a nomination deadline (overwrites any earlier nomination)
+    let expires_at = ctx.accounts.config.nominate_admin(new_admin, Clock::get()?.unix_timestamp)?;
     
     // Emit event for frontend tracking
     emit!(AdminTransferInitiatedEvent {
         current_admin: ctx.accounts.admin.key(),
         proposed_admin: new_admin,
+        expires_at,
+    });
+    
+    msg!("Admin transfer initiated to: {} (expires at {})", new_admin, expires_at);
+    Ok(())
+}
+
+/// Cancel a pending admin transfer
+pub fn cancel_admin_transfer(ctx: Context<AdminAction>) -> Result<()> {
+    // Clear the nomination
+    let cancelled_admin = ctx.accounts.config.cancel_admin_nomination()?;
+    
+    // Emit event for frontend tracking
+    emit!(AdminTransferCancelledEvent {
+        admin: ctx.accounts.admin.key(),
+        cancelled_admin,
     });
     
-    msg!("Admin transfer initiated to: {}", new_admin);
+    msg!("Admin transfer to {} cancelled", cancelled_admin);
     Ok(())
 }
 
-/// Accept admin role (must be called by pending admin)
+/// Accept admin role (must be called by pending admin before the deadline)
 pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
     let config = &mut ctx.accounts.config;
     
-    // Update admin
-    config.admin = ctx.accounts.pending_admin.key();
-    config.pending_admin = None;
+    // Update admin, rejecting expired nominations
+    let previous_admin = config.accept_admin(Clock::get()?.unix_timestamp)?;
     
     // Emit event for frontend tracking
     emit!(AdminTransferCompletedEvent {
+        previous_admin,
         new_admin: config.admin,
     });
     
@@ -59,24 +259,234 @@ pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
     Ok(())
 }
 
-/// Emergency pause/unpause of the protocol
-pub fn set_pause_state(ctx: Context<AdminAction>, paused: bool) -> Result<()> {
-    let config = &mut ctx.accounts.config;
-    
-    // Don't update if already in the requested state
-    if config.paused == paused {
+/// Emergency pause/unpause of individual operations
+///
+/// `pause_mask` replaces the current flags; a set bit pauses that operation
+pub fn set_pause_state(ctx: Context<AdminAction>, pause_mask: u8) -> Result<()> {
+    // Update pause state; nothing to announce if already in the requested state
+    if !ctx.accounts.config.set_pause_flags(pause_mask)? {
         return Ok(());
     }
     
-    // Update pause state
-    config.paused = paused;
-    
     // Emit event for frontend tracking
     emit!(PauseStateChangedEvent {
-        paused,
+        pause_flags: pause_mask,
         admin: ctx.accounts.admin.key(),
     });
     
-    msg!("Protocol {} by admin", if paused { "paused" } else { "unpaused" });
+    msg!("Pause flags set to {:#07b} by admin", pause_mask);
     Ok(())
 }
+
+/// Update the treasury account that receives SOL from the protocol
+pub fn set_treasury(ctx: Context<AdminAction>, new_treasury: Pubkey) -> Result<()> {
+    let old_treasury = ctx.accounts.config.set_treasury(new_treasury)?;
+    
+    // Emit event for frontend tracking
+    emit!(TreasuryUpdatedEvent {
+        old_treasury,
+        new_treasury,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    msg!("Treasury updated to: {}", new_treasury);
+    Ok(())
+}
+
+/// Update the bounty paid to whoever runs the migration
+pub fn set_migration_bounty(ctx: Context<AdminAction>, new_bounty: u64) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    // The bounty is a fee on the reserve
+    require!(!config.is_frozen(freeze_flags::FEES), YozoonError::ParametersFrozen);
+    require!(new_bounty <= MAX_MIGRATION_BOUNTY, YozoonError::InvalidParameter);
+    
+    let old_bounty = config.migration_bounty;
+    config.migration_bounty = new_bounty;
+    
+    // Emit event for frontend tracking
+    emit!(MigrationBountyUpdatedEvent {
+        old_bounty,
+        new_bounty,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    msg!("Migration bounty set to {} lamports", new_bounty);
+    Ok(())
+}
+
+/// Permanently freeze one or more settings groups
+///
+/// Bits in `freeze_mask` are added to the existing frozen set and can never be cleared
+pub fn freeze_parameters(ctx: Context<AdminAction>, freeze_mask: u8) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let newly_frozen = config.freeze(freeze_mask)?;
+    
+    // Emit event for frontend tracking
+    emit!(ParametersFrozenEvent {
+        newly_frozen,
+        frozen_params: config.frozen_params,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    msg!("Parameters frozen: {:#05b}", config.frozen_params);
+    Ok(())
+}
+
+/// Replace the allowlist of Pyth price accounts the program reads SOL/USD from
+pub fn set_oracle_feeds(ctx: Context<AdminAction>, feeds: Vec<Pubkey>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    require!(!config.is_frozen(freeze_flags::ORACLE_FEEDS), YozoonError::ParametersFrozen);
+    
+    // At least one feed, no empty slots in the list
+    require!(
+        !feeds.is_empty() && feeds.len() <= MAX_ORACLE_FEEDS,
+        YozoonError::InvalidParameter
+    );
+    require!(
+        feeds.iter().all(|feed| *feed != Pubkey::default()),
+        YozoonError::InvalidParameter
+    );
+    
+    config.oracle_feeds = [Pubkey::default(); MAX_ORACLE_FEEDS];
+    config.oracle_feeds[..feeds.len()].copy_from_slice(&feeds);
+    
+    // Emit event for frontend tracking
+    emit!(OracleFeedsUpdatedEvent {
+        feeds: feeds.clone(),
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    msg!("Oracle allowlist set to {} feeds", feeds.len());
+    Ok(())
+}
+
+/// Permanently give up admin control
+///
+/// Clears `admin` and any pending transfer, so no admin instruction can be called again.
+/// Fails while any operation is paused, since it could never be unpaused.
+pub fn renounce_admin(ctx: Context<AdminAction>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let former_admin = config.admin;
+    config.renounce_admin()?;
+    
+    // Emit event for frontend tracking
+    emit!(AdminRenouncedEvent {
+        former_admin,
+        frozen_params: config.frozen_params,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Admin {} renounced control", former_admin);
+    Ok(())
+}
+
+/// Upgrade a program account to the current layout, reallocating it if the layout grew
+pub fn migrate_account_layout(ctx: Context<MigrateAccountLayout>) -> Result<()> {
+    // Config itself may still be in an old layout, so decode it by version
+    let (config, _) = load_any_layout::<Config>(&ctx.accounts.config.try_borrow_data()?)?;
+    require!(config.admin == ctx.accounts.admin.key(), YozoonError::Unauthorized);
+    let mint = config.mint;
+    require_keys_eq!(
+        ctx.accounts.config.key(),
+        Pubkey::find_program_address(&[seeds::CONFIG, mint.as_ref()], &crate::ID).0,
+        YozoonError::InvalidParameter
+    );
+    
+    let target = ctx.accounts.target.to_account_info();
+    let payer = ctx.accounts.admin.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    
+    // Dispatch on the account type
+    let mut discriminator = [0u8; 8];
+    {
+        let data = target.try_borrow_data()?;
+        require!(data.len() >= 8, ErrorCode::AccountDiscriminatorNotFound);
+        discriminator.copy_from_slice(&data[..8]);
+    }
+    // Only the launch's own accounts may be upgraded, found at their PDAs under its mint
+    let (from_version, to_version) = if discriminator == Config::discriminator() {
+        upgrade_layout::<Config>(&target, &[seeds::CONFIG, mint.as_ref()], &payer, &system_program, |_| {})?
+    } else if discriminator == BondingCurve::discriminator() {
+        upgrade_layout::<BondingCurve>(&target, &[seeds::BONDING_CURVE, mint.as_ref()], &payer, &system_program, |_| {})?
+    } else if discriminator == Referral::discriminator() {
+        let user = ctx.accounts.user.as_ref().ok_or(YozoonError::InvalidParameter)?.key();
+        let referral_seeds: &[&[u8]] = &[seeds::REFERRAL_PREFIX, mint.as_ref(), user.as_ref()];
+        // Referrals before v2 did not record their launch
+        upgrade_layout::<Referral>(&target, referral_seeds, &payer, &system_program, |referral| {
+            if referral.mint == Pubkey::default() {
+                referral.mint = mint;
+            }
+        })?
+    } else if discriminator == AirdropLedger::discriminator() {
+        upgrade_layout::<AirdropLedger>(&target, &[seeds::AIRDROP_LEDGER, mint.as_ref()], &payer, &system_program, |_| {})?
+    } else {
+        return err!(ErrorCode::AccountDiscriminatorMismatch);
+    };
+    
+    // Emit event for frontend tracking
+    emit!(AccountLayoutMigratedEvent {
+        account: target.key(),
+        from_version,
+        to_version,
+        data_len: target.data_len() as u64,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    msg!("Account {} upgraded from layout v{} to v{}", target.key(), from_version, to_version);
+    Ok(())
+}
+
+/// Rewrite `target` in the current layout of `T`, growing it (and its rent) if needed
+///
+/// `target` must be the PDA of `seeds`; `fill` sets new fields that depend on the launch
+/// rather than on the account alone.
+fn upgrade_layout<'info, T: VersionedAccount>(
+    target: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    fill: impl FnOnce(&mut T),
+) -> Result<(u8, u8)> {
+    require_keys_eq!(
+        target.key(),
+        Pubkey::find_program_address(seeds, &crate::ID).0,
+        YozoonError::InvalidParameter
+    );
+    
+    let (mut account, from_version) = load_any_layout::<T>(&target.try_borrow_data()?)?;
+    
+    let new_len = 8 + T::CURRENT_LEN;
+    require!(
+        from_version < T::VERSION || target.data_len() < new_len,
+        YozoonError::AccountLayoutCurrent
+    );
+    
+    if target.data_len() < new_len {
+        // Top up rent for the larger account before growing it
+        let required = Rent::get()?.minimum_balance(new_len).saturating_sub(target.lamports());
+        if required > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    anchor_lang::system_program::Transfer {
+                        from: payer.clone(),
+                        to: target.clone(),
+                    },
+                ),
+                required,
+            )?;
+        }
+        target.realloc(new_len, false)?;
+    }
+    
+    // Clear stale bytes from the old layout before writing the new one
+    fill(&mut account);
+    account.set_version(T::VERSION);
+    let mut data = target.try_borrow_mut_data()?;
+    data.fill(0);
+    account.try_serialize(&mut &mut data[..])?;
+    
+    Ok((from_version, T::VERSION))
+}
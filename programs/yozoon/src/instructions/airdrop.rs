@@ -1,46 +1,64 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token;
+use anchor_spl::token_interface;
 use crate::errors::YozoonError;
 use crate::events::*;
 use crate::instructions::contexts::*;
+use crate::state::*;
 use crate::utils::constants::*;
+use crate::utils::token::with_thawed;
 
 /// Airdrops tokens to a recipient without affecting the bonding curve
+///
+/// Only while the curve is trading: later airdrops would land after the launch's terms
+/// are settled, and a refunding curve no longer holds back supply for the pool.
 pub fn airdrop_tokens(
     ctx: Context<AirdropTokens>,
     amount: u64
 ) -> Result<()> {
-    // Check if protocol is paused
-    require!(!ctx.accounts.config.paused, YozoonError::ProtocolPaused);
+    // Check if airdrops are paused
+    require!(!ctx.accounts.config.is_paused(pause_flags::AIRDROP), YozoonError::ProtocolPaused);
     
     // Verify amount is non-zero
     require!(amount > 0, YozoonError::InvalidParameter);
     
+    ctx.accounts.bonding_curve.require_trading()?;
+    
     let ledger = &mut ctx.accounts.airdrop_ledger;
     
-    // Ensure total supply (sold + airdropped) isn't exceeded
-    require!(
-        ledger.total_airdropped + amount <= TOTAL_SUPPLY,
-        YozoonError::SupplyExceeded
-    );
+    // Set up the ledger on first use
+    if ledger.version == 0 {
+        ledger.version = AirdropLedger::VERSION;
+        ledger.bump = *ctx.bumps.get("airdrop_ledger").unwrap();
+    }
     
-    // Mint tokens to the recipient
-    token::mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            token::MintTo {
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.recipient_token_account.to_account_info(),
-                authority: ctx.accounts.config.to_account_info(),
-            },
-            &[&[
-                crate::utils::constants::seeds::CONFIG,
-                &[ctx.accounts.config.bump]
-            ]],
-        ),
+    // Keep the mint supply and the pool liquidity still to come within the max supply
+    ctx.accounts.bonding_curve.require_within_supply(
+        ctx.accounts.mint.supply,
         amount,
+        0,
+        ctx.accounts.config.max_supply(),
     )?;
     
+    // Mint tokens to the recipient
+    let config_seeds: &[&[u8]] = &ctx.accounts.config.signer_seeds();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let mint = ctx.accounts.mint.to_account_info();
+    let config = ctx.accounts.config.to_account_info();
+    with_thawed(&token_program, &mint, &ctx.accounts.recipient_token_account, &config, config_seeds, || {
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                token_interface::MintTo {
+                    mint: mint.clone(),
+                    to: ctx.accounts.recipient_token_account.to_account_info(),
+                    authority: config.clone(),
+                },
+                &[config_seeds],
+            ),
+            amount,
+        )
+    })?;
+    
     // Update airdrop ledger
     ledger.total_airdropped += amount;
     
@@ -1,25 +1,53 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token;
+use anchor_spl::token_interface;
+use crate::dex::MigrationTargetKind;
 use crate::errors::YozoonError;
 use crate::events::*;
 use crate::instructions::contexts::*;
+use crate::state::*;
 use crate::utils::constants::*;
 use crate::utils::helpers::*;
+use crate::utils::oracle::{lamports_to_usd, load_validated_price, usd_to_lamports};
+use crate::utils::token::with_thawed;
+use pyth_sdk_solana::Price;
 
-/// Sets up the bonding curve with predefined price points
+/// Sets up the bonding curve with predefined price points, migration thresholds,
+/// the DEX it migrates to and a launch deadline (0 = none, only without a USD threshold)
 pub fn initialize_bonding_curve(
     ctx: Context<InitializeBondingCurve>,
-    price_points: Vec<u64>
+    price_points: Vec<u64>,
+    thresholds: MigrationThresholds,
+    migration_target: MigrationTargetKind,
+    launch_deadline: i64
 ) -> Result<()> {
+    require!(
+        !ctx.accounts.config.is_frozen(freeze_flags::PRICE_POINTS),
+        YozoonError::ParametersFrozen
+    );
+    thresholds.validate(ctx.accounts.config.max_supply())?;
+    require!(
+        launch_deadline == 0 || launch_deadline > Clock::get()?.unix_timestamp,
+        YozoonError::InvalidParameter
+    );
+    // A USD threshold can keep a full curve from migrating, so it needs a way out
+    require!(
+        launch_deadline != 0 || thresholds.usd_min == 0,
+        YozoonError::InvalidParameter
+    );
+    
     // Validate number of price points
     require!(
         price_points.len() <= MAX_PRICE_POINTS,
         YozoonError::TooManyPricePoints
     );
     
-    // Validate we have at least 2 price points for interpolation
+    // Validate we have at least 2 price points for interpolation, splitting the max
+    // supply into equal, non-empty segments
+    let max_supply = ctx.accounts.config.max_supply();
     require!(
-        price_points.len() >= 2,
+        price_points.len() >= 2
+            && price_points.len() as u64 <= max_supply
+            && max_supply.is_multiple_of(price_points.len() as u64),
         YozoonError::InvalidParameter
     );
     
@@ -34,57 +62,176 @@ pub fn initialize_bonding_curve(
     let curve = &mut ctx.accounts.bonding_curve;
     
     // Initialize bonding curve state
+    curve.version = BondingCurve::VERSION;
     curve.total_sold_supply = 0;
     curve.total_sol_raised = 0;
     curve.price_points = price_points;
     curve.bump = *ctx.bumps.get("bonding_curve").unwrap();
     curve.is_migrated = false;
+    curve.thresholds = thresholds;
+    curve.state = CurveState::Trading;
+    curve.migration_target = migration_target;
+    curve.launch_deadline = launch_deadline;
+    curve.ready_since = 0;
+    
+    ctx.accounts.price_observations.initialize(
+        *ctx.bumps.get("price_observations").unwrap(),
+        Clock::get()?.unix_timestamp,
+    );
+    
+    // Keep the SOL reserve rent-exempt so sells can drain it down to the curve's balance
+    let reserve = &ctx.accounts.sol_reserve;
+    let rent_top_up = Rent::get()?.minimum_balance(0).saturating_sub(reserve.lamports());
+    if rent_top_up > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: reserve.to_account_info(),
+                },
+            ),
+            rent_top_up,
+        )?;
+    }
     
-    msg!("Bonding curve initialized with {} price points", curve.price_points.len());
+    msg!("Bonding curve initialized with {} price points, migrating to {:?}", curve.price_points.len(), migration_target);
+    Ok(())
+}
+
+/// Change the migration thresholds; only allowed before the first sale
+pub fn set_migration_thresholds(
+    ctx: Context<SetMigrationThresholds>,
+    thresholds: MigrationThresholds
+) -> Result<()> {
+    require!(
+        !ctx.accounts.config.is_frozen(freeze_flags::MIGRATION_THRESHOLDS),
+        YozoonError::ParametersFrozen
+    );
+    thresholds.validate(ctx.accounts.config.max_supply())?;
+    
+    let curve = &mut ctx.accounts.bonding_curve;
+    
+    // Thresholds are part of the launch terms, so they are fixed once trading starts
+    require!(
+        curve.total_sold_supply == 0 && curve.total_sol_raised == 0,
+        YozoonError::InvalidParameter
+    );
+    
+    let old_thresholds = curve.thresholds();
+    curve.thresholds = thresholds;
+    
+    // Emit event for frontend tracking
+    emit!(MigrationThresholdsUpdatedEvent {
+        old_thresholds,
+        new_thresholds: thresholds,
+        admin: ctx.accounts.admin.key(),
+    });
+    
+    msg!(
+        "Migration thresholds set to {}..={} lamports, {} tokens",
+        thresholds.sol_min,
+        thresholds.sol_max,
+        thresholds.supply_threshold
+    );
     Ok(())
 }
 
 /// Allows users to buy tokens with SOL, applying referral fees if set
+///
+/// Tokens are minted, not transferred, so a Token-2022 transfer fee does not apply.
+///
+/// If the Pyth SOL/USD account is passed, the purchase event also carries the USD price.
 pub fn buy_tokens(ctx: Context<BuyTokens>, sol_amount: u64) -> Result<()> {
-    // Check if protocol is paused
-    require!(!ctx.accounts.config.paused, YozoonError::ProtocolPaused);
+    let sol_usd = match &ctx.accounts.sol_usd_price {
+        Some(oracle) => Some(load_validated_price(&ctx.accounts.config, oracle, Clock::get()?.unix_timestamp)?),
+        None => None,
+    };
+    buy(ctx, sol_amount, sol_usd)
+}
+
+/// Buy `usd_amount` worth of tokens (with `USD_DECIMALS` decimals), paying at most
+/// `max_sol_in` lamports
+///
+/// The amount is converted at the validated Pyth SOL/USD price, then bought like
+/// `buy_tokens`.
+pub fn buy_tokens_usd(ctx: Context<BuyTokens>, usd_amount: u64, max_sol_in: u64) -> Result<()> {
+    let oracle = ctx.accounts.sol_usd_price.as_ref().ok_or(YozoonError::InvalidOracleAccount)?;
+    let sol_usd = load_validated_price(&ctx.accounts.config, oracle, Clock::get()?.unix_timestamp)?;
+    
+    let sol_amount = usd_to_lamports(usd_amount, &sol_usd).ok_or(YozoonError::MathOverflow)?;
+    require!(sol_amount <= max_sol_in, YozoonError::SlippageExceeded);
+    
+    msg!("Buying ${} worth of tokens for {} lamports", usd_amount, sol_amount);
+    buy(ctx, sol_amount, Some(sol_usd))
+}
+
+fn buy(ctx: Context<BuyTokens>, sol_amount: u64, sol_usd: Option<Price>) -> Result<()> {
+    // Check if buys are paused
+    require!(!ctx.accounts.config.is_paused(pause_flags::BUY), YozoonError::ProtocolPaused);
     
     let curve = &mut ctx.accounts.bonding_curve;
     
-    // Prevent purchases after migration
-    require!(!curve.is_migrated, YozoonError::Migrated);
+    // Prevent purchases after migration, and once the curve is waiting to migrate
+    curve.require_trading()?;
     
     // Validate minimum purchase amount
     require!(sol_amount >= MINIMUM_SOL_PURCHASE, YozoonError::AmountTooSmall);
     
+    // A referral's fee goes to its referrer, so one cannot be given without the other
+    require!(
+        ctx.accounts.referral.is_none() || ctx.accounts.referrer.is_some(),
+        YozoonError::ReferrerMismatch
+    );
+    
+    // While referrals are paused the buy goes through without paying one
+    let fee_percentage = if ctx.accounts.config.is_paused(pause_flags::REFERRAL) {
+        0
+    } else {
+        ctx.accounts.referral.as_ref().map_or(0, |referral| referral.fee_percentage)
+    };
+    
+    // Fill only up to `sol_max`; the excess is refunded by never taking it from the buyer
+    let capacity = curve.remaining_sol_capacity();
+    let sol_after_fee = sol_amount
+        .checked_sub(mul_div(sol_amount, fee_percentage, 10_000).ok_or(YozoonError::MathOverflow)?)
+        .ok_or(YozoonError::MathOverflow)?;
+    let filled_sol = if sol_after_fee > capacity {
+        mul_div(capacity, 10_000, 10_000 - fee_percentage).ok_or(YozoonError::MathOverflow)?
+    } else {
+        sol_amount
+    };
+    let refunded_sol = sol_amount.checked_sub(filled_sol).ok_or(YozoonError::MathOverflow)?;
+    
     // Calculate current token price
+    let max_supply = ctx.accounts.config.max_supply();
     let supply = curve.total_sold_supply;
-    let price = calculate_token_price(supply, &curve.price_points);
+    let price = calculate_token_price(supply, &curve.price_points, max_supply);
+    let usd_price = sol_usd
+        .map(|sol_usd| lamports_to_usd(price, &sol_usd).ok_or(YozoonError::MathOverflow))
+        .transpose()?;
+    let timestamp = Clock::get()?.unix_timestamp;
+    
+    // Calculate fees if referral exists; only what reaches the reserve buys tokens
+    let referral_fee = mul_div(filled_sol, fee_percentage, 10_000).ok_or(YozoonError::MathOverflow)?;
+    let net_sol = filled_sol.checked_sub(referral_fee).ok_or(YozoonError::MathOverflow)?;
     
     // Calculate tokens to mint
-    let tokens = sol_amount * PRECISION_FACTOR / price;
+    let tokens = mul_div(net_sol, ctx.accounts.config.token_unit(), price).ok_or(YozoonError::MathOverflow)?;
     
     // Ensure non-zero tokens (prevent dust amounts)
     require!(tokens > 0, YozoonError::DustAmount);
     
-    // Check supply limit
-    require!(supply + tokens <= TOTAL_SUPPLY, YozoonError::SupplyExceeded);
-    
-    // Calculate fees if referral exists
-    let (net_sol, referral_fee) = if let Some(referral) = &ctx.accounts.referral {
-        let fee = sol_amount * referral.fee_percentage / 10_000;
-        (sol_amount - fee, fee)
-    } else {
-        (sol_amount, 0)
-    };
+    // Check supply limit, leaving room to match the sold supply in the pool
+    curve.require_within_supply(ctx.accounts.mint.supply, tokens, tokens, max_supply)?;
     
-    // Transfer SOL to treasury
+    // Transfer SOL to the reserve
     anchor_lang::system_program::transfer(
         CpiContext::new(
             ctx.accounts.system_program.to_account_info(),
             anchor_lang::system_program::Transfer {
                 from: ctx.accounts.buyer.to_account_info(),
-                to: ctx.accounts.treasury.to_account_info(),
+                to: ctx.accounts.sol_reserve.to_account_info(),
             },
         ),
         net_sol,
@@ -107,37 +254,57 @@ pub fn buy_tokens(ctx: Context<BuyTokens>, sol_amount: u64) -> Result<()> {
     }
     
     // Mint tokens to the user's token account
-    token::mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            token::MintTo {
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.buyer_token_account.to_account_info(),
-                authority: ctx.accounts.config.to_account_info(),
-            },
-            &[&[
-                crate::utils::constants::seeds::CONFIG,
-                &[ctx.accounts.config.bump]
-            ]],
-        ),
-        tokens,
-    )?;
-    
-    // Update bonding curve state
+    let config_seeds: &[&[u8]] = &ctx.accounts.config.signer_seeds();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let mint = ctx.accounts.mint.to_account_info();
+    let config = ctx.accounts.config.to_account_info();
+    with_thawed(&token_program, &mint, &ctx.accounts.buyer_token_account, &config, config_seeds, || {
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                token_interface::MintTo {
+                    mint: mint.clone(),
+                    to: ctx.accounts.buyer_token_account.to_account_info(),
+                    authority: config.clone(),
+                },
+                &[config_seeds],
+            ),
+            tokens,
+        )
+    })?;
+    
+    // Close out the old price in the history, then update bonding curve state
+    ctx.accounts.price_observations.record(price, timestamp);
     curve.total_sold_supply += tokens;
     curve.total_sol_raised += net_sol;
     
     // Emit event for frontend tracking
     emit!(TokenPurchaseEvent {
         user: ctx.accounts.buyer.key(),
-        sol_amount,
+        sol_amount: filled_sol,
         net_sol,
         tokens,
         price,
-        timestamp: Clock::get()?.unix_timestamp,
+        usd_price,
+        refunded_sol,
+        timestamp,
     });
     
-    msg!("User purchased {} tokens for {} SOL", tokens, sol_amount);
+    msg!("User purchased {} tokens for {} SOL ({} refunded)", tokens, filled_sol, refunded_sol);
+    
+    // Freeze trading once this buy reaches the migration thresholds
+    if curve.is_migration_ready() {
+        curve.state = CurveState::ReadyToMigrate;
+        curve.ready_since = timestamp;
+        
+        emit!(CurveReadyToMigrateEvent {
+            total_sol: curve.total_sol_raised,
+            total_supply: curve.total_sold_supply,
+            timestamp,
+        });
+        
+        msg!("Bonding curve ready to migrate at {} lamports", curve.total_sol_raised);
+    }
     Ok(())
 }
 
@@ -147,7 +314,7 @@ pub fn calculate_current_price(ctx: Context<GetCurrentPrice>) -> Result<u64> {
     let supply = curve.total_sold_supply;
     
     // Use extracted helper function
-    let price = calculate_token_price(supply, &curve.price_points);
+    let price = calculate_token_price(supply, &curve.price_points, ctx.accounts.config.max_supply());
     
     // Emit event for frontend tracking
     emit!(PriceCalculatedEvent {
@@ -170,8 +337,9 @@ pub fn calculate_tokens_for_sol(
     // Prevent calculations after migration
     require!(!curve.is_migrated, YozoonError::Migrated);
     
+    let config = &ctx.accounts.config;
     let supply = curve.total_sold_supply;
-    let price = calculate_token_price(supply, &curve.price_points);
+    let price = calculate_token_price(supply, &curve.price_points, config.max_supply());
     
     // Calculate tokens without referral fee since this is just a calculation
     let fee_percentage = 0; // No referral fee for calculations
@@ -179,14 +347,14 @@ pub fn calculate_tokens_for_sol(
         sol_amount,
         fee_percentage,
         price,
-        PRECISION_FACTOR
+        config.token_unit()
     );
     
     // Ensure non-zero tokens (prevent dust amounts)
     require!(tokens > 0, YozoonError::DustAmount);
     
-    // Check supply limit
-    require!(supply + tokens <= TOTAL_SUPPLY, YozoonError::SupplyExceeded);
+    // Check supply limit, leaving room to match the sold supply in the pool
+    curve.require_within_supply(ctx.accounts.mint.supply, tokens, tokens, config.max_supply())?;
     
     // Emit event for frontend tracking
     emit!(TokenCalculationEvent {
@@ -201,56 +369,81 @@ pub fn calculate_tokens_for_sol(
 }
 
 /// Allows users to sell tokens for SOL
+///
+/// Tokens are burned, not transferred, so a Token-2022 transfer fee does not apply.
 pub fn sell_tokens(ctx: Context<SellTokens>, token_amount: u64) -> Result<()> {
+    // Check if sells are paused
+    require!(!ctx.accounts.config.is_paused(pause_flags::SELL), YozoonError::ProtocolPaused);
+    
     let curve = &mut ctx.accounts.bonding_curve;
     
-    // Prevent sales after migration
-    require!(!curve.is_migrated, YozoonError::Migrated);
+    // Prevent sales after migration, and once the curve is waiting to migrate
+    curve.require_trading()?;
     
     // Validate minimum sale amount
     require!(token_amount >= MINIMUM_TOKEN_SALE, YozoonError::AmountTooSmall);
     
+    // Only tokens bought from the curve can be redeemed against its reserve, so
+    // airdropped tokens cannot drain what buyers paid in
+    require!(token_amount <= curve.total_sold_supply, YozoonError::SellExceedsSoldSupply);
+    
     // Calculate current token price
     let supply = curve.total_sold_supply;
-    let price = calculate_token_price(supply, &curve.price_points);
+    let price = calculate_token_price(supply, &curve.price_points, ctx.accounts.config.max_supply());
     
     // Calculate SOL to return
-    let sol_amount = token_amount * price / PRECISION_FACTOR;
+    let sol_amount = mul_div(token_amount, price, ctx.accounts.config.token_unit()).ok_or(YozoonError::MathOverflow)?;
     
     // Ensure non-zero SOL (prevent dust amounts)
     require!(sol_amount > 0, YozoonError::DustAmount);
     
-    // Check reserve balance
-    require!(ctx.accounts.reserve.lamports() >= sol_amount, YozoonError::InsufficientReserve);
+    // Check reserve balance, leaving it rent-exempt
+    let available = ctx.accounts.sol_reserve.lamports().saturating_sub(Rent::get()?.minimum_balance(0));
+    require!(available >= sol_amount, YozoonError::InsufficientReserve);
     
     // Burn tokens from the user's token account
-    token::burn(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::Burn {
-                mint: ctx.accounts.mint.to_account_info(),
-                from: ctx.accounts.seller_token_account.to_account_info(),
-                authority: ctx.accounts.seller.to_account_info(),
-            },
-        ),
-        token_amount,
-    )?;
+    let config_seeds: &[&[u8]] = &ctx.accounts.config.signer_seeds();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let mint = ctx.accounts.mint.to_account_info();
+    let config = ctx.accounts.config.to_account_info();
+    with_thawed(&token_program, &mint, &ctx.accounts.seller_token_account, &config, config_seeds, || {
+        token_interface::burn(
+            CpiContext::new(
+                token_program.clone(),
+                token_interface::Burn {
+                    mint: mint.clone(),
+                    from: ctx.accounts.seller_token_account.to_account_info(),
+                    authority: ctx.accounts.seller.to_account_info(),
+                },
+            ),
+            token_amount,
+        )
+    })?;
     
     // Transfer SOL from reserve to user
     anchor_lang::system_program::transfer(
-        CpiContext::new(
+        CpiContext::new_with_signer(
             ctx.accounts.system_program.to_account_info(),
             anchor_lang::system_program::Transfer {
-                from: ctx.accounts.reserve.to_account_info(),
+                from: ctx.accounts.sol_reserve.to_account_info(),
                 to: ctx.accounts.seller.to_account_info(),
             },
+            &[&[seeds::SOL_RESERVE, ctx.accounts.config.mint.as_ref(), &[*ctx.bumps.get("sol_reserve").unwrap()]]],
         ),
         sol_amount,
     )?;
     
-    // Update bonding curve state
-    curve.total_sold_supply -= token_amount;
-    curve.total_sol_raised -= sol_amount;
+    // Close out the old price in the history, then update bonding curve state
+    let timestamp = Clock::get()?.unix_timestamp;
+    ctx.accounts.price_observations.record(price, timestamp);
+    curve.total_sold_supply = curve
+        .total_sold_supply
+        .checked_sub(token_amount)
+        .ok_or(YozoonError::MathOverflow)?;
+    curve.total_sol_raised = curve
+        .total_sol_raised
+        .checked_sub(sol_amount)
+        .ok_or(YozoonError::MathOverflow)?;
     
     // Emit event for frontend tracking
     emit!(TokenSaleEvent {
@@ -258,7 +451,7 @@ pub fn sell_tokens(ctx: Context<SellTokens>, token_amount: u64) -> Result<()> {
         token_amount,
         sol_amount,
         price,
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp,
     });
     
     msg!("User sold {} tokens for {} SOL", token_amount, sol_amount);
@@ -1,58 +1,125 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::errors::YozoonError;
+use crate::metadata::{metadata_address, TokenMetadataProgram};
 use crate::state::*;
 use crate::utils::constants::seeds::*;
 
 /// Accounts required for initializing the token mint
 #[derive(Accounts)]
 pub struct InitializeMint<'info> {
-    /// Configuration account (PDA)
+    /// Launchpad (PDA); checks the creator and sets the creation fee
+    #[account(
+        mut,
+        seeds = [LAUNCHPAD],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+    
+    /// Receives the creation fee
+    #[account(
+        mut,
+        address = launchpad.fee_recipient @ YozoonError::InvalidParameter
+    )]
+    pub fee_recipient: SystemAccount<'info>,
+    
+    /// Configuration account (PDA) of the new launch
     #[account(
         init,
         payer = admin,
         space = 8 + Config::LEN,
-        seeds = [b"config"],
+        seeds = [b"config", mint.key().as_ref()],
         bump
     )]
     pub config: Account<'info, Config>,
     
-    /// Token mint account
-    #[account(
-        init,
-        payer = admin,
-        mint::decimals = 9,
-        mint::authority = config,
-    )]
-    pub mint: Account<'info, Mint>,
+    /// Token mint account, created by the handler with room for the chosen extensions
+    #[account(mut)]
+    pub mint: Signer<'info>,
     
-    /// Admin account (pays rent and becomes initial admin)
+    /// CHECK: Metaplex metadata account of the mint, created by the metadata program
+    #[account(mut, address = metadata_address(&mint.key()) @ YozoonError::InvalidParameter)]
+    pub metadata: UncheckedAccount<'info>,
+    
+    /// Creator on the launchpad allowlist (pays rent and the creation fee, and becomes initial admin)
     #[account(mut)]
     pub admin: Signer<'info>,
     
+    /// Metaplex Token Metadata program
+    pub token_metadata_program: Program<'info, TokenMetadataProgram>,
+    
     /// System program
     pub system_program: Program<'info, System>,
     
-    /// Token program
-    pub token_program: Program<'info, Token>,
+    /// Token program the mint is created under (SPL Token or Token-2022)
+    pub token_program: Interface<'info, TokenInterface>,
     
     /// Rent sysvar
     pub rent: Sysvar<'info, Rent>,
 }
 
+/// Accounts required for updating the token metadata
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    /// Configuration account (PDA); the metadata update authority
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        has_one = admin @ YozoonError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// CHECK: Metaplex metadata account of the mint
+    #[account(mut, address = metadata_address(&config.mint) @ YozoonError::InvalidParameter)]
+    pub metadata: UncheckedAccount<'info>,
+    
+    /// Admin account
+    pub admin: Signer<'info>,
+    
+    /// Metaplex Token Metadata program
+    pub token_metadata_program: Program<'info, TokenMetadataProgram>,
+}
+
 /// Accounts required for initializing the bonding curve
 #[derive(Accounts)]
 pub struct InitializeBondingCurve<'info> {
-    /// Configuration account (PDA)
+    /// Configuration account (PDA) to validate admin
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        has_one = admin @ YozoonError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Bonding curve account (PDA)
     #[account(
         init,
         payer = admin,
         space = 8 + BondingCurve::LEN,
-        seeds = [b"bonding_curve"],
+        seeds = [b"bonding_curve", config.mint.as_ref()],
         bump
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
     
+    /// Price history (PDA), started with the curve
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PriceObservations::LEN,
+        seeds = [PRICE_OBSERVATIONS, config.mint.as_ref()],
+        bump
+    )]
+    pub price_observations: Box<Account<'info, PriceObservations>>,
+    
+    /// SOL reserve (system-owned PDA) holding the curve's SOL, funded to rent exemption here
+    #[account(
+        mut,
+        seeds = [SOL_RESERVE, config.mint.as_ref()],
+        bump
+    )]
+    pub sol_reserve: SystemAccount<'info>,
+    
     /// Configuration account (PDA)
     #[account(mut)]
     pub admin: Signer<'info>,
@@ -67,63 +134,94 @@ pub struct BuyTokens<'info> {
     /// Configuration account (PDA)
     #[account(
         mut,
-        seeds = [b"config"],
-        bump = config.bump,
-        constraint = !config.paused @ YozoonError::ProtocolPaused
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump
     )]
     pub config: Account<'info, Config>,
     
     /// Bonding curve account (PDA)
     #[account(
         mut,
-        seeds = [b"bonding_curve"],
+        seeds = [b"bonding_curve", config.mint.as_ref()],
         bump = bonding_curve.bump
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
     
+    /// Price history (PDA), updated before the trade moves the price
+    #[account(
+        mut,
+        seeds = [PRICE_OBSERVATIONS, config.mint.as_ref()],
+        bump = price_observations.bump
+    )]
+    pub price_observations: Box<Account<'info, PriceObservations>>,
+    
     /// Token mint account
-    #[account(mut)]
-    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        address = config.mint @ YozoonError::InvalidParameter
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
     
     /// User's token account to receive tokens
     #[account(
         mut,
         constraint = buyer_token_account.owner == buyer.key()
     )]
-    pub buyer_token_account: Account<'info, TokenAccount>,
+    pub buyer_token_account: InterfaceAccount<'info, TokenAccount>,
     
     /// User account (signs transaction and pays SOL)
     #[account(mut)]
     pub buyer: Signer<'info>,
     
-    /// Treasury account
-    #[account(mut)]
-    pub treasury: SystemAccount<'info>,
+    /// SOL reserve (PDA) receiving the buy's SOL
+    #[account(
+        mut,
+        seeds = [SOL_RESERVE, config.mint.as_ref()],
+        bump
+    )]
+    pub sol_reserve: SystemAccount<'info>,
     
-    /// Referral account (optional)
-    #[account(mut)]
+    /// Buyer's referral account for this launch (PDA, optional)
+    #[account(
+        seeds = [b"referral", config.mint.as_ref(), buyer.key().as_ref()],
+        bump = referral.bump
+    )]
     pub referral: Option<Account<'info, Referral>>,
     
-    /// Referrer account (optional)
-    #[account(mut)]
+    /// Referrer recorded in `referral`, paid its fee (required with `referral`)
+    #[account(
+        mut,
+        constraint = referral.as_ref().map(|referral| referral.referrer) == Some(referrer.key())
+            @ YozoonError::ReferrerMismatch
+    )]
     pub referrer: Option<SystemAccount<'info>>,
     
+    /// CHECK: Pyth SOL/USD price account on `config.oracle_feeds` (required by `buy_tokens_usd`), validated when read
+    pub sol_usd_price: Option<UncheckedAccount<'info>>,
+    
     /// System program
     pub system_program: Program<'info, System>,
     
-    /// Token program
-    pub token_program: Program<'info, Token>,
+    /// Token program of the mint
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 /// Accounts required for setting a referral
 #[derive(Accounts)]
 pub struct SetReferral<'info> {
+    /// Configuration account (PDA)
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
     /// User's referral account (PDA)
     #[account(
         init,
         payer = user,
         space = 8 + Referral::LEN,
-        seeds = [b"referral", user.key().as_ref()],
+        seeds = [b"referral", config.mint.as_ref(), user.key().as_ref()],
         bump
     )]
     pub referral: Account<'info, Referral>,
@@ -139,21 +237,27 @@ pub struct SetReferral<'info> {
 /// Accounts required for updating referral fee
 #[derive(Accounts)]
 pub struct UpdateReferralFee<'info> {
-    /// Configuration account (PDA) to validate admin
+    /// User's referral account (PDA), which must belong to this launch
     #[account(
         mut,
-        constraint = config.admin == admin.key() @ YozoonError::Unauthorized
+        seeds = [b"referral", config.mint.as_ref(), user.key().as_ref()],
+        bump = referral.bump,
+        constraint = referral.mint == config.mint @ YozoonError::InvalidParameter
     )]
     pub referral: Account<'info, Referral>,
     
-    /// Configuration account (PDA)
+    /// CHECK: Referred user; only used to derive the referral address
+    pub user: UncheckedAccount<'info>,
+    
+    /// Configuration account (PDA) to validate admin
     #[account(
-        mut,
-        constraint = config.admin == admin.key() @ YozoonError::Unauthorized
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        has_one = admin @ YozoonError::Unauthorized
     )]
     pub config: Account<'info, Config>,
     
-    /// Admin account (pays rent and becomes initial admin)
+    /// Admin account
     pub admin: Signer<'info>,
 }
 
@@ -171,29 +275,39 @@ pub struct AirdropTokens<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
     
+    /// Bonding curve account (PDA); its sold supply is reserved for the pool liquidity
+    #[account(
+        seeds = [b"bonding_curve", config.mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
     /// Airdrop ledger account (PDA)
     #[account(
         init_if_needed,
         payer = admin,
-        space = 8 + 8 + 1, // Discriminator + total_airdropped + bump
-        seeds = [AIRDROP_LEDGER],
+        space = 8 + AirdropLedger::LEN,
+        seeds = [AIRDROP_LEDGER, config.mint.as_ref()],
         bump
     )]
     pub airdrop_ledger: Account<'info, AirdropLedger>,
     
-    /// Token mint account
-    #[account(mut)]
-    pub mint: Account<'info, Mint>,
+    /// Token mint account; `finalize_migration` revokes the minting rights airdrops need
+    #[account(
+        mut,
+        constraint = mint.mint_authority == COption::Some(config.key()) @ YozoonError::MintAuthorityRevoked
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
     
     /// Recipient's token account to receive airdropped tokens
     #[account(mut)]
-    pub recipient_token_account: Account<'info, TokenAccount>,
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
     
     /// System program
     pub system_program: Program<'info, System>,
     
-    /// Token program
-    pub token_program: Program<'info, Token>,
+    /// Token program of the mint
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 /// Accounts required for admin actions
@@ -210,6 +324,55 @@ pub struct AdminAction<'info> {
     pub admin: Signer<'info>,
 }
 
+/// Accounts required for changing the bonding curve's migration thresholds
+#[derive(Accounts)]
+pub struct SetMigrationThresholds<'info> {
+    /// Configuration account (PDA) to validate admin
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump,
+        has_one = admin @ YozoonError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Bonding curve account (PDA)
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", config.mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// Admin account
+    pub admin: Signer<'info>,
+}
+
+/// Accounts required for upgrading an account to the current layout
+#[derive(Accounts)]
+pub struct MigrateAccountLayout<'info> {
+    /// CHECK: Config of the launch (program-owned); decoded in any layout by the handler to validate admin
+    #[account(owner = crate::ID @ YozoonError::InvalidParameter)]
+    pub config: UncheckedAccount<'info>,
+    
+    /// CHECK: Account of the config's launch to upgrade (may be `config`); its type is read
+    /// from the discriminator and its address checked against the launch's PDA
+    #[account(
+        mut,
+        owner = crate::ID @ YozoonError::InvalidParameter
+    )]
+    pub target: UncheckedAccount<'info>,
+    
+    /// CHECK: Referred user when `target` is a referral; only used to derive its address
+    pub user: Option<UncheckedAccount<'info>>,
+    
+    /// Admin account (pays rent for any extra space)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
 /// Accounts required for accepting admin role
 #[derive(Accounts)]
 pub struct AcceptAdmin<'info> {
@@ -227,9 +390,16 @@ pub struct AcceptAdmin<'info> {
 /// Accounts required for viewing current price
 #[derive(Accounts)]
 pub struct GetCurrentPrice<'info> {
+    /// Configuration account (PDA) holding the launch's supply and decimals
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
     /// Bonding curve account (PDA)
     #[account(
-        seeds = [b"bonding_curve"],
+        seeds = [b"bonding_curve", config.mint.as_ref()],
         bump = bonding_curve.bump
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
@@ -238,27 +408,59 @@ pub struct GetCurrentPrice<'info> {
 /// Accounts required for calculating tokens for a SOL amount
 #[derive(Accounts)]
 pub struct CalculateTokens<'info> {
+    /// Configuration account (PDA) holding the launch's supply and decimals
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
     /// Bonding curve account (PDA)
     #[account(
-        seeds = [b"bonding_curve"],
+        seeds = [b"bonding_curve", config.mint.as_ref()],
         bump = bonding_curve.bump
     )]
-    pub bonding_curve: Account<'info, BondingCurve>,
+    pub bonding_curve: Account<'info, BondingCurve>,    
+    /// Token mint; its supply counts against the max supply
+    #[account(address = config.mint @ YozoonError::InvalidParameter)]
+    pub mint: InterfaceAccount<'info, Mint>,
 }
 
 /// Accounts required for selling tokens
 #[derive(Accounts)]
 pub struct SellTokens<'info> {
+    #[account(
+        seeds = [b"config", config.mint.as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
     #[account(mut)]
     pub seller: Signer<'info>,
     #[account(mut)]
-    pub seller_token_account: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub mint: Account<'info, Mint>,
-    #[account(mut)]
+    pub seller_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = config.mint @ YozoonError::InvalidParameter
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", config.mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
     pub bonding_curve: Account<'info, BondingCurve>,
-    #[account(mut)]
-    pub reserve: SystemAccount<'info>,
-    pub token_program: Program<'info, Token>,
+    #[account(
+        mut,
+        seeds = [PRICE_OBSERVATIONS, config.mint.as_ref()],
+        bump = price_observations.bump
+    )]
+    pub price_observations: Box<Account<'info, PriceObservations>>,
+    #[account(
+        mut,
+        seeds = [SOL_RESERVE, config.mint.as_ref()],
+        bump
+    )]
+    pub sol_reserve: SystemAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
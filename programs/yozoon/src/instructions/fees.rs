@@ -0,0 +1,622 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, spl_token::instruction::AuthorityType, Mint, Token, TokenAccount};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{self, Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface};
+use crate::dex::MigrationTargetKind;
+use crate::errors::YozoonError;
+use crate::events::*;
+use crate::raydium::*;
+use crate::utils::constants::*;
+
+/// Pay the fee key holder their share of the trading fees earned by the locked LP
+///
+/// Withdraws whatever the locked LP has earned into the pool's fee vaults first,
+/// then pays this key's share of everything collected since its last settlement.
+/// A Token-2022 transfer fee comes out of the project token payout.
+pub fn claim_pool_fees(ctx: &mut Context<ClaimPoolFees>) -> Result<()> {
+    // 1. Collect the LP position's fees into the fee vaults
+    let lp_lock_bump = *ctx.bumps.get("lp_lock").unwrap();
+    ctx.accounts.fee_collection(lp_lock_bump).collect()?;
+    let pool = &ctx.accounts.raydium_pool;
+    
+    // 2. Work out what this key is owed
+    let fee_key = &mut ctx.accounts.fee_key_nft;
+    fee_key.settle(pool.total_fees_token, pool.total_fees_sol).ok_or(YozoonError::MathOverflow)?;
+    let (token_amount, sol_amount) = fee_key.take_unclaimed();
+    
+    // 3. Pay the holder from the fee vaults
+    let pool_key = pool.key();
+    let pool_seeds: &[&[u8]] = &[seeds::RAYDIUM_POOL, pool.token_a_mint.as_ref(), &[pool.bump]];
+    for (token_program, mint, vault, recipient, amount) in [
+        (
+            ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.mint,
+            &ctx.accounts.fee_vault_token,
+            &ctx.accounts.holder_token_account,
+            token_amount,
+        ),
+        (
+            ctx.accounts.spl_token_program.to_account_info(),
+            &ctx.accounts.wrapped_sol,
+            &ctx.accounts.fee_vault_sol,
+            &ctx.accounts.holder_sol_account,
+            sol_amount,
+        ),
+    ] {
+        if amount > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    token_program,
+                    token_interface::TransferChecked {
+                        from: vault.to_account_info(),
+                        mint: mint.to_account_info(),
+                        to: recipient.to_account_info(),
+                        authority: pool.to_account_info(),
+                    },
+                    &[pool_seeds],
+                ),
+                amount,
+                mint.decimals,
+            )?;
+        }
+    }
+    
+    // The NFT may have changed hands since the last claim
+    let timestamp = Clock::get()?.unix_timestamp;
+    fee_key.owner = ctx.accounts.holder.key();
+    fee_key.last_claimed = timestamp;
+    
+    // Emit event for frontend tracking
+    emit!(PoolFeesClaimedEvent {
+        pool: pool_key,
+        fee_key_mint: fee_key.mint,
+        holder: fee_key.owner,
+        share_bps: fee_key.fee_percentage,
+        token_amount,
+        sol_amount,
+        timestamp,
+    });
+    
+    msg!("Claimed {} tokens and {} lamports in pool fees", token_amount, sol_amount);
+    Ok(())
+}
+
+/// Accounts that collect a locked CPMM LP position's fee growth into its pool's fee
+/// vaults, borrowed from `ClaimPoolFees` or `SplitFeeKey`
+struct FeeCollection<'a, 'info> {
+    raydium_pool: &'a mut Account<'info, RaydiumPool>,
+    mint: &'a InterfaceAccount<'info, InterfaceMint>,
+    wrapped_sol: &'a InterfaceAccount<'info, InterfaceMint>,
+    fee_vault_token: &'a mut InterfaceAccount<'info, InterfaceTokenAccount>,
+    fee_vault_sol: &'a mut InterfaceAccount<'info, InterfaceTokenAccount>,
+    lp_lock: &'a Account<'info, TokenAccount>,
+    lp_lock_bump: u8,
+    pool_authority: &'a UncheckedAccount<'info>,
+    pool_state: &'a UncheckedAccount<'info>,
+    token_vault: &'a InterfaceAccount<'info, InterfaceTokenAccount>,
+    sol_vault: &'a Account<'info, TokenAccount>,
+    lp_mint: &'a Account<'info, Mint>,
+    raydium_program: &'a Program<'info, Raydium>,
+    spl_token_program: &'a Program<'info, Token>,
+    token_2022_program: &'a Program<'info, Token2022>,
+    memo_program: &'a Program<'info, Memo>,
+}
+
+impl FeeCollection<'_, '_> {
+    /// Withdraw the locked LP's fee growth from the pool into the fee vaults, and add
+    /// what arrived to the pool's fee totals
+    ///
+    /// CPMM compounds trading fees into the reserves and has no instruction that collects
+    /// them on their own, so the lock vault withdraws the LP tokens it holds beyond the
+    /// value of the principal locked at migration. This is the only withdrawal the vault
+    /// ever signs: holders trust this program to leave the principal in the pool, rather
+    /// than relying on the vault having no signer at all.
+    fn collect(self) -> Result<()> {
+        let pool_key = self.raydium_pool.key();
+        
+        // CPMM orders the vaults by mint; map the fee vaults onto them
+        let token_is_0 = self.raydium_pool.token_a_mint < self.raydium_pool.token_b_mint;
+        let (vault_0_amount, vault_1_amount) = if token_is_0 {
+            (self.token_vault.amount, self.sol_vault.amount)
+        } else {
+            (self.sol_vault.amount, self.token_vault.amount)
+        };
+        let (lp_supply, reserve_0, reserve_1) = cpmm::pool_reserves(
+            &self.pool_state.try_borrow_data()?,
+            vault_0_amount,
+            vault_1_amount,
+        )
+        .ok_or(YozoonError::InvalidPoolAccount)?;
+        let fee_lp = cpmm::fee_growth_lp(self.lp_lock.amount, self.raydium_pool.lp_amount, lp_supply, reserve_0, reserve_1)
+            .ok_or(YozoonError::MathOverflow)?;
+        if fee_lp == 0 {
+            return Ok(());
+        }
+        
+        let (token_0_vault, token_1_vault) = if token_is_0 {
+            (self.token_vault.to_account_info(), self.sol_vault.to_account_info())
+        } else {
+            (self.sol_vault.to_account_info(), self.token_vault.to_account_info())
+        };
+        let (token_0_account, token_1_account) = if token_is_0 {
+            (self.fee_vault_token.to_account_info(), self.fee_vault_sol.to_account_info())
+        } else {
+            (self.fee_vault_sol.to_account_info(), self.fee_vault_token.to_account_info())
+        };
+        let (vault_0_mint, vault_1_mint) = if token_is_0 {
+            (self.mint.to_account_info(), self.wrapped_sol.to_account_info())
+        } else {
+            (self.wrapped_sol.to_account_info(), self.mint.to_account_info())
+        };
+        
+        let token_before = self.fee_vault_token.amount;
+        let sol_before = self.fee_vault_sol.amount;
+        
+        // The lock vault owns the LP position, so it signs the withdrawal. A withdrawal
+        // pays out pro rata at any price, so there is nothing to protect with minimums.
+        let lp_lock = self.lp_lock.to_account_info();
+        let ix = cpmm::withdraw_instruction(
+            self.raydium_program.key(),
+            &cpmm::WithdrawAccounts {
+                owner: lp_lock.key(),
+                authority: self.pool_authority.key(),
+                pool_state: self.pool_state.key(),
+                owner_lp_token: lp_lock.key(),
+                token_0_account: token_0_account.key(),
+                token_1_account: token_1_account.key(),
+                token_0_vault: token_0_vault.key(),
+                token_1_vault: token_1_vault.key(),
+                token_program: self.spl_token_program.key(),
+                token_program_2022: self.token_2022_program.key(),
+                vault_0_mint: vault_0_mint.key(),
+                vault_1_mint: vault_1_mint.key(),
+                lp_mint: self.lp_mint.key(),
+                memo_program: self.memo_program.key(),
+            },
+            &cpmm::WithdrawArgs {
+                lp_token_amount: fee_lp,
+                minimum_token_0_amount: 0,
+                minimum_token_1_amount: 0,
+            },
+        );
+        invoke_signed(
+            &ix,
+            &[
+                lp_lock.clone(),
+                self.pool_authority.to_account_info(),
+                self.pool_state.to_account_info(),
+                lp_lock,
+                token_0_account,
+                token_1_account,
+                token_0_vault,
+                token_1_vault,
+                self.spl_token_program.to_account_info(),
+                self.token_2022_program.to_account_info(),
+                vault_0_mint,
+                vault_1_mint,
+                self.lp_mint.to_account_info(),
+                self.memo_program.to_account_info(),
+            ],
+            &[&[seeds::LP_LOCK, pool_key.as_ref(), &[self.lp_lock_bump]]],
+        )?;
+        
+        // Measure what actually arrived
+        self.fee_vault_token.reload()?;
+        self.fee_vault_sol.reload()?;
+        let collected_token = self.fee_vault_token.amount.saturating_sub(token_before);
+        let collected_sol = self.fee_vault_sol.amount.saturating_sub(sol_before);
+        
+        let pool = &mut *self.raydium_pool;
+        pool.total_fees_token = pool.total_fees_token.checked_add(collected_token).ok_or(YozoonError::MathOverflow)?;
+        pool.total_fees_sol = pool.total_fees_sol.checked_add(collected_sol).ok_or(YozoonError::MathOverflow)?;
+        Ok(())
+    }
+}
+
+/// Carve `share_bps` out of the holder's fee key into a newly minted fee key NFT
+///
+/// Shares always add up to `FEE_SHARE_TOTAL_BPS`. The LP's fees are collected first,
+/// so the parent key keeps everything earned so far; the new key only earns fees
+/// from now on.
+pub fn split_fee_key(ctx: Context<SplitFeeKey>, share_bps: u64) -> Result<()> {
+    require!(
+        share_bps > 0 && share_bps < ctx.accounts.fee_key_nft.fee_percentage,
+        YozoonError::InvalidParameter
+    );
+    
+    // Fees still in the pool were earned at the old shares, so collect them first
+    let lp_lock_bump = *ctx.bumps.get("lp_lock").unwrap();
+    ctx.accounts.fee_collection(lp_lock_bump).collect()?;
+    let pool = &ctx.accounts.raydium_pool;
+    let parent = &mut ctx.accounts.fee_key_nft;
+    
+    // Settle the parent at its old share before shrinking it
+    parent.settle(pool.total_fees_token, pool.total_fees_sol).ok_or(YozoonError::MathOverflow)?;
+    parent.fee_percentage -= share_bps;
+    parent.owner = ctx.accounts.holder.key();
+    
+    let timestamp = Clock::get()?.unix_timestamp;
+    let child = &mut ctx.accounts.new_fee_key_nft;
+    child.mint = ctx.accounts.new_nft_mint.key();
+    child.owner = ctx.accounts.holder.key();
+    child.pool = pool.key();
+    child.fee_percentage = share_bps;
+    child.last_claimed = timestamp;
+    child.bump = *ctx.bumps.get("new_fee_key_nft").unwrap();
+    child.fees_checkpoint_token = pool.total_fees_token;
+    child.fees_checkpoint_sol = pool.total_fees_sol;
+    child.unclaimed_token = 0;
+    child.unclaimed_sol = 0;
+    
+    // Mint the single NFT to the holder, then fix the supply for good
+    let pool_seeds: &[&[u8]] = &[seeds::RAYDIUM_POOL, pool.token_a_mint.as_ref(), &[pool.bump]];
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.spl_token_program.to_account_info(),
+            token::MintTo {
+                mint: ctx.accounts.new_nft_mint.to_account_info(),
+                to: ctx.accounts.new_nft_account.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            &[pool_seeds],
+        ),
+        1,
+    )?;
+    token::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.spl_token_program.to_account_info(),
+            token::SetAuthority {
+                current_authority: pool.to_account_info(),
+                account_or_mint: ctx.accounts.new_nft_mint.to_account_info(),
+            },
+            &[pool_seeds],
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+    
+    // Emit event for frontend tracking
+    emit!(FeeKeySplitEvent {
+        pool: pool.key(),
+        parent_mint: parent.mint,
+        parent_share_bps: parent.fee_percentage,
+        new_mint: child.mint,
+        new_share_bps: share_bps,
+        holder: child.owner,
+    });
+    
+    msg!("Split {} bps of fee key {} into {}", share_bps, parent.mint, child.mint);
+    Ok(())
+}
+
+/// Accounts required for claiming pool fees with the fee key NFT
+#[derive(Accounts)]
+pub struct ClaimPoolFees<'info> {
+    /// Pool record written at migration; only a locked CPMM LP position earns claimable fees
+    #[account(
+        mut,
+        seeds = [seeds::RAYDIUM_POOL, raydium_pool.token_a_mint.as_ref()],
+        bump = raydium_pool.bump,
+        constraint = raydium_pool.target == MigrationTargetKind::RaydiumCpmm @ YozoonError::UnsupportedMigrationTarget,
+        constraint = raydium_pool.lp_lock_mode == LpLockMode::Lock @ YozoonError::LpNotLocked
+    )]
+    pub raydium_pool: Box<Account<'info, RaydiumPool>>,
+    
+    /// Fee key state for the holder's NFT (PDA)
+    #[account(
+        mut,
+        seeds = [seeds::NFT_FEE_KEY, raydium_pool.key().as_ref(), fee_key_nft.mint.as_ref()],
+        bump = fee_key_nft.bump
+    )]
+    pub fee_key_nft: Account<'info, FeeKeyNft>,
+    
+    /// Holder's account for the fee key NFT; whoever holds the NFT may claim
+    #[account(
+        constraint = holder_nft_account.mint == fee_key_nft.mint @ YozoonError::NotFeeKeyHolder,
+        constraint = holder_nft_account.owner == holder.key() @ YozoonError::NotFeeKeyHolder,
+        constraint = holder_nft_account.amount == 1 @ YozoonError::NotFeeKeyHolder
+    )]
+    pub holder_nft_account: Box<Account<'info, TokenAccount>>,
+    
+    /// Holder's account receiving the project token side of the fees
+    #[account(
+        mut,
+        token::mint = raydium_pool.token_a_mint,
+        token::authority = holder
+    )]
+    pub holder_token_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    
+    /// Holder's account receiving the wrapped SOL side of the fees
+    #[account(
+        mut,
+        token::mint = raydium_pool.token_b_mint,
+        token::authority = holder
+    )]
+    pub holder_sol_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    
+    /// Current fee key NFT holder; pays for the fee vaults on the first claim
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    
+    /// Project token mint
+    #[account(address = raydium_pool.token_a_mint @ YozoonError::InvalidPoolAccount)]
+    pub mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+    
+    /// Wrapped SOL mint
+    #[account(address = raydium_pool.token_b_mint @ YozoonError::InvalidPoolAccount)]
+    pub wrapped_sol: Box<InterfaceAccount<'info, InterfaceMint>>,
+    
+    /// Collected project token fees awaiting their holders (PDA)
+    #[account(
+        init_if_needed,
+        payer = holder,
+        seeds = [seeds::FEE_VAULT, raydium_pool.key().as_ref(), mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = raydium_pool,
+        token::token_program = token_program
+    )]
+    pub fee_vault_token: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    
+    /// Collected wrapped SOL fees awaiting their holders (PDA)
+    #[account(
+        init_if_needed,
+        payer = holder,
+        seeds = [seeds::FEE_VAULT, raydium_pool.key().as_ref(), wrapped_sol.key().as_ref()],
+        bump,
+        token::mint = wrapped_sol,
+        token::authority = raydium_pool,
+        token::token_program = spl_token_program
+    )]
+    pub fee_vault_sol: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    
+    /// LP lock vault holding the pool's LP position (PDA token account owned by itself)
+    #[account(
+        mut,
+        seeds = [seeds::LP_LOCK, raydium_pool.key().as_ref()],
+        bump
+    )]
+    pub lp_lock: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: CPMM vault authority PDA
+    #[account(seeds = [cpmm::seeds::AUTHORITY], bump, seeds::program = raydium_program.key())]
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: CPMM pool state recorded at migration
+    #[account(mut, address = raydium_pool.pool_state @ YozoonError::InvalidPoolAccount)]
+    pub pool_state: UncheckedAccount<'info>,
+    
+    /// Pool vault for the project token
+    #[account(mut, address = raydium_pool.token_a_account @ YozoonError::InvalidPoolAccount)]
+    pub token_vault: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    
+    /// Pool vault for wrapped SOL
+    #[account(mut, address = raydium_pool.token_b_account @ YozoonError::InvalidPoolAccount)]
+    pub sol_vault: Box<Account<'info, TokenAccount>>,
+    
+    /// Pool LP mint; the withdrawn fee LP is burned from it
+    #[account(mut, address = raydium_pool.lp_mint @ YozoonError::InvalidPoolAccount)]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    
+    /// Raydium CPMM program
+    pub raydium_program: Program<'info, Raydium>,
+    
+    /// Token program of the project mint
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// SPL Token program, which owns wrapped SOL and the LP tokens
+    pub spl_token_program: Program<'info, Token>,
+    
+    /// Token-2022 program, which CPMM `withdraw` takes alongside SPL Token
+    pub token_2022_program: Program<'info, Token2022>,
+    
+    /// SPL Memo program, which CPMM `withdraw` takes for Token-2022 transfers
+    pub memo_program: Program<'info, Memo>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+    
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> ClaimPoolFees<'info> {
+    fn fee_collection(&mut self, lp_lock_bump: u8) -> FeeCollection<'_, 'info> {
+        FeeCollection {
+            raydium_pool: &mut self.raydium_pool,
+            mint: &self.mint,
+            wrapped_sol: &self.wrapped_sol,
+            fee_vault_token: &mut self.fee_vault_token,
+            fee_vault_sol: &mut self.fee_vault_sol,
+            lp_lock: &self.lp_lock,
+            lp_lock_bump,
+            pool_authority: &self.pool_authority,
+            pool_state: &self.pool_state,
+            token_vault: &self.token_vault,
+            sol_vault: &self.sol_vault,
+            lp_mint: &self.lp_mint,
+            raydium_program: &self.raydium_program,
+            spl_token_program: &self.spl_token_program,
+            token_2022_program: &self.token_2022_program,
+            memo_program: &self.memo_program,
+        }
+    }
+}
+
+/// Accounts required for splitting a fee key
+///
+/// The LP's fees are collected before the split, so this takes the same pool
+/// accounts as `ClaimPoolFees`.
+#[derive(Accounts)]
+pub struct SplitFeeKey<'info> {
+    /// Pool record written at migration; signs for the new NFT mint. Only a locked
+    /// CPMM LP position earns fees to share.
+    #[account(
+        mut,
+        seeds = [seeds::RAYDIUM_POOL, raydium_pool.token_a_mint.as_ref()],
+        bump = raydium_pool.bump,
+        constraint = raydium_pool.target == MigrationTargetKind::RaydiumCpmm @ YozoonError::UnsupportedMigrationTarget,
+        constraint = raydium_pool.lp_lock_mode == LpLockMode::Lock @ YozoonError::LpNotLocked
+    )]
+    pub raydium_pool: Box<Account<'info, RaydiumPool>>,
+    
+    /// Fee key state for the holder's NFT (PDA), which gives up `share_bps`
+    #[account(
+        mut,
+        seeds = [seeds::NFT_FEE_KEY, raydium_pool.key().as_ref(), fee_key_nft.mint.as_ref()],
+        bump = fee_key_nft.bump
+    )]
+    pub fee_key_nft: Box<Account<'info, FeeKeyNft>>,
+    
+    /// Holder's account for the fee key NFT
+    #[account(
+        constraint = holder_nft_account.mint == fee_key_nft.mint @ YozoonError::NotFeeKeyHolder,
+        constraint = holder_nft_account.owner == holder.key() @ YozoonError::NotFeeKeyHolder,
+        constraint = holder_nft_account.amount == 1 @ YozoonError::NotFeeKeyHolder
+    )]
+    pub holder_nft_account: Box<Account<'info, TokenAccount>>,
+    
+    /// Mint of the new fee key NFT
+    #[account(
+        init,
+        payer = holder,
+        mint::decimals = 0,
+        mint::authority = raydium_pool,
+        mint::token_program = spl_token_program
+    )]
+    pub new_nft_mint: Box<Account<'info, Mint>>,
+    
+    /// Holder's account receiving the new NFT
+    #[account(
+        init,
+        payer = holder,
+        associated_token::mint = new_nft_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = spl_token_program
+    )]
+    pub new_nft_account: Box<Account<'info, TokenAccount>>,
+    
+    /// Fee key state for the new NFT (PDA)
+    #[account(
+        init,
+        payer = holder,
+        space = 8 + FeeKeyNft::LEN,
+        seeds = [seeds::NFT_FEE_KEY, raydium_pool.key().as_ref(), new_nft_mint.key().as_ref()],
+        bump
+    )]
+    pub new_fee_key_nft: Box<Account<'info, FeeKeyNft>>,
+    
+    /// Current fee key NFT holder; pays for the new accounts and, before the first
+    /// claim, the fee vaults
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    
+    /// Project token mint
+    #[account(address = raydium_pool.token_a_mint @ YozoonError::InvalidPoolAccount)]
+    pub mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+    
+    /// Wrapped SOL mint
+    #[account(address = raydium_pool.token_b_mint @ YozoonError::InvalidPoolAccount)]
+    pub wrapped_sol: Box<InterfaceAccount<'info, InterfaceMint>>,
+    
+    /// Collected project token fees awaiting their holders (PDA)
+    #[account(
+        init_if_needed,
+        payer = holder,
+        seeds = [seeds::FEE_VAULT, raydium_pool.key().as_ref(), mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = raydium_pool,
+        token::token_program = token_program
+    )]
+    pub fee_vault_token: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    
+    /// Collected wrapped SOL fees awaiting their holders (PDA)
+    #[account(
+        init_if_needed,
+        payer = holder,
+        seeds = [seeds::FEE_VAULT, raydium_pool.key().as_ref(), wrapped_sol.key().as_ref()],
+        bump,
+        token::mint = wrapped_sol,
+        token::authority = raydium_pool,
+        token::token_program = spl_token_program
+    )]
+    pub fee_vault_sol: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    
+    /// LP lock vault holding the pool's LP position (PDA token account owned by itself)
+    #[account(
+        mut,
+        seeds = [seeds::LP_LOCK, raydium_pool.key().as_ref()],
+        bump
+    )]
+    pub lp_lock: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: CPMM vault authority PDA
+    #[account(seeds = [cpmm::seeds::AUTHORITY], bump, seeds::program = raydium_program.key())]
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: CPMM pool state recorded at migration
+    #[account(mut, address = raydium_pool.pool_state @ YozoonError::InvalidPoolAccount)]
+    pub pool_state: UncheckedAccount<'info>,
+    
+    /// Pool vault for the project token
+    #[account(mut, address = raydium_pool.token_a_account @ YozoonError::InvalidPoolAccount)]
+    pub token_vault: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    
+    /// Pool vault for wrapped SOL
+    #[account(mut, address = raydium_pool.token_b_account @ YozoonError::InvalidPoolAccount)]
+    pub sol_vault: Box<Account<'info, TokenAccount>>,
+    
+    /// Pool LP mint; the withdrawn fee LP is burned from it
+    #[account(mut, address = raydium_pool.lp_mint @ YozoonError::InvalidPoolAccount)]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    
+    /// Raydium CPMM program
+    pub raydium_program: Program<'info, Raydium>,
+    
+    /// Token program of the project mint
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// SPL Token program, which owns wrapped SOL, the LP tokens and the fee key NFTs
+    pub spl_token_program: Program<'info, Token>,
+    
+    /// Token-2022 program, which CPMM `withdraw` takes alongside SPL Token
+    pub token_2022_program: Program<'info, Token2022>,
+    
+    /// SPL Memo program, which CPMM `withdraw` takes for Token-2022 transfers
+    pub memo_program: Program<'info, Memo>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> SplitFeeKey<'info> {
+    fn fee_collection(&mut self, lp_lock_bump: u8) -> FeeCollection<'_, 'info> {
+        FeeCollection {
+            raydium_pool: &mut self.raydium_pool,
+            mint: &self.mint,
+            wrapped_sol: &self.wrapped_sol,
+            fee_vault_token: &mut self.fee_vault_token,
+            fee_vault_sol: &mut self.fee_vault_sol,
+            lp_lock: &self.lp_lock,
+            lp_lock_bump,
+            pool_authority: &self.pool_authority,
+            pool_state: &self.pool_state,
+            token_vault: &self.token_vault,
+            sol_vault: &self.sol_vault,
+            lp_mint: &self.lp_mint,
+            raydium_program: &self.raydium_program,
+            spl_token_program: &self.spl_token_program,
+            token_2022_program: &self.token_2022_program,
+            memo_program: &self.memo_program,
+        }
+    }
+}
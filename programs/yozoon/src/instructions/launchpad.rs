@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use crate::errors::YozoonError;
+use crate::events::*;
+use crate::program::Yozoon;
+use crate::state::*;
+use crate::utils::constants::*;
+
+/// Create the launchpad; the signer becomes its authority and fee recipient
+///
+/// Only the program's upgrade authority may call it, so nobody can claim the launchpad
+/// between deployment and setup.
+pub fn initialize_launchpad(ctx: Context<InitializeLaunchpad>, creation_fee: u64) -> Result<()> {
+    let launchpad = &mut ctx.accounts.launchpad;
+    
+    launchpad.version = Launchpad::VERSION;
+    launchpad.authority = ctx.accounts.authority.key();
+    launchpad.fee_recipient = ctx.accounts.authority.key();
+    launchpad.creation_fee = creation_fee;
+    launchpad.creators = [Pubkey::default(); MAX_LAUNCH_CREATORS];
+    launchpad.launch_count = 0;
+    launchpad.bump = *ctx.bumps.get("launchpad").unwrap();
+    launchpad.legacy_mint = Pubkey::default();
+    launchpad.pending_authority = Pubkey::default();
+    
+    msg!("Launchpad initialized with a creation fee of {} lamports", creation_fee);
+    Ok(())
+}
+
+/// Change the creation fee and where it is paid
+pub fn set_creation_fee(ctx: Context<LaunchpadAction>, creation_fee: u64, fee_recipient: Pubkey) -> Result<()> {
+    require!(fee_recipient != Pubkey::default(), YozoonError::InvalidParameter);
+    
+    let launchpad = &mut ctx.accounts.launchpad;
+    launchpad.creation_fee = creation_fee;
+    launchpad.fee_recipient = fee_recipient;
+    
+    emit_update(launchpad, ctx.accounts.authority.key());
+    msg!("Creation fee set to {} lamports, paid to {}", creation_fee, fee_recipient);
+    Ok(())
+}
+
+/// Replace the allowlist of accounts that may create launches besides the authority
+pub fn set_launch_creators(ctx: Context<LaunchpadAction>, creators: Vec<Pubkey>) -> Result<()> {
+    // An empty list leaves launches to the authority alone; no empty slots in the list
+    require!(creators.len() <= MAX_LAUNCH_CREATORS, YozoonError::InvalidParameter);
+    require!(
+        creators.iter().all(|creator| *creator != Pubkey::default()),
+        YozoonError::InvalidParameter
+    );
+    
+    let launchpad = &mut ctx.accounts.launchpad;
+    launchpad.creators = [Pubkey::default(); MAX_LAUNCH_CREATORS];
+    launchpad.creators[..creators.len()].copy_from_slice(&creators);
+    
+    emit_update(launchpad, ctx.accounts.authority.key());
+    msg!("Creator allowlist set to {} accounts", creators.len());
+    Ok(())
+}
+
+/// Nominate a new launchpad authority (two-step process)
+pub fn transfer_launchpad_authority(ctx: Context<LaunchpadAction>, new_authority: Pubkey) -> Result<()> {
+    // Overwrites any earlier nomination; launchpads from before v3 gain the field here
+    let launchpad = &mut ctx.accounts.launchpad;
+    launchpad.nominate_authority(new_authority)?;
+    launchpad.version = Launchpad::VERSION;
+    
+    emit!(LaunchpadAuthorityTransferInitiatedEvent {
+        current_authority: ctx.accounts.authority.key(),
+        proposed_authority: new_authority,
+    });
+    
+    msg!("Launchpad authority transfer initiated to: {}", new_authority);
+    Ok(())
+}
+
+/// Cancel a pending launchpad authority transfer
+pub fn cancel_launchpad_authority_transfer(ctx: Context<LaunchpadAction>) -> Result<()> {
+    let cancelled_authority = ctx.accounts.launchpad.cancel_authority_nomination()?;
+    
+    emit!(LaunchpadAuthorityTransferCancelledEvent {
+        authority: ctx.accounts.authority.key(),
+        cancelled_authority,
+    });
+    
+    msg!("Launchpad authority transfer to {} cancelled", cancelled_authority);
+    Ok(())
+}
+
+/// Accept the launchpad authority role (must be called by the nominee)
+pub fn accept_launchpad_authority(ctx: Context<AcceptLaunchpadAuthority>) -> Result<()> {
+    let launchpad = &mut ctx.accounts.launchpad;
+    let previous_authority = launchpad.accept_authority()?;
+    
+    emit!(LaunchpadAuthorityTransferCompletedEvent {
+        previous_authority,
+        new_authority: launchpad.authority,
+    });
+    
+    msg!("Launchpad authority transfer completed to: {}", launchpad.authority);
+    Ok(())
+}
+
+/// Emit the launchpad settings after a change
+fn emit_update(launchpad: &Launchpad, authority: Pubkey) {
+    emit!(LaunchpadUpdatedEvent {
+        creation_fee: launchpad.creation_fee,
+        fee_recipient: launchpad.fee_recipient,
+        creators: launchpad
+            .creators
+            .iter()
+            .copied()
+            .filter(|creator| *creator != Pubkey::default())
+            .collect(),
+        authority,
+    });
+}
+
+/// Accounts for `initialize_launchpad`
+#[derive(Accounts)]
+pub struct InitializeLaunchpad<'info> {
+    /// Launchpad (PDA)
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Launchpad::LEN,
+        seeds = [seeds::LAUNCHPAD],
+        bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+    
+    /// Pays rent and becomes the launchpad authority; must be the program's upgrade authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// This program, to locate its program data
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ YozoonError::InvalidParameter)]
+    pub program: Program<'info, Yozoon>,
+    
+    /// This program's program data, holding its upgrade authority
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ YozoonError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the launchpad authority's instructions
+#[derive(Accounts)]
+pub struct LaunchpadAction<'info> {
+    /// Launchpad (PDA)
+    #[account(
+        mut,
+        seeds = [seeds::LAUNCHPAD],
+        bump = launchpad.bump,
+        has_one = authority @ YozoonError::Unauthorized
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+    
+    /// Launchpad authority
+    pub authority: Signer<'info>,
+}
+
+/// Accounts for `accept_launchpad_authority`
+#[derive(Accounts)]
+pub struct AcceptLaunchpadAuthority<'info> {
+    /// Launchpad (PDA)
+    #[account(
+        mut,
+        seeds = [seeds::LAUNCHPAD],
+        bump = launchpad.bump,
+        constraint = launchpad.pending_authority == pending_authority.key() @ YozoonError::Unauthorized
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+    
+    /// Nominated authority
+    pub pending_authority: Signer<'info>,
+}
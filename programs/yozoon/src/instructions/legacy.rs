@@ -0,0 +1,388 @@
+//! Re-keying of the launch created before the launchpad, whose accounts live at
+//! singleton PDAs without the mint in their seeds
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_interface::{self, Mint, TokenInterface};
+use crate::errors::YozoonError;
+use crate::events::*;
+use crate::metadata::{metadata_address, set_update_authority, update_authority_of, TokenMetadataProgram};
+use crate::state::*;
+use crate::utils::constants::*;
+use crate::utils::token::{metadata_pointer_authority, set_metadata_pointer_authority};
+
+/// Move the launch of before the launchpad to the addresses keyed by its mint
+///
+/// Anyone may call this. Every account is recreated unchanged at its mint-keyed PDA
+/// and the old one closed; the SOL reserve follows, and the mint, freeze, metadata
+/// pointer and metadata update authorities pass from the old config PDA to the new
+/// one. The caller pays the new accounts' rent and gets the old accounts' back.
+pub fn rekey_legacy_launch(ctx: Context<RekeyLegacyLaunch>) -> Result<()> {
+    let (mut config, _) = load_any_layout::<Config>(&ctx.accounts.legacy_config.try_borrow_data()?)?;
+    let mint = config.mint;
+    require_keys_eq!(ctx.accounts.mint.key(), mint, YozoonError::InvalidParameter);
+    
+    // 1. Hand the old config's authorities to the new one while it can still sign
+    let legacy_bump = [*ctx.bumps.get("legacy_config").unwrap()];
+    let legacy_seeds: &[&[u8]] = &[seeds::CONFIG, &legacy_bump];
+    hand_over_authorities(ctx.accounts, legacy_seeds)?;
+    
+    // 2. Recreate each account that still exists at its mint-keyed PDA
+    let accounts = &ctx.accounts;
+    let payer = accounts.caller.to_account_info();
+    let system_program = accounts.system_program.to_account_info();
+    
+    config.bump = *ctx.bumps.get("config").unwrap();
+    config.set_version(Config::VERSION);
+    recreate_account(
+        &config,
+        &accounts.config,
+        &[seeds::CONFIG, mint.as_ref(), &[config.bump]],
+        8 + Config::LEN,
+        &payer,
+        &system_program,
+    )?;
+    close_legacy_account(&accounts.legacy_config, &payer)?;
+    
+    // A migrated curve may already have been closed by `finalize_migration`
+    if is_open(&accounts.legacy_bonding_curve) {
+        let (mut curve, _) = load_any_layout::<BondingCurve>(&accounts.legacy_bonding_curve.try_borrow_data()?)?;
+        curve.bump = *ctx.bumps.get("bonding_curve").unwrap();
+        curve.set_version(BondingCurve::VERSION);
+        recreate_account(
+            &curve,
+            &accounts.bonding_curve,
+            &[seeds::BONDING_CURVE, mint.as_ref(), &[curve.bump]],
+            8 + BondingCurve::LEN,
+            &payer,
+            &system_program,
+        )?;
+        close_legacy_account(&accounts.legacy_bonding_curve, &payer)?;
+    }
+    
+    if is_open(&accounts.legacy_price_observations) {
+        let mut observations =
+            PriceObservations::try_deserialize(&mut &accounts.legacy_price_observations.try_borrow_data()?[..])?;
+        observations.bump = *ctx.bumps.get("price_observations").unwrap();
+        recreate_account(
+            &observations,
+            &accounts.price_observations,
+            &[seeds::PRICE_OBSERVATIONS, mint.as_ref(), &[observations.bump]],
+            8 + PriceObservations::LEN,
+            &payer,
+            &system_program,
+        )?;
+        close_legacy_account(&accounts.legacy_price_observations, &payer)?;
+    }
+    
+    if is_open(&accounts.legacy_airdrop_ledger) {
+        let (mut ledger, _) = load_any_layout::<AirdropLedger>(&accounts.legacy_airdrop_ledger.try_borrow_data()?)?;
+        ledger.bump = *ctx.bumps.get("airdrop_ledger").unwrap();
+        ledger.set_version(AirdropLedger::VERSION);
+        recreate_account(
+            &ledger,
+            &accounts.airdrop_ledger,
+            &[seeds::AIRDROP_LEDGER, mint.as_ref(), &[ledger.bump]],
+            8 + AirdropLedger::LEN,
+            &payer,
+            &system_program,
+        )?;
+        close_legacy_account(&accounts.legacy_airdrop_ledger, &payer)?;
+    }
+    
+    // 3. Move the whole reserve, rent included, so the old PDA is left empty
+    let reserve_lamports = accounts.legacy_sol_reserve.lamports();
+    if reserve_lamports > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: accounts.legacy_sol_reserve.to_account_info(),
+                    to: accounts.sol_reserve.to_account_info(),
+                },
+                &[&[seeds::SOL_RESERVE, &[*ctx.bumps.get("legacy_sol_reserve").unwrap()]]],
+            ),
+            reserve_lamports,
+        )?;
+    }
+    
+    // 4. Record the launch so its users can re-key their referrals
+    let launchpad = &mut ctx.accounts.launchpad;
+    launchpad.version = Launchpad::VERSION;
+    launchpad.legacy_mint = mint;
+    
+    emit!(LegacyLaunchRekeyedEvent {
+        mint,
+        legacy_config: ctx.accounts.legacy_config.key(),
+        config: ctx.accounts.config.key(),
+        reserve_lamports,
+        caller: ctx.accounts.caller.key(),
+    });
+    
+    msg!("Launch {} re-keyed by its mint", mint);
+    Ok(())
+}
+
+/// Move the signer's referral for the legacy launch to its mint-keyed PDA
+///
+/// Runs after `rekey_legacy_launch`; the user pays the new account's rent and gets
+/// the old one's back.
+pub fn rekey_legacy_referral(ctx: Context<RekeyLegacyReferral>) -> Result<()> {
+    let mint = ctx.accounts.launchpad.legacy_mint;
+    require!(mint != Pubkey::default(), YozoonError::InvalidParameter);
+    
+    let accounts = &ctx.accounts;
+    let (mut referral, _) = load_any_layout::<Referral>(&accounts.legacy_referral.try_borrow_data()?)?;
+    referral.bump = *ctx.bumps.get("referral").unwrap();
+    referral.mint = mint;
+    referral.set_version(Referral::VERSION);
+    
+    let user = accounts.user.key();
+    let payer = accounts.user.to_account_info();
+    recreate_account(
+        &referral,
+        &accounts.referral,
+        &[seeds::REFERRAL_PREFIX, mint.as_ref(), user.as_ref(), &[referral.bump]],
+        8 + Referral::LEN,
+        &payer,
+        &accounts.system_program.to_account_info(),
+    )?;
+    close_legacy_account(&accounts.legacy_referral, &payer)?;
+    
+    msg!("Referral of {} re-keyed to launch {}", user, mint);
+    Ok(())
+}
+
+/// Move every authority the old config PDA holds over the mint and its metadata to
+/// the new config PDA
+fn hand_over_authorities(accounts: &RekeyLegacyLaunch, legacy_seeds: &[&[u8]]) -> Result<()> {
+    let legacy_config = accounts.legacy_config.to_account_info();
+    let legacy_key = legacy_config.key();
+    let config_key = accounts.config.key();
+    let mint = accounts.mint.to_account_info();
+    
+    // Authorities already revoked by `finalize_migration` stay revoked
+    let mut authority_types = vec![];
+    if accounts.mint.mint_authority == COption::Some(legacy_key) {
+        authority_types.push(AuthorityType::MintTokens);
+    }
+    if accounts.mint.freeze_authority == COption::Some(legacy_key) {
+        authority_types.push(AuthorityType::FreezeAccount);
+    }
+    for authority_type in authority_types {
+        token_interface::set_authority(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                token_interface::SetAuthority {
+                    account_or_mint: mint.clone(),
+                    current_authority: legacy_config.clone(),
+                },
+                &[legacy_seeds],
+            ),
+            authority_type,
+            Some(config_key),
+        )?;
+    }
+    
+    if metadata_pointer_authority(&mint)? == Some(legacy_key) {
+        invoke_signed(
+            &set_metadata_pointer_authority(&accounts.token_program.key(), &mint.key(), &legacy_key, &config_key),
+            &[mint.clone(), legacy_config.clone()],
+            &[legacy_seeds],
+        )?;
+    }
+    
+    let metadata = accounts.metadata.to_account_info();
+    let update_authority = if *metadata.owner == TokenMetadataProgram::id() {
+        update_authority_of(&metadata.try_borrow_data()?)
+    } else {
+        None
+    };
+    if update_authority == Some(legacy_key) {
+        invoke_signed(
+            &set_update_authority(metadata.key(), legacy_key, config_key),
+            &[metadata, legacy_config, accounts.token_metadata_program.to_account_info()],
+            &[legacy_seeds],
+        )?;
+    }
+    Ok(())
+}
+
+/// True if `account` is a live account of this program
+fn is_open(account: &AccountInfo) -> bool {
+    *account.owner == crate::ID && !account.data_is_empty()
+}
+
+/// Create the PDA `target` with `space` bytes and write `account` into it
+///
+/// Funds, allocates and assigns separately, so lamports sent to the address
+/// beforehand cannot block the creation.
+fn recreate_account<'info, T: AccountSerialize>(
+    account: &T,
+    target: &AccountInfo<'info>,
+    target_seeds: &[&[u8]],
+    space: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(space).saturating_sub(target.lamports());
+    if required > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+            ),
+            required,
+        )?;
+    }
+    anchor_lang::system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Allocate {
+                account_to_allocate: target.clone(),
+            },
+            &[target_seeds],
+        ),
+        space as u64,
+    )?;
+    anchor_lang::system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Assign {
+                account_to_assign: target.clone(),
+            },
+            &[target_seeds],
+        ),
+        &crate::ID,
+    )?;
+    
+    account.try_serialize(&mut &mut target.try_borrow_mut_data()?[..])
+}
+
+/// Close a program account, returning its rent to `destination`
+fn close_legacy_account<'info>(account: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<()> {
+    let lamports = account.lamports();
+    **destination.try_borrow_mut_lamports()? += lamports;
+    **account.try_borrow_mut_lamports()? = 0;
+    
+    account.assign(&anchor_lang::system_program::ID);
+    account.realloc(0, false)?;
+    Ok(())
+}
+
+/// Accounts for `rekey_legacy_launch`
+#[derive(Accounts)]
+pub struct RekeyLegacyLaunch<'info> {
+    /// Launchpad (PDA); records the re-keyed mint for `rekey_legacy_referral`
+    #[account(
+        mut,
+        seeds = [seeds::LAUNCHPAD],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+    
+    /// CHECK: Singleton config of before the launchpad, decoded in any layout; closed here
+    #[account(
+        mut,
+        seeds = [seeds::CONFIG],
+        bump,
+        owner = crate::ID @ YozoonError::InvalidParameter
+    )]
+    pub legacy_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Singleton bonding curve, unless `finalize_migration` closed it; closed here
+    #[account(mut, seeds = [seeds::BONDING_CURVE], bump)]
+    pub legacy_bonding_curve: UncheckedAccount<'info>,
+    
+    /// CHECK: Singleton price history, if the launch had one; closed here
+    #[account(mut, seeds = [seeds::PRICE_OBSERVATIONS], bump)]
+    pub legacy_price_observations: UncheckedAccount<'info>,
+    
+    /// CHECK: Singleton airdrop ledger, if the launch had one; closed here
+    #[account(mut, seeds = [seeds::AIRDROP_LEDGER], bump)]
+    pub legacy_airdrop_ledger: UncheckedAccount<'info>,
+    
+    /// Singleton SOL reserve (PDA), emptied into `sol_reserve`
+    #[account(mut, seeds = [seeds::SOL_RESERVE], bump)]
+    pub legacy_sol_reserve: SystemAccount<'info>,
+    
+    /// CHECK: Config at its mint-keyed PDA; created here
+    #[account(mut, seeds = [seeds::CONFIG, mint.key().as_ref()], bump)]
+    pub config: UncheckedAccount<'info>,
+    
+    /// CHECK: Bonding curve at its mint-keyed PDA; created here if the old one is open
+    #[account(mut, seeds = [seeds::BONDING_CURVE, mint.key().as_ref()], bump)]
+    pub bonding_curve: UncheckedAccount<'info>,
+    
+    /// CHECK: Price history at its mint-keyed PDA; created here if the old one is open
+    #[account(mut, seeds = [seeds::PRICE_OBSERVATIONS, mint.key().as_ref()], bump)]
+    pub price_observations: UncheckedAccount<'info>,
+    
+    /// CHECK: Airdrop ledger at its mint-keyed PDA; created here if the old one is open
+    #[account(mut, seeds = [seeds::AIRDROP_LEDGER, mint.key().as_ref()], bump)]
+    pub airdrop_ledger: UncheckedAccount<'info>,
+    
+    /// SOL reserve (PDA) keyed by the mint
+    #[account(mut, seeds = [seeds::SOL_RESERVE, mint.key().as_ref()], bump)]
+    pub sol_reserve: SystemAccount<'info>,
+    
+    /// Token mint of the legacy launch
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    /// CHECK: Metaplex metadata account of the mint, if it has one
+    #[account(mut, address = metadata_address(&mint.key()) @ YozoonError::InvalidParameter)]
+    pub metadata: UncheckedAccount<'info>,
+    
+    /// Pays the new accounts' rent and receives the old accounts'
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    
+    /// Token program of the mint
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// Metaplex Token Metadata program
+    pub token_metadata_program: Program<'info, TokenMetadataProgram>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `rekey_legacy_referral`
+#[derive(Accounts)]
+pub struct RekeyLegacyReferral<'info> {
+    /// Launchpad (PDA); names the re-keyed legacy launch
+    #[account(
+        seeds = [seeds::LAUNCHPAD],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+    
+    /// CHECK: User's referral of before the launchpad, decoded in any layout; closed here
+    #[account(
+        mut,
+        seeds = [seeds::REFERRAL_PREFIX, user.key().as_ref()],
+        bump,
+        owner = crate::ID @ YozoonError::InvalidParameter
+    )]
+    pub legacy_referral: UncheckedAccount<'info>,
+    
+    /// CHECK: Referral at its PDA keyed by the legacy launch's mint; created here
+    #[account(
+        mut,
+        seeds = [seeds::REFERRAL_PREFIX, launchpad.legacy_mint.as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub referral: UncheckedAccount<'info>,
+    
+    /// Referred user (pays the new account's rent and receives the old one's)
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
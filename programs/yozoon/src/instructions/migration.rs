@@ -1,310 +1,917 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::program::{invoke, invoke_signed};
-use anchor_lang::solana_program::instruction::Instruction;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Token};
+use anchor_spl::token_2022::spl_token_2022::extension::default_account_state::instruction::update_default_account_state;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_2022::spl_token_2022::state::AccountState;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
 use std::str::FromStr;
+use crate::dex::{self, CreatedPool, LpPosition, MigrationTargetKind, PoolDeposit};
 use crate::errors::*;
 use crate::events::*;
 use crate::state::*;
 use crate::utils::constants::*;
+use crate::utils::oracle::{lamports_to_usd, load_validated_price};
+use crate::utils::token::{has_default_account_state, is_default_frozen, transfer_fee};
 use crate::raydium::*;
 
+/// Accounts for `migrate`
+///
+/// The target DEX's own accounts follow as remaining accounts, in the order given
+/// by its adapter's `account_metas` (see `crate::dex`).
 #[derive(Accounts)]
-pub struct MigrateToRaydium<'info> {
+pub struct Migrate<'info> {
     /// Configuration account (PDA)
     #[account(
         mut,
-        has_one = admin @ YozoonError::Unauthorized
+        seeds = [seeds::CONFIG, config.mint.as_ref()],
+        bump = config.bump
     )]
     pub config: Account<'info, Config>,
     
     /// Bonding curve account (PDA)
     #[account(
         mut,
-        seeds = [seeds::BONDING_CURVE],
+        seeds = [seeds::BONDING_CURVE, config.mint.as_ref()],
         bump = bonding_curve.bump
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
     
     /// Token mint (project token)
-    #[account(mut)]
-    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        address = config.mint @ YozoonError::InvalidParameter
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
     
     /// Wrapped SOL mint
     #[account(
         address = Pubkey::from_str(WRAPPED_SOL_MINT).unwrap()
     )]
-    pub wrapped_sol: Account<'info, Mint>,
+    pub wrapped_sol: Box<InterfaceAccount<'info, Mint>>,
     
-    /// Treasury account (source of SOL for liquidity)
-    #[account(mut)]
-    pub treasury: SystemAccount<'info>,
+    /// SOL reserve (PDA) holding the curve's SOL; source of the pool SOL and the bounty
+    #[account(
+        mut,
+        seeds = [seeds::SOL_RESERVE, config.mint.as_ref()],
+        bump
+    )]
+    pub sol_reserve: SystemAccount<'info>,
     
-    /// Project token account for pool liquidity
-    #[account(mut)]
-    pub token_account: Account<'info, TokenAccount>,
+    /// CHECK: System-owned PDA that creates the pool and supplies its initial liquidity
+    #[account(
+        mut,
+        seeds = [seeds::POOL_CREATOR, config.mint.as_ref()],
+        bump
+    )]
+    pub pool_creator: UncheckedAccount<'info>,
     
-    /// SOL token account for pool liquidity
-    #[account(mut)]
-    pub sol_token_account: Account<'info, TokenAccount>,
+    /// Pool creator's project token account, receives the liquidity allocation
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = mint,
+        associated_token::authority = pool_creator,
+        associated_token::token_program = token_program
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     
-    /// LP token mint (to be created)
-    #[account(mut)]
-    pub lp_mint: Account<'info, Mint>,
+    /// Pool creator's WSOL account, receives the wrapped curve reserves
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = wrapped_sol,
+        associated_token::authority = pool_creator,
+        associated_token::token_program = spl_token_program
+    )]
+    pub sol_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     
-    /// Fee account for pool fees
-    #[account(mut)]
-    pub fee_account: Account<'info, TokenAccount>,
+    /// Fee key NFT mint, created here; the migration mints its single token and
+    /// then revokes the mint authority
+    #[account(
+        init,
+        payer = caller,
+        mint::decimals = 0,
+        mint::authority = raydium_pool,
+        mint::token_program = spl_token_program
+    )]
+    pub nft_mint: Box<InterfaceAccount<'info, Mint>>,
     
-    /// NFT mint for fee key
-    #[account(mut)]
-    pub nft_mint: Account<'info, Mint>,
+    /// CHECK: Treasury from the config; receives the fee key NFT
+    #[account(address = config.treasury @ YozoonError::InvalidTreasury)]
+    pub treasury: UncheckedAccount<'info>,
     
-    /// Raydium pool account (PDA to be created)
+    /// Treasury's account for the fee key NFT
     #[account(
         init,
-        payer = admin,
+        payer = caller,
+        associated_token::mint = nft_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = spl_token_program
+    )]
+    pub treasury_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Pool record (PDA to be created)
+    #[account(
+        init,
+        payer = caller,
         space = 8 + RaydiumPool::LEN,
         seeds = [seeds::RAYDIUM_POOL, mint.key().as_ref()],
         bump
     )]
     pub raydium_pool: Account<'info, RaydiumPool>,
     
+    /// CHECK: LP lock vault PDA, created as an LP token account owned by itself when locking
+    #[account(
+        mut,
+        seeds = [seeds::LP_LOCK, raydium_pool.key().as_ref()],
+        bump
+    )]
+    pub lp_lock: UncheckedAccount<'info>,
+    
     /// Fee key NFT account (PDA to be created)
     #[account(
         init,
-        payer = admin,
+        payer = caller,
         space = 8 + FeeKeyNft::LEN,
-        seeds = [seeds::NFT_FEE_KEY, raydium_pool.key().as_ref()],
+        seeds = [seeds::NFT_FEE_KEY, raydium_pool.key().as_ref(), nft_mint.key().as_ref()],
         bump
     )]
     pub fee_key_nft: Account<'info, FeeKeyNft>,
     
-    /// Admin account who triggers migration
+    /// Anyone may trigger migration once the thresholds are met; pays rent and earns the bounty
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub caller: Signer<'info>,
     
-    /// Raydium AMM program
-    pub raydium_program: Program<'info, Raydium>,
+    /// CHECK: Pyth SOL/USD price account on `config.oracle_feeds`, validated when read
+    pub sol_usd_price: UncheckedAccount<'info>,
     
-    /// Raydium Fee Key program
-    pub fee_key_program: Program<'info, FeeKeyProgram>,
+    /// CHECK: Program of the DEX chosen in `bonding_curve.migration_target`
+    #[account(
+        executable,
+        constraint = dex_program.key() == bonding_curve.migration_target.program_id() @ YozoonError::InvalidPoolAccount
+    )]
+    pub dex_program: UncheckedAccount<'info>,
     
     /// System program
     pub system_program: Program<'info, System>,
     
-    /// Token program
-    pub token_program: Program<'info, Token>,
+    /// Token program of the project mint (SPL Token or Token-2022)
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// SPL Token program, which owns wrapped SOL, LP tokens and the fee key NFT
+    /// whichever program the project mint uses
+    pub spl_token_program: Program<'info, Token>,
+    
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
     
     /// Rent sysvar
     pub rent: Sysvar<'info, Rent>,
 }
 
 /// Verify if migration conditions are met
+///
+/// Returns the USD value of the raised SOL
 pub fn check_migration_conditions(
-    ctx: &Context<MigrateToRaydium>
-) -> Result<()> {
+    ctx: &Context<Migrate>
+) -> Result<u64> {
     let curve = &ctx.accounts.bonding_curve;
     
-    // Check if we've reached the SOL threshold
-    let total_sol = curve.total_sol_raised;
-    msg!("Current SOL raised: {}", total_sol);
+    // Check if already migrated, or given up on
+    require!(
+        curve.state != CurveState::Migrated,
+        YozoonError::AlreadyMigrated
+    );
+    require!(
+        curve.state != CurveState::Refunding,
+        YozoonError::RefundModeActive
+    );
     
+    // The buy that reached the SOL and supply thresholds froze the curve for migration
+    msg!("Current SOL raised: {}, supply sold: {}", curve.total_sol_raised, curve.total_sold_supply);
     require!(
-        total_sol >= MIGRATION_SOL_MIN && total_sol <= MIGRATION_SOL_MAX,
+        curve.state == CurveState::ReadyToMigrate,
         YozoonError::MigrationThresholdNotReached
     );
     
-    // Check if already migrated
+    // Check the USD value of the reserve at the oracle price
+    let total_usd = reserve_usd_value(&ctx.accounts.config, curve, &ctx.accounts.sol_usd_price)?;
+    msg!("Current USD value: {}", total_usd);
     require!(
-        !curve.is_migrated,
-        YozoonError::AlreadyMigrated
+        total_usd >= curve.thresholds().usd_min,
+        YozoonError::MigrationUsdThresholdNotReached
     );
     
-    Ok(())
+    Ok(total_usd)
 }
 
-/// Migrates liquidity to Raydium when conditions are met
-pub fn migrate_to_raydium(ctx: &mut Context<MigrateToRaydium>) -> Result<()> {
-    // 1. Verify migration conditions
-    check_migration_conditions(ctx)?;
+/// USD value of the curve's raised SOL at the validated Pyth SOL/USD price
+fn reserve_usd_value(config: &Config, curve: &BondingCurve, sol_usd_price: &AccountInfo) -> Result<u64> {
+    let price = load_validated_price(config, sol_usd_price, Clock::get()?.unix_timestamp)?;
+    lamports_to_usd(curve.total_sol_raised, &price).ok_or_else(|| error!(YozoonError::MathOverflow))
+}
+
+/// Migrates liquidity to the curve's target DEX when conditions are met
+/// (permissionless, pays the caller a bounty)
+pub fn migrate<'info>(
+    ctx: &mut Context<'_, '_, '_, 'info, Migrate<'info>>,
+    lp_lock_mode: LpLockMode
+) -> Result<()> {
+    // 1. Verify migration is not paused and conditions are met
+    require!(!ctx.accounts.config.is_paused(pause_flags::MIGRATE), YozoonError::ProtocolPaused);
+    let total_usd = check_migration_conditions(ctx)?;
     
     // 2. Mark as migrated to prevent further buying/selling via bonding curve
     let curve = &mut ctx.accounts.bonding_curve;
     curve.is_migrated = true;
+    curve.state = CurveState::Migrated;
     
-    // 3. Calculate total values for the pool
+    // 3. Calculate total values for the pool; the caller's bounty comes out of the SOL side
     let total_sol = curve.total_sol_raised;
     let total_supply = curve.total_sold_supply;
+    let target = curve.migration_target;
+    let bounty = ctx.accounts.config.migration_bounty;
+    let pool_sol = total_sol.checked_sub(bounty).ok_or(YozoonError::InsufficientReserve)?;
     
-    // 4. Create Raydium pool with permanent liquidity locking
-    create_raydium_pool(ctx, total_supply, total_sol)?;
+    // 4. Pay the caller and move the reserves into the pool creator's accounts
+    pay_migration_bounty(ctx, bounty)?;
+    let (token_before, sol_before) = fund_pool_liquidity(ctx, total_supply, pool_sol)?;
     
-    // 5. Create NFT fee key for fee distribution
+    // 5. Create the pool on the target DEX and check where the liquidity went
+    let created = create_pool(ctx, target, total_supply, pool_sol)?;
+    verify_pool_deposits(ctx, &created, token_before, sol_before, total_supply, pool_sol)?;
+    
+    // 6. Take the LP position out of circulation
+    lock_lp_tokens(ctx, &created.lp, lp_lock_mode)?;
+    
+    // 7. Create NFT fee key for fee distribution
     create_fee_key_nft(ctx)?;
     
-    // 6. Emit migration event
+    // 8. Emit migration event
     emit!(MigrationEvent {
         total_sol,
-        total_usd: 0, // Not using USD value anymore
+        total_usd,
         total_supply,
         timestamp: Clock::get()?.unix_timestamp,
     });
     
-    // 7. Emit more detailed migration event with pool information
+    // 9. Emit more detailed migration event with pool information
     emit!(MigrationCompletedEvent {
+        target,
+        pool_state: created.pool_state,
         sol_value: total_sol,
         tokens_sold: total_supply,
-        admin: ctx.accounts.admin.key(),
+        caller: ctx.accounts.caller.key(),
+        bounty,
         timestamp: Clock::get()?.unix_timestamp,
     });
     
-    msg!("Migration to Raydium completed. Total SOL: {}, Total tokens: {}", total_sol, total_supply);
-    msg!("Raydium pool created with permanent liquidity locking");
+    msg!("Migration to {:?} completed. Total SOL: {}, Total tokens: {}", target, total_sol, total_supply);
     msg!("NFT fee key created for fee distribution");
     
     Ok(())
 }
 
-/// Creates a Raydium liquidity pool with permanent liquidity locking
-fn create_raydium_pool(
-    ctx: &mut Context<MigrateToRaydium>,
+/// Pays the migration bounty from the SOL reserve to the caller
+fn pay_migration_bounty(
+    ctx: &mut Context<Migrate>,
+    bounty: u64
+) -> Result<()> {
+    if bounty == 0 {
+        return Ok(());
+    }
+    
+    let launch_mint = ctx.accounts.config.mint;
+    let reserve_bump = *ctx.bumps.get("sol_reserve").unwrap();
+    anchor_lang::system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.sol_reserve.to_account_info(),
+                to: ctx.accounts.caller.to_account_info(),
+            },
+            &[&[seeds::SOL_RESERVE, launch_mint.as_ref(), &[reserve_bump]]],
+        ),
+        bounty,
+    )?;
+    
+    msg!("Paid migration bounty of {} lamports to {}", bounty, ctx.accounts.caller.key());
+    Ok(())
+}
+
+/// Wraps the reserve SOL and mints the liquidity allocation into the pool
+/// creator's accounts. Returns the balances held before the deposit.
+fn fund_pool_liquidity(
+    ctx: &mut Context<Migrate>,
     token_amount: u64,
     sol_amount: u64
-) -> Result<()> {
-    let pool = &mut ctx.accounts.raydium_pool;
+) -> Result<(u64, u64)> {
+    // The reserve stays rent-exempt after paying out
+    let available = ctx.accounts.sol_reserve.lamports()
+        .saturating_sub(ctx.accounts.rent.minimum_balance(0));
+    require!(available >= sol_amount, YozoonError::InsufficientReserve);
+    require!(
+        ctx.accounts.mint.supply.checked_add(token_amount).ok_or(YozoonError::MathOverflow)? <= ctx.accounts.config.max_supply(),
+        YozoonError::SupplyExceeded
+    );
+    
+    // Anyone can send dust to the creator's accounts, so track deltas rather than totals
+    let token_before = ctx.accounts.token_account.amount;
+    let sol_before = ctx.accounts.sol_token_account.amount;
+    
+    // Wrap the reserve SOL: move lamports into the WSOL account and sync its balance
+    let launch_mint = ctx.accounts.config.mint;
+    let reserve_bump = *ctx.bumps.get("sol_reserve").unwrap();
+    anchor_lang::system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.sol_reserve.to_account_info(),
+                to: ctx.accounts.sol_token_account.to_account_info(),
+            },
+            &[&[seeds::SOL_RESERVE, launch_mint.as_ref(), &[reserve_bump]]],
+        ),
+        sol_amount,
+    )?;
+    token::sync_native(CpiContext::new(
+        ctx.accounts.spl_token_program.to_account_info(),
+        token::SyncNative {
+            account: ctx.accounts.sol_token_account.to_account_info(),
+        },
+    ))?;
+    
+    // Open transfers of a frozen-until-migration mint before the pool moves any tokens
+    let config_seeds: &[&[u8]] = &ctx.accounts.config.signer_seeds();
+    unlock_transfers(ctx, config_seeds)?;
+    
+    // Mint the liquidity allocation with the Config PDA as mint authority
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.token_account.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            },
+            &[config_seeds],
+        ),
+        token_amount,
+    )?;
+    
+    ctx.accounts.token_account.reload()?;
+    ctx.accounts.sol_token_account.reload()?;
+    require!(
+        ctx.accounts.token_account.amount == token_before + token_amount
+            && ctx.accounts.sol_token_account.amount == sol_before + sol_amount,
+        YozoonError::LiquidityDepositMismatch
+    );
+    
+    msg!("Wrapped {} lamports and minted {} tokens for pool liquidity", sol_amount, token_amount);
+    Ok((token_before, sol_before))
+}
+
+/// For mints whose accounts start frozen: make new accounts (such as the pool's
+/// vaults) start unfrozen and thaw the pool creator's account. Earlier holders
+/// thaw theirs with `thaw_token_account`.
+fn unlock_transfers(ctx: &Context<Migrate>, config_seeds: &[&[u8]]) -> Result<()> {
+    let mint = ctx.accounts.mint.to_account_info();
+    if !is_default_frozen(&mint)? {
+        return Ok(());
+    }
+    
+    let config = ctx.accounts.config.to_account_info();
+    invoke_signed(
+        &update_default_account_state(
+            ctx.accounts.token_program.key,
+            mint.key,
+            config.key,
+            &[],
+            &AccountState::Initialized,
+        )?,
+        &[mint.clone(), config.clone()],
+        &[config_seeds],
+    )?;
+    if ctx.accounts.token_account.is_frozen() {
+        token_interface::thaw_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::ThawAccount {
+                account: ctx.accounts.token_account.to_account_info(),
+                mint,
+                authority: config,
+            },
+            &[config_seeds],
+        ))?;
+    }
+    
+    msg!("Token transfers unlocked");
+    Ok(())
+}
+
+/// Creates the pool on the target DEX from the pool creator's accounts and records it
+fn create_pool<'info>(
+    ctx: &mut Context<'_, '_, '_, 'info, Migrate<'info>>,
+    target: MigrationTargetKind,
+    token_amount: u64,
+    sol_amount: u64
+) -> Result<CreatedPool<'info>> {
+    // Fund the pool creator for the pool creation fee and account rent
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.caller.to_account_info(),
+                to: ctx.accounts.pool_creator.to_account_info(),
+            },
+        ),
+        POOL_CREATION_LAMPORTS,
+    )?;
     
-    // Initialize pool data
+    // The adapter signs every CPI as the pool creator PDA
+    let launch_mint = ctx.accounts.config.mint;
+    let creator_bump = [*ctx.bumps.get("pool_creator").unwrap()];
+    let creator_seeds: &[&[u8]] = &[seeds::POOL_CREATOR, launch_mint.as_ref(), &creator_bump];
+    let accounts = &ctx.accounts;
+    let deposit = PoolDeposit {
+        program: &accounts.dex_program.to_account_info(),
+        creator: &accounts.pool_creator.to_account_info(),
+        creator_seeds,
+        token_mint: &accounts.mint.to_account_info(),
+        sol_mint: &accounts.wrapped_sol.to_account_info(),
+        creator_token_account: &accounts.token_account.to_account_info(),
+        creator_sol_account: &accounts.sol_token_account.to_account_info(),
+        token_amount,
+        sol_amount,
+        token_program: &accounts.token_program.to_account_info(),
+        spl_token_program: &accounts.spl_token_program.to_account_info(),
+        system_program: &accounts.system_program.to_account_info(),
+        associated_token_program: &accounts.associated_token_program.to_account_info(),
+        rent: &accounts.rent.to_account_info(),
+    };
+    let created = dex::create_pool(target, &deposit, ctx.remaining_accounts)?;
+    
+    // Record the pool
+    let pool = &mut ctx.accounts.raydium_pool;
     pool.authority = ctx.accounts.config.key();
     pool.token_a_mint = ctx.accounts.mint.key();
     pool.token_b_mint = ctx.accounts.wrapped_sol.key();
-    pool.token_a_account = ctx.accounts.token_account.key();
-    pool.token_b_account = ctx.accounts.sol_token_account.key();
-    pool.lp_mint = ctx.accounts.lp_mint.key();
-    pool.fee_account = ctx.accounts.fee_account.key();
+    pool.token_a_account = created.token_vault.key();
+    pool.token_b_account = created.sol_vault.key();
+    pool.pool_state = created.pool_state;
     pool.init_timestamp = Clock::get()?.unix_timestamp;
     pool.bump = *ctx.bumps.get("raydium_pool").unwrap();
     pool.is_initialized = true;
+    pool.target = target;
     
-    // Create instruction data for Raydium pool creation
-    let instruction_data = create_pool_instruction_data(
-        token_amount,
-        sol_amount,
-        RAYDIUM_POOL_FEE,
-        LIQUIDITY_LOCK_PERIOD
+    msg!("{:?} pool {} created", target, created.pool_state);
+    Ok(created)
+}
+
+/// Checks that the pool took the curve reserves from the creator and that its vaults
+/// hold them, less any Token-2022 transfer fee on the way in. Concentrated liquidity
+/// targets may leave up to `MAX_LIQUIDITY_DUST_BPS` undeposited: leftover tokens are
+/// burned and leftover SOL returns to the reserve.
+fn verify_pool_deposits(
+    ctx: &mut Context<Migrate>,
+    created: &CreatedPool,
+    token_before: u64,
+    sol_before: u64,
+    token_amount: u64,
+    sol_amount: u64
+) -> Result<()> {
+    ctx.accounts.token_account.reload()?;
+    ctx.accounts.sol_token_account.reload()?;
+    let token_left = ctx.accounts.token_account.amount
+        .checked_sub(token_before)
+        .ok_or(YozoonError::LiquidityDepositMismatch)?;
+    let sol_left = ctx.accounts.sol_token_account.amount
+        .checked_sub(sol_before)
+        .ok_or(YozoonError::LiquidityDepositMismatch)?;
+    require!(
+        is_dust(token_left, token_amount) && is_dust(sol_left, sol_amount),
+        YozoonError::LiquidityDepositMismatch
+    );
+    
+    let token_deposited = token_amount - token_left;
+    let deposit_fee = transfer_fee(&ctx.accounts.mint.to_account_info(), token_deposited)?;
+    require!(
+        token_amount_of(&created.token_vault)? == token_deposited - deposit_fee
+            && token_amount_of(&created.sol_vault)? == sol_amount - sol_left,
+        YozoonError::LiquidityDepositMismatch
     );
     
-    // Create the CPI instruction
-    let raydium_program_id = ctx.accounts.raydium_program.key();
-    let accounts = vec![
-        AccountMeta::new(ctx.accounts.config.key(), false),
-        AccountMeta::new(ctx.accounts.mint.key(), false),
-        AccountMeta::new(ctx.accounts.wrapped_sol.key(), false),
-        AccountMeta::new(ctx.accounts.token_account.key(), false),
-        AccountMeta::new(ctx.accounts.sol_token_account.key(), false),
-        AccountMeta::new(ctx.accounts.lp_mint.key(), false),
-        AccountMeta::new(ctx.accounts.fee_account.key(), false),
-        AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),
-        AccountMeta::new_readonly(ctx.accounts.token_program.key(), false),
-        AccountMeta::new_readonly(ctx.accounts.rent.key(), false),
-    ];
-    
-    let ix = Instruction {
-        program_id: raydium_program_id,
-        accounts,
-        data: instruction_data,
+    let launch_mint = ctx.accounts.config.mint;
+    let creator_bump = *ctx.bumps.get("pool_creator").unwrap();
+    let creator_seeds: &[&[u8]] = &[seeds::POOL_CREATOR, launch_mint.as_ref(), &[creator_bump]];
+    if token_left > 0 {
+        token_interface::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.token_account.to_account_info(),
+                    authority: ctx.accounts.pool_creator.to_account_info(),
+                },
+                &[creator_seeds],
+            ),
+            token_left,
+        )?;
+    }
+    if sol_left > 0 {
+        // Closing a WSOL account unwraps its whole balance
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.spl_token_program.to_account_info(),
+            token::CloseAccount {
+                account: ctx.accounts.sol_token_account.to_account_info(),
+                destination: ctx.accounts.sol_reserve.to_account_info(),
+                authority: ctx.accounts.pool_creator.to_account_info(),
+            },
+            &[creator_seeds],
+        ))?;
+    }
+    if token_left > 0 || sol_left > 0 {
+        msg!("Burned {} undeposited tokens, returned {} lamports to the reserve", token_left, sol_left);
+    }
+    
+    Ok(())
+}
+
+/// True when `left` is at most `MAX_LIQUIDITY_DUST_BPS` of `amount`
+fn is_dust(left: u64, amount: u64) -> bool {
+    left as u128 * 10_000 <= amount as u128 * MAX_LIQUIDITY_DUST_BPS as u128
+}
+
+/// Reads the balance of a token account the program does not deserialize up front
+fn token_amount_of(account: &AccountInfo) -> Result<u64> {
+    require!(
+        *account.owner == token::ID || *account.owner == anchor_spl::token_2022::ID,
+        YozoonError::InvalidPoolAccount
+    );
+    let data = account.try_borrow_data()?;
+    Ok(TokenAccount::try_deserialize(&mut &data[..])?.amount)
+}
+
+/// Burns the pool creator's LP tokens (or position NFT) or moves them into the LP
+/// lock vault. The vault's token authority is the vault PDA itself, which signs
+/// only in `claim_pool_fees`, to withdraw the LP tokens it holds beyond the value of
+/// the principal locked here; the principal stays in the pool. Targets that lock
+/// the position themselves are recorded as locked, whatever `mode` asked for.
+fn lock_lp_tokens<'info>(
+    ctx: &mut Context<'_, '_, '_, 'info, Migrate<'info>>,
+    lp: &LpPosition<'info>,
+    mode: LpLockMode
+) -> Result<()> {
+    let (lp_mint, lp_amount, mode, lp_lock) = match lp {
+        LpPosition::Tokens { mint, account } => {
+            let (lp_amount, lp_lock) = lock_lp_token_account(ctx, mint, account, mode)?;
+            (mint.key(), lp_amount, mode, lp_lock)
+        }
+        LpPosition::Locked { position } => (Pubkey::default(), 0, LpLockMode::Lock, *position),
     };
     
-    // Execute the CPI with PDA signer
-    invoke_signed(
-        &ix,
-        &[
-            ctx.accounts.config.to_account_info(),
-            ctx.accounts.mint.to_account_info(),
-            ctx.accounts.wrapped_sol.to_account_info(),
-            ctx.accounts.token_account.to_account_info(),
-            ctx.accounts.sol_token_account.to_account_info(),
-            ctx.accounts.lp_mint.to_account_info(),
-            ctx.accounts.fee_account.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-            ctx.accounts.token_program.to_account_info(),
-            ctx.accounts.rent.to_account_info(),
-        ],
-        &[&[
-            b"config",
-            &[ctx.accounts.config.bump]
-        ]]
-    )?;
+    let pool = &mut ctx.accounts.raydium_pool;
+    pool.lp_mint = lp_mint;
+    pool.lp_amount = lp_amount;
+    pool.lp_lock_mode = mode;
+    pool.lp_lock = lp_lock;
+    
+    emit!(LiquidityLockedEvent {
+        pool: pool.key(),
+        pool_state: pool.pool_state,
+        lp_mint,
+        lp_amount,
+        mode,
+        lp_lock,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     
-    msg!("Raydium pool created with permanent liquidity locking");
+    msg!("{} LP tokens {:?}", lp_amount, mode);
     Ok(())
 }
 
-/// Creates an NFT fee key for fee distribution
+/// Burns or locks the LP tokens in `creator_lp_token`; returns the amount and the lock vault
+///
+/// LP tokens and position NFTs are SPL Token mints whatever the project mint uses.
+fn lock_lp_token_account<'info>(
+    ctx: &mut Context<'_, '_, '_, 'info, Migrate<'info>>,
+    lp_mint: &AccountInfo<'info>,
+    creator_lp_token: &AccountInfo<'info>,
+    mode: LpLockMode
+) -> Result<(u64, Pubkey)> {
+    let lp_amount = token_amount_of(creator_lp_token)?;
+    require!(lp_amount > 0, YozoonError::LiquidityLockFailed);
+    
+    let launch_mint = ctx.accounts.config.mint;
+    let creator_bump = *ctx.bumps.get("pool_creator").unwrap();
+    let creator_seeds: &[&[u8]] = &[seeds::POOL_CREATOR, launch_mint.as_ref(), &[creator_bump]];
+    
+    let lp_lock = match mode {
+        LpLockMode::Burn => {
+            token::burn(
+                CpiContext::new_with_signer(
+                    ctx.accounts.spl_token_program.to_account_info(),
+                    token::Burn {
+                        mint: lp_mint.clone(),
+                        from: creator_lp_token.clone(),
+                        authority: ctx.accounts.pool_creator.to_account_info(),
+                    },
+                    &[creator_seeds],
+                ),
+                lp_amount,
+            )?;
+            Pubkey::default()
+        }
+        LpLockMode::Lock => {
+            let raydium_pool_key = ctx.accounts.raydium_pool.key();
+            let lock_bump = *ctx.bumps.get("lp_lock").unwrap();
+            let lock_seeds: &[&[u8]] = &[seeds::LP_LOCK, raydium_pool_key.as_ref(), &[lock_bump]];
+    
+            // Create the vault at the PDA; the program is the only one able to sign for it
+            anchor_lang::system_program::create_account(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::CreateAccount {
+                        from: ctx.accounts.caller.to_account_info(),
+                        to: ctx.accounts.lp_lock.to_account_info(),
+                    },
+                    &[lock_seeds],
+                ),
+                ctx.accounts.rent.minimum_balance(token::TokenAccount::LEN),
+                token::TokenAccount::LEN as u64,
+                &token::ID,
+            )?;
+            token::initialize_account3(CpiContext::new(
+                ctx.accounts.spl_token_program.to_account_info(),
+                token::InitializeAccount3 {
+                    account: ctx.accounts.lp_lock.to_account_info(),
+                    mint: lp_mint.clone(),
+                    authority: ctx.accounts.lp_lock.to_account_info(),
+                },
+            ))?;
+    
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.spl_token_program.to_account_info(),
+                    token::Transfer {
+                        from: creator_lp_token.clone(),
+                        to: ctx.accounts.lp_lock.to_account_info(),
+                        authority: ctx.accounts.pool_creator.to_account_info(),
+                    },
+                    &[creator_seeds],
+                ),
+                lp_amount,
+            )?;
+            require!(
+                token_amount_of(&ctx.accounts.lp_lock.to_account_info())? == lp_amount,
+                YozoonError::LiquidityLockFailed
+            );
+            ctx.accounts.lp_lock.key()
+        }
+    };
+    
+    // Nothing may remain in the creator's hands
+    require!(token_amount_of(creator_lp_token)? == 0, YozoonError::LiquidityLockFailed);
+    
+    Ok((lp_amount, lp_lock))
+}
+
+/// Creates an NFT fee key for fee distribution, held by the treasury
 fn create_fee_key_nft(
-    ctx: &mut Context<MigrateToRaydium>
+    ctx: &mut Context<Migrate>
 ) -> Result<()> {
+    // Mint the single NFT to the treasury, then fix the supply for good
+    let pool = &ctx.accounts.raydium_pool;
+    let pool_seeds: &[&[u8]] = &[seeds::RAYDIUM_POOL, pool.token_a_mint.as_ref(), &[pool.bump]];
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.spl_token_program.to_account_info(),
+            token_interface::MintTo {
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                to: ctx.accounts.treasury_nft_account.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            &[pool_seeds],
+        ),
+        1,
+    )?;
+    token_interface::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.spl_token_program.to_account_info(),
+            token_interface::SetAuthority {
+                current_authority: pool.to_account_info(),
+                account_or_mint: ctx.accounts.nft_mint.to_account_info(),
+            },
+            &[pool_seeds],
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+    
     let fee_key = &mut ctx.accounts.fee_key_nft;
     
     // Initialize fee key data
     fee_key.mint = ctx.accounts.nft_mint.key();
-    fee_key.owner = ctx.accounts.admin.key();
+    fee_key.owner = ctx.accounts.config.treasury;
     fee_key.pool = ctx.accounts.raydium_pool.key();
-    fee_key.fee_percentage = 10000; // 100% of fees
+    fee_key.fee_percentage = FEE_SHARE_TOTAL_BPS; // 100% of fees until split
     fee_key.last_claimed = Clock::get()?.unix_timestamp;
     fee_key.bump = *ctx.bumps.get("fee_key_nft").unwrap();
     
-    // Create instruction data for NFT fee key creation
-    let instruction_data = create_nft_fee_key_instruction_data(10000);
-    
-    // Create the CPI instruction
-    let fee_key_program_id = ctx.accounts.fee_key_program.key();
-    let accounts = vec![
-        AccountMeta::new(ctx.accounts.nft_mint.key(), false),
-        AccountMeta::new(ctx.accounts.admin.key(), true),
-        AccountMeta::new(ctx.accounts.raydium_pool.key(), false),
-        AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),
-        AccountMeta::new_readonly(ctx.accounts.token_program.key(), false),
-    ];
-    
-    let ix = Instruction {
-        program_id: fee_key_program_id,
-        accounts,
-        data: instruction_data,
-    };
+    msg!("NFT fee key created for fee distribution");
+    Ok(())
+}
+
+/// Wraps up a migrated launch (permissionless): revokes the mint and freeze
+/// authorities so no more tokens can ever be minted, freezes the curve settings,
+/// and closes the bonding curve and SOL reserve to the treasury
+///
+/// Mints whose accounts started frozen keep the freeze authority, so holders from
+/// before the migration can still `thaw_token_account`.
+pub fn finalize_migration(ctx: Context<FinalizeMigration>) -> Result<()> {
+    require!(
+        ctx.accounts.bonding_curve.state == CurveState::Migrated,
+        YozoonError::NotMigrated
+    );
     
-    // Execute the CPI
-    invoke(
-        &ix,
-        &[
-            ctx.accounts.nft_mint.to_account_info(),
-            ctx.accounts.admin.to_account_info(),
-            ctx.accounts.raydium_pool.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-            ctx.accounts.token_program.to_account_info(),
-        ]
-    )?;
+    // 1. Revoke the Config PDA's authorities over the mint
+    let config_seeds: &[&[u8]] = &ctx.accounts.config.signer_seeds();
+    let config_key = ctx.accounts.config.key();
+    let mut authorities = vec![AuthorityType::MintTokens];
+    if ctx.accounts.mint.freeze_authority == COption::Some(config_key)
+        && !has_default_account_state(&ctx.accounts.mint.to_account_info())?
+    {
+        authorities.push(AuthorityType::FreezeAccount);
+    }
+    for authority_type in authorities {
+        token_interface::set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::SetAuthority {
+                    account_or_mint: ctx.accounts.mint.to_account_info(),
+                    current_authority: ctx.accounts.config.to_account_info(),
+                },
+                &[config_seeds],
+            ),
+            authority_type,
+            None,
+        )?;
+    }
     
-    msg!("NFT fee key created for fee distribution");
+    // 2. A closed curve must not be initialized again
+    let config = &mut ctx.accounts.config;
+    config.frozen_params |= freeze_flags::PRICE_POINTS | freeze_flags::MIGRATION_THRESHOLDS;
+    
+    // 3. Sweep the reserve; the curve account itself is closed by its `close` constraint
+    let reserve_lamports = ctx.accounts.sol_reserve.lamports();
+    if reserve_lamports > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.sol_reserve.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+                &[&[seeds::SOL_RESERVE, ctx.accounts.config.mint.as_ref(), &[*ctx.bumps.get("sol_reserve").unwrap()]]],
+            ),
+            reserve_lamports,
+        )?;
+    }
+    let curve_lamports = ctx.accounts.bonding_curve.to_account_info().lamports();
+    
+    emit!(MigrationFinalizedEvent {
+        mint: ctx.accounts.mint.key(),
+        treasury: ctx.accounts.treasury.key(),
+        reclaimed_lamports: reserve_lamports + curve_lamports,
+        caller: ctx.accounts.caller.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Migration finalized; mint authority revoked, {} lamports sent to the treasury", reserve_lamports + curve_lamports);
+    Ok(())
+}
+
+/// Accounts for `finalize_migration`
+#[derive(Accounts)]
+pub struct FinalizeMigration<'info> {
+    /// Configuration account (PDA); mint authority until this runs
+    #[account(
+        mut,
+        seeds = [seeds::CONFIG, config.mint.as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Bonding curve account (PDA), closed to the treasury
+    #[account(
+        mut,
+        seeds = [seeds::BONDING_CURVE, config.mint.as_ref()],
+        bump = bonding_curve.bump,
+        close = treasury
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// Token mint (project token)
+    #[account(
+        mut,
+        address = config.mint @ YozoonError::InvalidParameter,
+        constraint = mint.mint_authority == COption::Some(config.key()) @ YozoonError::MintAuthorityRevoked
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// SOL reserve (PDA), emptied into the treasury
+    #[account(
+        mut,
+        seeds = [seeds::SOL_RESERVE, config.mint.as_ref()],
+        bump
+    )]
+    pub sol_reserve: SystemAccount<'info>,
+    
+    /// CHECK: Treasury from the config; only receives lamports
+    #[account(
+        mut,
+        address = config.treasury @ YozoonError::InvalidTreasury
+    )]
+    pub treasury: UncheckedAccount<'info>,
+    
+    /// Anyone may finalize a migrated curve
+    pub caller: Signer<'info>,
+    
+    /// Token program of the mint
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Thaw a token account frozen while its mint was frozen until migration
+/// (permissionless, once `migrate` has opened transfers)
+pub fn thaw_token_account(ctx: Context<ThawTokenAccount>) -> Result<()> {
+    let mint = ctx.accounts.mint.to_account_info();
+    require!(!is_default_frozen(&mint)?, YozoonError::NotMigrated);
+    
+    token_interface::thaw_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::ThawAccount {
+            account: ctx.accounts.token_account.to_account_info(),
+            mint,
+            authority: ctx.accounts.config.to_account_info(),
+        },
+        &[&ctx.accounts.config.signer_seeds()],
+    ))?;
+    
+    msg!("Thawed token account {}", ctx.accounts.token_account.key());
     Ok(())
 }
 
+/// Accounts for `thaw_token_account`
+#[derive(Accounts)]
+pub struct ThawTokenAccount<'info> {
+    /// Configuration account (PDA); freeze authority of the mint
+    #[account(
+        seeds = [seeds::CONFIG, config.mint.as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Token mint (project token)
+    #[account(address = config.mint @ YozoonError::InvalidParameter)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// Frozen token account of the mint
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = token_account.is_frozen() @ YozoonError::InvalidParameter
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Token program of the mint
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
 /// Automatic migration check that can be called on every token purchase
 pub fn check_auto_migration(ctx: &mut Context<CheckAutoMigration>) -> Result<()> {
     let curve = &ctx.accounts.bonding_curve;
     
-    // Check if we've reached the SOL threshold
+    // Check if a buy has reached the SOL and supply thresholds
     let total_sol = curve.total_sol_raised;
+    if curve.state != CurveState::ReadyToMigrate {
+        return Ok(());
+    }
     
-    // If we're within the migration threshold, emit an event to notify frontends
-    if total_sol >= MIGRATION_SOL_MIN && total_sol <= MIGRATION_SOL_MAX {
+    // If the reserve is also worth enough in USD, emit an event to notify frontends
+    let total_usd = reserve_usd_value(&ctx.accounts.config, curve, &ctx.accounts.sol_usd_price)?;
+    if total_usd >= curve.thresholds().usd_min {
         emit!(MigrationReadyEvent {
             total_sol,
+            total_usd,
             total_supply: curve.total_sold_supply,
             timestamp: Clock::get()?.unix_timestamp,
         });
         
-        msg!("Migration threshold reached: {} SOL. Ready for migration to Raydium.", total_sol);
+        msg!("Migration threshold reached: {} lamports, {} micro-USD. Ready for migration.", total_sol, total_usd);
     }
     
     Ok(())
@@ -313,18 +920,29 @@ pub fn check_auto_migration(ctx: &mut Context<CheckAutoMigration>) -> Result<()>
 /// Accounts required for automatic migration checking
 #[derive(Accounts)]
 pub struct CheckAutoMigration<'info> {
+    /// Configuration account (PDA); holds the oracle allowlist
+    #[account(
+        seeds = [seeds::CONFIG, config.mint.as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
     /// Bonding curve account (PDA)
     #[account(
-        seeds = [seeds::BONDING_CURVE],
+        seeds = [seeds::BONDING_CURVE, config.mint.as_ref()],
         bump = bonding_curve.bump
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// CHECK: Pyth SOL/USD price account on `config.oracle_feeds`, validated when read
+    pub sol_usd_price: UncheckedAccount<'info>,
 }
 
 /// Event emitted when migration threshold is reached
 #[event]
 pub struct MigrationReadyEvent {
     pub total_sol: u64,
+    pub total_usd: u64,
     pub total_supply: u64,
     pub timestamp: i64,
 }
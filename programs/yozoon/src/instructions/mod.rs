@@ -2,11 +2,20 @@ pub mod admin;
 pub mod airdrop;
 pub mod bonding_curve;
 pub mod contexts;
+pub mod fees;
+pub mod launchpad;
+pub mod legacy;
 pub mod migration;
 pub mod referral;
+pub mod refund;
+pub mod twap;
 
 // Re-export all context types at the instructions module level
 pub use contexts::*;
-// Explicitly re-export MigrateToRaydium from migration module
-pub use migration::MigrateToRaydium;
-pub use migration::CheckAutoMigration;
+// Re-export migration contexts (glob so the generated client account modules come along)
+pub use migration::*;
+pub use fees::*;
+pub use launchpad::*;
+pub use legacy::*;
+pub use refund::*;
+pub use twap::*;
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use crate::errors::YozoonError;
 use crate::events::*;
 use crate::instructions::contexts::*;
+use crate::state::*;
 use crate::utils::constants::*;
 
 /// Sets a referrer for a user
@@ -9,6 +10,9 @@ pub fn set_referral(
     ctx: Context<SetReferral>,
     referrer: Pubkey
 ) -> Result<()> {
+    // Check if referrals are paused
+    require!(!ctx.accounts.config.is_paused(pause_flags::REFERRAL), YozoonError::ProtocolPaused);
+    
     // Check if user is trying to refer themselves
     require!(
         ctx.accounts.user.key() != referrer,
@@ -18,9 +22,11 @@ pub fn set_referral(
     let referral = &mut ctx.accounts.referral;
     
     // Update referral
+    referral.version = Referral::VERSION;
     referral.referrer = referrer;
     referral.fee_percentage = DEFAULT_REFERRAL_FEE;
     referral.bump = *ctx.bumps.get("referral").unwrap();
+    referral.mint = ctx.accounts.config.mint;
     
     // Emit event
     emit!(ReferralSetEvent {
@@ -38,6 +44,9 @@ pub fn update_referral_fee(
     ctx: Context<UpdateReferralFee>,
     new_fee_percentage: u64
 ) -> Result<()> {
+    require!(!ctx.accounts.config.is_paused(pause_flags::REFERRAL), YozoonError::ProtocolPaused);
+    require!(!ctx.accounts.config.is_frozen(freeze_flags::FEES), YozoonError::ParametersFrozen);
+    
     let referral = &mut ctx.accounts.referral;
     let old_fee = referral.fee_percentage;
     
@@ -52,13 +61,13 @@ pub fn update_referral_fee(
     
     // Emit event
     emit!(ReferralFeeUpdatedEvent {
-        user: ctx.accounts.referral.key(),
+        user: ctx.accounts.user.key(),
         old_fee,
         new_fee: new_fee_percentage,
         timestamp: Clock::get()?.unix_timestamp,
     });
     
-    msg!("Referral fee updated for {} to {}bps", ctx.accounts.referral.key(), new_fee_percentage);
+    msg!("Referral fee updated for {} to {}bps", ctx.accounts.user.key(), new_fee_percentage);
     
     Ok(())
 }
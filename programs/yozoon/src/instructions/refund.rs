@@ -0,0 +1,194 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+use crate::errors::YozoonError;
+use crate::events::*;
+use crate::state::*;
+use crate::utils::constants::*;
+use crate::utils::helpers::mul_div;
+use crate::utils::token::with_thawed;
+
+/// Give up on a launch that will not migrate and open the reserve for refunds
+///
+/// Anyone may call this once the launch deadline has passed, or once the curve has
+/// been ready to migrate for `MIGRATION_REFUND_TIMEOUT` without migrating; the admin
+/// may call it earlier when migration keeps failing.
+pub fn enter_refund_mode(ctx: Context<EnterRefundMode>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let by_admin = ctx.accounts.caller.key() == ctx.accounts.config.admin;
+    let curve = &mut ctx.accounts.bonding_curve;
+    curve.require_refund_allowed(now, by_admin)?;
+    curve.state = CurveState::Refunding;
+    
+    let reserve = refundable_lamports(&ctx.accounts.sol_reserve)?;
+    emit!(RefundModeEnteredEvent {
+        caller: ctx.accounts.caller.key(),
+        by_admin,
+        reserve,
+        token_supply: curve.total_sold_supply,
+        timestamp: now,
+    });
+    
+    msg!("Refund mode entered: {} lamports for {} tokens", reserve, curve.total_sold_supply);
+    Ok(())
+}
+
+/// Burn tokens for a pro-rata share of the reserve
+///
+/// Only buys paid into the reserve, so it is shared over the supply sold through the
+/// curve: each claim pays `reserve * token_amount / total_sold_supply` and shrinks
+/// both by the same proportion. Like sells, refunds stop once the sold supply is used
+/// up, so airdropped tokens can never take more than buyers paid in. Not pausable,
+/// since it is the way out of a failed launch.
+pub fn claim_refund(ctx: Context<ClaimRefund>, token_amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.bonding_curve.state == CurveState::Refunding,
+        YozoonError::RefundModeNotActive
+    );
+    require!(token_amount > 0, YozoonError::AmountTooSmall);
+    
+    let sold_supply = ctx.accounts.bonding_curve.total_sold_supply;
+    require!(token_amount <= sold_supply, YozoonError::RefundExceedsSoldSupply);
+    
+    let reserve = refundable_lamports(&ctx.accounts.sol_reserve)?;
+    let sol_amount = mul_div(reserve, token_amount, sold_supply)
+        .ok_or(YozoonError::MathOverflow)?;
+    require!(sol_amount > 0, YozoonError::DustAmount);
+    
+    // Tokens of a mint frozen until migration never got to move, so thaw to burn them
+    let config_seeds: &[&[u8]] = &ctx.accounts.config.signer_seeds();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let mint = ctx.accounts.mint.to_account_info();
+    let config = ctx.accounts.config.to_account_info();
+    with_thawed(&token_program, &mint, &ctx.accounts.holder_token_account, &config, config_seeds, || {
+        token_interface::burn(
+            CpiContext::new(
+                token_program.clone(),
+                token_interface::Burn {
+                    mint: mint.clone(),
+                    from: ctx.accounts.holder_token_account.to_account_info(),
+                    authority: ctx.accounts.holder.to_account_info(),
+                },
+            ),
+            token_amount,
+        )
+    })?;
+    
+    anchor_lang::system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.sol_reserve.to_account_info(),
+                to: ctx.accounts.holder.to_account_info(),
+            },
+            &[&[seeds::SOL_RESERVE, ctx.accounts.config.mint.as_ref(), &[*ctx.bumps.get("sol_reserve").unwrap()]]],
+        ),
+        sol_amount,
+    )?;
+    
+    // The reserve may hold more than was raised (e.g. lamports sent to it directly),
+    // so the raised total only shrinks as far as zero
+    let curve = &mut ctx.accounts.bonding_curve;
+    curve.total_sold_supply = sold_supply - token_amount;
+    curve.total_sol_raised = curve.total_sol_raised.saturating_sub(sol_amount);
+    
+    emit!(RefundClaimedEvent {
+        holder: ctx.accounts.holder.key(),
+        token_amount,
+        sol_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Refunded {} lamports for {} tokens", sol_amount, token_amount);
+    Ok(())
+}
+
+/// Reserve lamports above its rent-exempt minimum
+fn refundable_lamports(sol_reserve: &SystemAccount) -> Result<u64> {
+    Ok(sol_reserve.lamports().saturating_sub(Rent::get()?.minimum_balance(0)))
+}
+
+/// Accounts for `enter_refund_mode`
+#[derive(Accounts)]
+pub struct EnterRefundMode<'info> {
+    /// Configuration account (PDA); identifies the admin
+    #[account(
+        seeds = [seeds::CONFIG, config.mint.as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Bonding curve account (PDA)
+    #[account(
+        mut,
+        seeds = [seeds::BONDING_CURVE, config.mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// Token mint (project token)
+    #[account(address = config.mint @ YozoonError::InvalidParameter)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// SOL reserve (PDA) the refunds are paid from
+    #[account(
+        seeds = [seeds::SOL_RESERVE, config.mint.as_ref()],
+        bump
+    )]
+    pub sol_reserve: SystemAccount<'info>,
+    
+    /// Anyone once the launch deadline has passed or the curve has been ready to
+    /// migrate for `MIGRATION_REFUND_TIMEOUT`; the admin as soon as it is ready to migrate
+    pub caller: Signer<'info>,
+}
+
+/// Accounts for `claim_refund`
+#[derive(Accounts)]
+pub struct ClaimRefund<'info> {
+    /// Configuration account (PDA)
+    #[account(
+        seeds = [seeds::CONFIG, config.mint.as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Bonding curve account (PDA)
+    #[account(
+        mut,
+        seeds = [seeds::BONDING_CURVE, config.mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// Token mint (project token)
+    #[account(
+        mut,
+        address = config.mint @ YozoonError::InvalidParameter
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// SOL reserve (PDA) the refund is paid from
+    #[account(
+        mut,
+        seeds = [seeds::SOL_RESERVE, config.mint.as_ref()],
+        bump
+    )]
+    pub sol_reserve: SystemAccount<'info>,
+    
+    /// Holder's token account the tokens are burned from
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = holder
+    )]
+    pub holder_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Token holder; receives the refund
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    
+    /// Token program of the mint
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::errors::YozoonError;
+use crate::state::*;
+use crate::utils::constants::*;
+use crate::utils::helpers::calculate_token_price;
+
+/// Create the price history of a curve that predates it
+///
+/// New curves get theirs in `initialize_bonding_curve`. Permissionless, since
+/// the history always starts empty at the current time.
+pub fn initialize_price_observations(ctx: Context<InitializePriceObservations>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .price_observations
+        .initialize(*ctx.bumps.get("price_observations").unwrap(), now);
+    
+    msg!("Price history started at {}", now);
+    Ok(())
+}
+
+/// Time-weighted average curve price over the last `window_secs` seconds
+pub fn get_twap(ctx: Context<GetTwap>, window_secs: u32) -> Result<u64> {
+    let curve = &ctx.accounts.bonding_curve;
+    let price = calculate_token_price(curve.total_sold_supply, &curve.price_points, ctx.accounts.config.max_supply());
+    let now = Clock::get()?.unix_timestamp;
+    
+    let twap = ctx.accounts
+        .price_observations
+        .twap(price, now, window_secs)
+        .ok_or(YozoonError::InsufficientObservations)?;
+    
+    msg!("TWAP over {}s: {}", window_secs, twap);
+    Ok(twap)
+}
+
+/// Accounts for `initialize_price_observations`
+#[derive(Accounts)]
+pub struct InitializePriceObservations<'info> {
+    /// Configuration account (PDA) of the launch
+    #[account(
+        seeds = [seeds::CONFIG, config.mint.as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Bonding curve account (PDA) the history belongs to
+    #[account(
+        seeds = [seeds::BONDING_CURVE, config.mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// Price history (PDA)
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PriceObservations::LEN,
+        seeds = [seeds::PRICE_OBSERVATIONS, config.mint.as_ref()],
+        bump
+    )]
+    pub price_observations: Box<Account<'info, PriceObservations>>,
+    
+    /// Pays for the account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for `get_twap`
+#[derive(Accounts)]
+pub struct GetTwap<'info> {
+    /// Configuration account (PDA); supplies the launch's max supply
+    #[account(
+        seeds = [seeds::CONFIG, config.mint.as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Bonding curve account (PDA); supplies the price since the last trade
+    #[account(
+        seeds = [seeds::BONDING_CURVE, config.mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// Price history (PDA)
+    #[account(
+        seeds = [seeds::PRICE_OBSERVATIONS, config.mint.as_ref()],
+        bump = price_observations.bump
+    )]
+    pub price_observations: Box<Account<'info, PriceObservations>>,
+}
@@ -1,5 +1,7 @@
+// Anchor's `Error` is large by design and every handler returns it
+#![allow(clippy::result_large_err)]
+
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
 declare_id!("3J6Hu2iwgwuU4gvQACsHqEsfrRog6EzoaEWo1oZ8NHNx");
 
@@ -8,181 +10,211 @@ pub mod events;
 pub mod state;
 pub mod utils;
 pub mod raydium;
+pub mod dex;
+pub mod metadata;
 pub mod instructions;
 
+// Context types must be reachable from the crate root for the program macro.
+// Handler names overlap with the program module's, which only matters for glob users.
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::*;
+pub use raydium::LpLockMode;
+pub use dex::MigrationTargetKind;
+pub use state::{MigrationThresholds, MintExtensions};
+
 #[program]
 pub mod yozoon {
     use super::*;
-    
-    pub fn initialize_mint(ctx: Context<InitializeMint>) -> Result<()> {
-        msg!("Initializing mint");
-        let config = &mut ctx.accounts.config;
-        config.admin = ctx.accounts.admin.key();
-        config.mint = ctx.accounts.mint.key();
-        config.bump = *ctx.bumps.get("config").unwrap();
-        config.paused = false;
-        config.total_supply = 0;
-        config.total_value = 0;
-        config.treasury = ctx.accounts.admin.key();
-        config.pending_admin = None;
-        
-        Ok(())
-    }
-    
-    pub fn initialize_bonding_curve(ctx: Context<InitializeBondingCurve>, price_points: Vec<u64>) -> Result<()> {
-        let curve = &mut ctx.accounts.bonding_curve;
-        curve.price_points = price_points;
-        curve.total_sold_supply = 0;
-        curve.total_sol_raised = 0;
-        curve.bump = *ctx.bumps.get("bonding_curve").unwrap();
-        curve.is_migrated = false;
-        
-        Ok(())
-    }
-    
+
+    // Launchpad instructions
+    pub fn initialize_launchpad(ctx: Context<InitializeLaunchpad>, creation_fee: u64) -> Result<()> {
+        instructions::launchpad::initialize_launchpad(ctx, creation_fee)
+    }
+
+    pub fn set_creation_fee(ctx: Context<LaunchpadAction>, creation_fee: u64, fee_recipient: Pubkey) -> Result<()> {
+        instructions::launchpad::set_creation_fee(ctx, creation_fee, fee_recipient)
+    }
+
+    pub fn set_launch_creators(ctx: Context<LaunchpadAction>, creators: Vec<Pubkey>) -> Result<()> {
+        instructions::launchpad::set_launch_creators(ctx, creators)
+    }
+
+    pub fn transfer_launchpad_authority(ctx: Context<LaunchpadAction>, new_authority: Pubkey) -> Result<()> {
+        instructions::launchpad::transfer_launchpad_authority(ctx, new_authority)
+    }
+
+    pub fn accept_launchpad_authority(ctx: Context<AcceptLaunchpadAuthority>) -> Result<()> {
+        instructions::launchpad::accept_launchpad_authority(ctx)
+    }
+
+    pub fn cancel_launchpad_authority_transfer(ctx: Context<LaunchpadAction>) -> Result<()> {
+        instructions::launchpad::cancel_launchpad_authority_transfer(ctx)
+    }
+
+    // Admin instructions
+    pub fn initialize_mint(
+        ctx: Context<InitializeMint>,
+        name: String,
+        symbol: String,
+        uri: String,
+        decimals: u8,
+        max_supply: u64,
+        extensions: MintExtensions,
+    ) -> Result<()> {
+        instructions::admin::initialize_mint(ctx, name, symbol, uri, decimals, max_supply, extensions)
+    }
+
+    pub fn update_metadata(ctx: Context<UpdateMetadata>, name: String, symbol: String, uri: String) -> Result<()> {
+        instructions::admin::update_metadata(ctx, name, symbol, uri)
+    }
+
+    pub fn transfer_admin(ctx: Context<AdminAction>, new_admin: Pubkey) -> Result<()> {
+        instructions::admin::transfer_admin(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::admin::accept_admin(ctx)
+    }
+
+    pub fn cancel_admin_transfer(ctx: Context<AdminAction>) -> Result<()> {
+        instructions::admin::cancel_admin_transfer(ctx)
+    }
+
+    pub fn set_pause_state(ctx: Context<AdminAction>, pause_mask: u8) -> Result<()> {
+        instructions::admin::set_pause_state(ctx, pause_mask)
+    }
+
+    pub fn set_treasury(ctx: Context<AdminAction>, new_treasury: Pubkey) -> Result<()> {
+        instructions::admin::set_treasury(ctx, new_treasury)
+    }
+
+    pub fn set_migration_bounty(ctx: Context<AdminAction>, new_bounty: u64) -> Result<()> {
+        instructions::admin::set_migration_bounty(ctx, new_bounty)
+    }
+
+    pub fn freeze_parameters(ctx: Context<AdminAction>, freeze_mask: u8) -> Result<()> {
+        instructions::admin::freeze_parameters(ctx, freeze_mask)
+    }
+
+    pub fn set_oracle_feeds(ctx: Context<AdminAction>, feeds: Vec<Pubkey>) -> Result<()> {
+        instructions::admin::set_oracle_feeds(ctx, feeds)
+    }
+
+    pub fn renounce_admin(ctx: Context<AdminAction>) -> Result<()> {
+        instructions::admin::renounce_admin(ctx)
+    }
+
+    pub fn migrate_account_layout(ctx: Context<MigrateAccountLayout>) -> Result<()> {
+        instructions::admin::migrate_account_layout(ctx)
+    }
+
+    pub fn rekey_legacy_launch(ctx: Context<RekeyLegacyLaunch>) -> Result<()> {
+        instructions::legacy::rekey_legacy_launch(ctx)
+    }
+
+    pub fn rekey_legacy_referral(ctx: Context<RekeyLegacyReferral>) -> Result<()> {
+        instructions::legacy::rekey_legacy_referral(ctx)
+    }
+
+    // Bonding curve instructions
+    pub fn initialize_bonding_curve(
+        ctx: Context<InitializeBondingCurve>,
+        price_points: Vec<u64>,
+        thresholds: MigrationThresholds,
+        migration_target: MigrationTargetKind,
+        launch_deadline: i64,
+    ) -> Result<()> {
+        instructions::bonding_curve::initialize_bonding_curve(
+            ctx,
+            price_points,
+            thresholds,
+            migration_target,
+            launch_deadline,
+        )
+    }
+
+    pub fn set_migration_thresholds(ctx: Context<SetMigrationThresholds>, thresholds: MigrationThresholds) -> Result<()> {
+        instructions::bonding_curve::set_migration_thresholds(ctx, thresholds)
+    }
+
     pub fn buy_tokens(ctx: Context<BuyTokens>, sol_amount: u64) -> Result<()> {
-        let curve = &mut ctx.accounts.bonding_curve;
-        
-        // Example validation
-        require!(!curve.is_migrated, errors::YozoonError::Migrated);
-        
-        // Example calculation
-        let tokens = sol_amount * 1_000_000_000;
-        
-        // Mint tokens
-        token::mint_to(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                token::MintTo {
-                    mint: ctx.accounts.mint.to_account_info(),
-                    to: ctx.accounts.buyer_token_account.to_account_info(),
-                    authority: ctx.accounts.config.to_account_info(),
-                },
-                &[&[b"config", &[ctx.accounts.config.bump]]],
-            ),
-            tokens,
-        )?;
-        
-        // Update state
-        curve.total_sold_supply += tokens;
-        curve.total_sol_raised += sol_amount;
-        
-        Ok(())
-    }
-    
-    pub fn migrate_to_raydium(ctx: Context<MigrateContext>) -> Result<()> {
-        let curve = &mut ctx.accounts.bonding_curve;
-        curve.is_migrated = true;
-        
-        // Emit migration event
-        emit!(events::MigrationEvent {
-            total_sol: curve.total_sol_raised,
-            total_usd: 0,
-            total_supply: curve.total_sold_supply,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-        
-        Ok(())
+        instructions::bonding_curve::buy_tokens(ctx, sol_amount)
     }
-}
 
-#[derive(Accounts)]
-pub struct InitializeMint<'info> {
-    #[account(
-        init,
-        payer = admin,
-        space = 8 + state::Config::LEN,
-        seeds = [b"config"],
-        bump
-    )]
-    pub config: Account<'info, state::Config>,
-    
-    #[account(
-        init,
-        payer = admin,
-        mint::decimals = 9,
-        mint::authority = config,
-    )]
-    pub mint: Account<'info, Mint>,
-    
-    #[account(mut)]
-    pub admin: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
-    pub rent: Sysvar<'info, Rent>,
-}
+    pub fn buy_tokens_usd(ctx: Context<BuyTokens>, usd_amount: u64, max_sol_in: u64) -> Result<()> {
+        instructions::bonding_curve::buy_tokens_usd(ctx, usd_amount, max_sol_in)
+    }
 
-#[derive(Accounts)]
-pub struct InitializeBondingCurve<'info> {
-    #[account(
-        init,
-        payer = admin,
-        space = 8 + state::BondingCurve::LEN,
-        seeds = [b"bonding_curve"],
-        bump
-    )]
-    pub bonding_curve: Account<'info, state::BondingCurve>,
-    
-    #[account(mut)]
-    pub admin: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+    pub fn sell_tokens(ctx: Context<SellTokens>, token_amount: u64) -> Result<()> {
+        instructions::bonding_curve::sell_tokens(ctx, token_amount)
+    }
 
-#[derive(Accounts)]
-pub struct BuyTokens<'info> {
-    #[account(
-        mut,
-        seeds = [b"config"],
-        bump = config.bump
-    )]
-    pub config: Account<'info, state::Config>,
-    
-    #[account(
-        mut,
-        seeds = [b"bonding_curve"],
-        bump = bonding_curve.bump
-    )]
-    pub bonding_curve: Account<'info, state::BondingCurve>,
-    
-    #[account(mut)]
-    pub mint: Account<'info, Mint>,
-    
-    #[account(
-        mut,
-        constraint = buyer_token_account.owner == buyer.key()
-    )]
-    pub buyer_token_account: Account<'info, TokenAccount>,
-    
-    #[account(mut)]
-    pub buyer: Signer<'info>,
-    
-    #[account(mut)]
-    pub treasury: SystemAccount<'info>,
-    
-    pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
-}
+    pub fn calculate_current_price(ctx: Context<GetCurrentPrice>) -> Result<u64> {
+        instructions::bonding_curve::calculate_current_price(ctx)
+    }
+
+    pub fn calculate_tokens_for_sol(ctx: Context<CalculateTokens>, sol_amount: u64) -> Result<u64> {
+        instructions::bonding_curve::calculate_tokens_for_sol(ctx, sol_amount)
+    }
+
+    // Price history instructions
+    pub fn initialize_price_observations(ctx: Context<InitializePriceObservations>) -> Result<()> {
+        instructions::twap::initialize_price_observations(ctx)
+    }
+
+    pub fn get_twap(ctx: Context<GetTwap>, window_secs: u32) -> Result<u64> {
+        instructions::twap::get_twap(ctx, window_secs)
+    }
+
+    // Referral instructions
+    pub fn set_referral(ctx: Context<SetReferral>, referrer: Pubkey) -> Result<()> {
+        instructions::referral::set_referral(ctx, referrer)
+    }
+
+    pub fn update_referral_fee(ctx: Context<UpdateReferralFee>, new_fee_percentage: u64) -> Result<()> {
+        instructions::referral::update_referral_fee(ctx, new_fee_percentage)
+    }
+
+    // Airdrop instructions
+    pub fn airdrop_tokens(ctx: Context<AirdropTokens>, amount: u64) -> Result<()> {
+        instructions::airdrop::airdrop_tokens(ctx, amount)
+    }
+
+    // Migration instructions
+    pub fn migrate<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, Migrate<'info>>,
+        lp_lock_mode: LpLockMode,
+    ) -> Result<()> {
+        instructions::migration::migrate(&mut ctx, lp_lock_mode)
+    }
+
+    pub fn finalize_migration(ctx: Context<FinalizeMigration>) -> Result<()> {
+        instructions::migration::finalize_migration(ctx)
+    }
+
+    pub fn thaw_token_account(ctx: Context<ThawTokenAccount>) -> Result<()> {
+        instructions::migration::thaw_token_account(ctx)
+    }
+
+    pub fn check_auto_migration(mut ctx: Context<CheckAutoMigration>) -> Result<()> {
+        instructions::migration::check_auto_migration(&mut ctx)
+    }
+
+    // Refund instructions
+    pub fn enter_refund_mode(ctx: Context<EnterRefundMode>) -> Result<()> {
+        instructions::refund::enter_refund_mode(ctx)
+    }
 
-#[derive(Accounts)]
-pub struct MigrateContext<'info> {
-    #[account(
-        mut,
-        seeds = [b"config"],
-        bump = config.bump
-    )]
-    pub config: Account<'info, state::Config>,
-    
-    #[account(
-        mut,
-        seeds = [b"bonding_curve"],
-        bump = bonding_curve.bump
-    )]
-    pub bonding_curve: Account<'info, state::BondingCurve>,
-    
-    #[account(
-        constraint = admin.key() == config.admin @ errors::YozoonError::Unauthorized
-    )]
-    pub admin: Signer<'info>,
+    pub fn claim_refund(ctx: Context<ClaimRefund>, token_amount: u64) -> Result<()> {
+        instructions::refund::claim_refund(ctx, token_amount)
+    }
+
+    // Fee instructions
+    pub fn claim_pool_fees(mut ctx: Context<ClaimPoolFees>) -> Result<()> {
+        instructions::fees::claim_pool_fees(&mut ctx)
+    }
+
+    pub fn split_fee_key(ctx: Context<SplitFeeKey>, share_bps: u64) -> Result<()> {
+        instructions::fees::split_fee_key(ctx, share_bps)
+    }
 }
@@ -0,0 +1,255 @@
+//! Metaplex Token Metadata instruction builders
+//!
+//! Only the two instructions the program calls are built here, by hand, in the
+//! program's Borsh layout: the metadata program's instructions are a Borsh enum,
+//! so each one starts with its variant index.
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use std::str::FromStr;
+use crate::errors::YozoonError;
+use crate::utils::constants::TOKEN_METADATA_PROGRAM_ID;
+
+/// Variant index of `CreateMetadataAccountV3`
+pub const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+
+/// Variant index of `UpdateMetadataAccountV2`
+pub const UPDATE_METADATA_ACCOUNT_V2: u8 = 15;
+
+/// Longest name the metadata program accepts
+pub const MAX_NAME_LENGTH: usize = 32;
+
+/// Longest symbol the metadata program accepts
+pub const MAX_SYMBOL_LENGTH: usize = 10;
+
+/// Longest URI the metadata program accepts
+pub const MAX_URI_LENGTH: usize = 200;
+
+/// Seed of metadata account PDAs
+pub const METADATA_SEED: &[u8] = b"metadata";
+
+/// Token Metadata program (for `Program<'info, TokenMetadataProgram>`)
+#[derive(Clone)]
+pub struct TokenMetadataProgram;
+
+impl anchor_lang::Id for TokenMetadataProgram {
+    fn id() -> Pubkey {
+        Pubkey::from_str(TOKEN_METADATA_PROGRAM_ID).unwrap()
+    }
+}
+
+/// Derive the metadata account of `mint`
+pub fn metadata_address(mint: &Pubkey) -> Pubkey {
+    let program_id = TokenMetadataProgram::id();
+    Pubkey::find_program_address(&[METADATA_SEED, program_id.as_ref(), mint.as_ref()], &program_id).0
+}
+
+/// Creator entry of `DataV2`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Creator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+/// Collection entry of `DataV2`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Collection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+/// Uses entry of `DataV2`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Uses {
+    pub use_method: u8,
+    pub remaining: u64,
+    pub total: u64,
+}
+
+/// On-chain token description
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DataV2 {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub creators: Option<Vec<Creator>>,
+    pub collection: Option<Collection>,
+    pub uses: Option<Uses>,
+}
+
+impl DataV2 {
+    /// Fungible token description: no royalties, creators, collection or uses
+    pub fn fungible(name: String, symbol: String, uri: String) -> Result<Self> {
+        require!(
+            name.len() <= MAX_NAME_LENGTH && symbol.len() <= MAX_SYMBOL_LENGTH && uri.len() <= MAX_URI_LENGTH,
+            YozoonError::MetadataTooLong
+        );
+        Ok(Self {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        })
+    }
+}
+
+/// Collection details of `CreateMetadataAccountArgsV3` (only for collection parents)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum CollectionDetails {
+    V1 { size: u64 },
+}
+
+/// Arguments of `CreateMetadataAccountV3`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateMetadataAccountArgsV3 {
+    pub data: DataV2,
+    pub is_mutable: bool,
+    pub collection_details: Option<CollectionDetails>,
+}
+
+/// Arguments of `UpdateMetadataAccountV2`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMetadataAccountArgsV2 {
+    pub data: Option<DataV2>,
+    pub update_authority: Option<Pubkey>,
+    pub primary_sale_happened: Option<bool>,
+    pub is_mutable: Option<bool>,
+}
+
+fn instruction<T: AnchorSerialize>(variant: u8, args: &T, accounts: Vec<AccountMeta>) -> Instruction {
+    let mut data = vec![variant];
+    data.extend(args.try_to_vec().unwrap());
+    Instruction {
+        program_id: TokenMetadataProgram::id(),
+        accounts,
+        data,
+    }
+}
+
+/// Build `CreateMetadataAccountV3`; the update authority signs so it can be a PDA
+pub fn create_metadata_account_v3(
+    metadata: Pubkey,
+    mint: Pubkey,
+    mint_authority: Pubkey,
+    payer: Pubkey,
+    update_authority: Pubkey,
+    data: DataV2,
+    is_mutable: bool,
+) -> Instruction {
+    instruction(
+        CREATE_METADATA_ACCOUNT_V3,
+        &CreateMetadataAccountArgsV3 {
+            data,
+            is_mutable,
+            collection_details: None,
+        },
+        vec![
+            AccountMeta::new(metadata, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(mint_authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(update_authority, true),
+            AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+            AccountMeta::new_readonly(anchor_lang::solana_program::sysvar::rent::ID, false),
+        ],
+    )
+}
+
+/// Build `UpdateMetadataAccountV2`, replacing only the token description
+pub fn update_metadata_account_v2(metadata: Pubkey, update_authority: Pubkey, data: DataV2) -> Instruction {
+    instruction(
+        UPDATE_METADATA_ACCOUNT_V2,
+        &UpdateMetadataAccountArgsV2 {
+            data: Some(data),
+            update_authority: None,
+            primary_sale_happened: None,
+            is_mutable: None,
+        },
+        vec![
+            AccountMeta::new(metadata, false),
+            AccountMeta::new_readonly(update_authority, true),
+        ],
+    )
+}
+
+/// Build `UpdateMetadataAccountV2`, handing the update authority to `new_update_authority`
+pub fn set_update_authority(metadata: Pubkey, update_authority: Pubkey, new_update_authority: Pubkey) -> Instruction {
+    instruction(
+        UPDATE_METADATA_ACCOUNT_V2,
+        &UpdateMetadataAccountArgsV2 {
+            data: None,
+            update_authority: Some(new_update_authority),
+            primary_sale_happened: None,
+            is_mutable: None,
+        },
+        vec![
+            AccountMeta::new(metadata, false),
+            AccountMeta::new_readonly(update_authority, true),
+        ],
+    )
+}
+
+/// Update authority stored in a metadata account's data (after its one-byte key)
+pub fn update_authority_of(data: &[u8]) -> Option<Pubkey> {
+    data.get(1..33).map(|bytes| Pubkey::try_from(bytes).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_data_is_variant_then_borsh_args() {
+        let data = DataV2::fungible("Yozoon".into(), "YOZ".into(), "https://yozoon.io/t.json".into()).unwrap();
+        let ix = create_metadata_account_v3(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            data,
+            true,
+        );
+
+        let mut expected = vec![CREATE_METADATA_ACCOUNT_V3];
+        for field in ["Yozoon", "YOZ", "https://yozoon.io/t.json"] {
+            expected.extend((field.len() as u32).to_le_bytes());
+            expected.extend(field.as_bytes());
+        }
+        // Royalties, no creators, collection or uses, mutable, no collection details
+        expected.extend([0, 0, 0, 0, 0, 1, 0]);
+        assert_eq!(ix.data, expected);
+        assert_eq!(ix.accounts.len(), 7);
+    }
+
+    #[test]
+    fn set_update_authority_changes_nothing_else() {
+        let new_authority = Pubkey::new_unique();
+        let ix = set_update_authority(Pubkey::new_unique(), Pubkey::new_unique(), new_authority);
+
+        // No data, the new authority, no sale or mutability change
+        let mut expected = vec![UPDATE_METADATA_ACCOUNT_V2, 0, 1];
+        expected.extend(new_authority.as_ref());
+        expected.extend([0, 0]);
+        assert_eq!(ix.data, expected);
+        assert!(ix.accounts[1].is_signer);
+
+        let mut data = vec![4];
+        data.extend(new_authority.as_ref());
+        assert_eq!(update_authority_of(&data), Some(new_authority));
+        assert_eq!(update_authority_of(&[4]), None);
+    }
+
+    #[test]
+    fn fungible_data_respects_metadata_limits() {
+        let at_limit = |n: usize| "x".repeat(n);
+        assert!(DataV2::fungible(at_limit(MAX_NAME_LENGTH), at_limit(MAX_SYMBOL_LENGTH), at_limit(MAX_URI_LENGTH)).is_ok());
+        assert!(DataV2::fungible(at_limit(MAX_NAME_LENGTH + 1), String::new(), String::new()).is_err());
+        assert!(DataV2::fungible(String::new(), at_limit(MAX_SYMBOL_LENGTH + 1), String::new()).is_err());
+        assert!(DataV2::fungible(String::new(), String::new(), at_limit(MAX_URI_LENGTH + 1)).is_err());
+    }
+}
@@ -0,0 +1,391 @@
+//! Raydium CPMM (`raydium-cp-swap`) instruction builders
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use crate::dex::integer_sqrt;
+
+/// Anchor discriminator of `initialize` (sha256("global:initialize")[..8])
+pub const INITIALIZE_DISCRIMINATOR: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
+
+/// Anchor discriminator of `withdraw` (sha256("global:withdraw")[..8])
+pub const WITHDRAW_DISCRIMINATOR: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
+
+/// SPL Memo program, which CPMM `withdraw` takes for Token-2022 transfers
+pub const MEMO_PROGRAM_ID: &str = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr";
+
+/// Offset of `lp_supply` in the `PoolState` account: the discriminator, ten pubkeys
+/// (config, creator, both vaults, LP mint, both mints, both token programs and the
+/// observation account) and five one-byte fields (bump, status and three decimals)
+const POOL_STATE_LP_SUPPLY_OFFSET: usize = 8 + 10 * 32 + 5;
+
+/// Seeds used by the CPMM program for its PDAs
+pub mod seeds {
+    pub const AMM_CONFIG: &[u8] = b"amm_config";
+    pub const AUTHORITY: &[u8] = b"vault_and_lp_mint_auth_seed";
+    pub const POOL: &[u8] = b"pool";
+    pub const POOL_LP_MINT: &[u8] = b"pool_lp_mint";
+    pub const POOL_VAULT: &[u8] = b"pool_vault";
+    pub const OBSERVATION: &[u8] = b"observation";
+}
+
+/// Arguments of the CPMM `initialize` instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InitializeArgs {
+    /// Initial deposit of token 0
+    pub init_amount_0: u64,
+    /// Initial deposit of token 1
+    pub init_amount_1: u64,
+    /// Unix timestamp at which swaps open (0 = immediately)
+    pub open_time: u64,
+}
+
+/// Order two mints the way CPMM requires (token 0 < token 1)
+pub fn sort_mints(a: Pubkey, b: Pubkey) -> (Pubkey, Pubkey) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Derive the AMM config address for a fee tier index
+pub fn amm_config_address(program_id: &Pubkey, index: u16) -> Pubkey {
+    Pubkey::find_program_address(&[seeds::AMM_CONFIG, &index.to_be_bytes()], program_id).0
+}
+
+/// PDAs owned by the CPMM program for a single pool
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoolAddresses {
+    pub authority: Pubkey,
+    pub pool_state: Pubkey,
+    pub lp_mint: Pubkey,
+    pub token_0_vault: Pubkey,
+    pub token_1_vault: Pubkey,
+    pub observation_state: Pubkey,
+}
+
+impl PoolAddresses {
+    /// Derive the pool PDAs; the mints must already be sorted with `sort_mints`
+    pub fn derive(
+        program_id: &Pubkey,
+        amm_config: &Pubkey,
+        token_0_mint: &Pubkey,
+        token_1_mint: &Pubkey,
+    ) -> Self {
+        let pda = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, program_id).0;
+        let pool_state = pda(&[
+            seeds::POOL,
+            amm_config.as_ref(),
+            token_0_mint.as_ref(),
+            token_1_mint.as_ref(),
+        ]);
+        Self {
+            authority: pda(&[seeds::AUTHORITY]),
+            pool_state,
+            lp_mint: pda(&[seeds::POOL_LP_MINT, pool_state.as_ref()]),
+            token_0_vault: pda(&[seeds::POOL_VAULT, pool_state.as_ref(), token_0_mint.as_ref()]),
+            token_1_vault: pda(&[seeds::POOL_VAULT, pool_state.as_ref(), token_1_mint.as_ref()]),
+            observation_state: pda(&[seeds::OBSERVATION, pool_state.as_ref()]),
+        }
+    }
+}
+
+/// Full account list of the CPMM `initialize` instruction, in program order
+#[derive(Clone, Copy, Debug)]
+pub struct InitializeAccounts {
+    pub creator: Pubkey,
+    pub amm_config: Pubkey,
+    pub authority: Pubkey,
+    pub pool_state: Pubkey,
+    pub token_0_mint: Pubkey,
+    pub token_1_mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub creator_token_0: Pubkey,
+    pub creator_token_1: Pubkey,
+    pub creator_lp_token: Pubkey,
+    pub token_0_vault: Pubkey,
+    pub token_1_vault: Pubkey,
+    pub create_pool_fee: Pubkey,
+    pub observation_state: Pubkey,
+    pub token_program: Pubkey,
+    pub token_0_program: Pubkey,
+    pub token_1_program: Pubkey,
+    pub associated_token_program: Pubkey,
+    pub system_program: Pubkey,
+    pub rent: Pubkey,
+}
+
+impl InitializeAccounts {
+    /// Account metas with the signer/writable flags CPMM expects
+    pub fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.creator, true),
+            AccountMeta::new_readonly(self.amm_config, false),
+            AccountMeta::new_readonly(self.authority, false),
+            AccountMeta::new(self.pool_state, false),
+            AccountMeta::new_readonly(self.token_0_mint, false),
+            AccountMeta::new_readonly(self.token_1_mint, false),
+            AccountMeta::new(self.lp_mint, false),
+            AccountMeta::new(self.creator_token_0, false),
+            AccountMeta::new(self.creator_token_1, false),
+            AccountMeta::new(self.creator_lp_token, false),
+            AccountMeta::new(self.token_0_vault, false),
+            AccountMeta::new(self.token_1_vault, false),
+            AccountMeta::new(self.create_pool_fee, false),
+            AccountMeta::new(self.observation_state, false),
+            AccountMeta::new_readonly(self.token_program, false),
+            AccountMeta::new_readonly(self.token_0_program, false),
+            AccountMeta::new_readonly(self.token_1_program, false),
+            AccountMeta::new_readonly(self.associated_token_program, false),
+            AccountMeta::new_readonly(self.system_program, false),
+            AccountMeta::new_readonly(self.rent, false),
+        ]
+    }
+}
+
+/// Serialize `initialize` instruction data (discriminator followed by the Borsh args)
+pub fn initialize_instruction_data(args: &InitializeArgs) -> Vec<u8> {
+    let mut data = INITIALIZE_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&args.init_amount_0.to_le_bytes());
+    data.extend_from_slice(&args.init_amount_1.to_le_bytes());
+    data.extend_from_slice(&args.open_time.to_le_bytes());
+    data
+}
+
+/// Build the CPMM `initialize` instruction
+pub fn initialize_instruction(
+    program_id: Pubkey,
+    accounts: &InitializeAccounts,
+    args: &InitializeArgs,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: accounts.to_account_metas(),
+        data: initialize_instruction_data(args),
+    }
+}
+
+/// Arguments of the CPMM `withdraw` instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WithdrawArgs {
+    /// LP tokens to burn
+    pub lp_token_amount: u64,
+    /// Least token 0 the withdrawal must pay out
+    pub minimum_token_0_amount: u64,
+    /// Least token 1 the withdrawal must pay out
+    pub minimum_token_1_amount: u64,
+}
+
+/// Full account list of the CPMM `withdraw` instruction, in program order
+#[derive(Clone, Copy, Debug)]
+pub struct WithdrawAccounts {
+    pub owner: Pubkey,
+    pub authority: Pubkey,
+    pub pool_state: Pubkey,
+    pub owner_lp_token: Pubkey,
+    pub token_0_account: Pubkey,
+    pub token_1_account: Pubkey,
+    pub token_0_vault: Pubkey,
+    pub token_1_vault: Pubkey,
+    pub token_program: Pubkey,
+    pub token_program_2022: Pubkey,
+    pub vault_0_mint: Pubkey,
+    pub vault_1_mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub memo_program: Pubkey,
+}
+
+impl WithdrawAccounts {
+    /// Account metas with the signer/writable flags CPMM expects
+    pub fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.owner, true),
+            AccountMeta::new_readonly(self.authority, false),
+            AccountMeta::new(self.pool_state, false),
+            AccountMeta::new(self.owner_lp_token, false),
+            AccountMeta::new(self.token_0_account, false),
+            AccountMeta::new(self.token_1_account, false),
+            AccountMeta::new(self.token_0_vault, false),
+            AccountMeta::new(self.token_1_vault, false),
+            AccountMeta::new_readonly(self.token_program, false),
+            AccountMeta::new_readonly(self.token_program_2022, false),
+            AccountMeta::new_readonly(self.vault_0_mint, false),
+            AccountMeta::new_readonly(self.vault_1_mint, false),
+            AccountMeta::new(self.lp_mint, false),
+            AccountMeta::new_readonly(self.memo_program, false),
+        ]
+    }
+}
+
+/// Build the CPMM `withdraw` instruction
+pub fn withdraw_instruction(
+    program_id: Pubkey,
+    accounts: &WithdrawAccounts,
+    args: &WithdrawArgs,
+) -> Instruction {
+    let mut data = WITHDRAW_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&args.lp_token_amount.to_le_bytes());
+    data.extend_from_slice(&args.minimum_token_0_amount.to_le_bytes());
+    data.extend_from_slice(&args.minimum_token_1_amount.to_le_bytes());
+    Instruction {
+        program_id,
+        accounts: accounts.to_account_metas(),
+        data,
+    }
+}
+
+/// Read the LP supply and the LP-owned reserves from a `PoolState` account
+///
+/// The vaults also hold protocol and fund fees that belong to Raydium; those are
+/// subtracted, as CPMM does when it prices a deposit or withdrawal.
+pub fn pool_reserves(pool_state: &[u8], vault_0_amount: u64, vault_1_amount: u64) -> Option<(u64, u64, u64)> {
+    let read = |index: usize| {
+        let start = POOL_STATE_LP_SUPPLY_OFFSET + index * 8;
+        let bytes = pool_state.get(start..start + 8)?;
+        Some(u64::from_le_bytes(bytes.try_into().ok()?))
+    };
+    let lp_supply = read(0)?;
+    let (protocol_fees_0, protocol_fees_1) = (read(1)?, read(2)?);
+    let (fund_fees_0, fund_fees_1) = (read(3)?, read(4)?);
+    let reserve_0 = vault_0_amount.checked_sub(protocol_fees_0)?.checked_sub(fund_fees_0)?;
+    let reserve_1 = vault_1_amount.checked_sub(protocol_fees_1)?.checked_sub(fund_fees_1)?;
+    Some((lp_supply, reserve_0, reserve_1))
+}
+
+/// LP tokens of a locked position that are fee growth rather than principal
+///
+/// CPMM leaves trading fees in the reserves, so they show up as growth of
+/// sqrt(reserve_0 * reserve_1) per LP token. A pool opens with exactly that many LP
+/// tokens, so the `principal_lp` locked at creation was worth one unit of sqrt(k)
+/// each; withdrawing whatever the lock holds beyond that value pays out the fees and
+/// leaves the principal in the pool.
+pub fn fee_growth_lp(
+    locked_lp: u64,
+    principal_lp: u64,
+    lp_supply: u64,
+    reserve_0: u64,
+    reserve_1: u64,
+) -> Option<u64> {
+    let root_k = integer_sqrt((reserve_0 as u128).checked_mul(reserve_1 as u128)?);
+    if root_k == 0 {
+        return Some(0);
+    }
+    // LP tokens now worth the principal, rounded up so the principal is never withdrawn
+    let principal_now = (principal_lp as u128)
+        .checked_mul(lp_supply as u128)?
+        .checked_add(root_k - 1)?
+        / root_k;
+    Some((locked_lp as u128).saturating_sub(principal_now) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::hash::hash;
+
+    #[test]
+    fn discriminator_matches_anchor_sighash() {
+        let sighash = hash(b"global:initialize");
+        assert_eq!(INITIALIZE_DISCRIMINATOR, sighash.to_bytes()[..8]);
+
+        let sighash = hash(b"global:withdraw");
+        assert_eq!(WITHDRAW_DISCRIMINATOR, sighash.to_bytes()[..8]);
+    }
+
+    #[test]
+    fn serializes_initialize_data() {
+        let args = InitializeArgs {
+            init_amount_0: 1_000_000_000,
+            init_amount_1: 2_500_000,
+            open_time: 1_700_000_000,
+        };
+        let expected: [u8; 32] = [
+            175, 175, 109, 31, 13, 152, 155, 237, // discriminator
+            0x00, 0xca, 0x9a, 0x3b, 0x00, 0x00, 0x00, 0x00, // init_amount_0
+            0xa0, 0x25, 0x26, 0x00, 0x00, 0x00, 0x00, 0x00, // init_amount_1
+            0x00, 0xf1, 0x53, 0x65, 0x00, 0x00, 0x00, 0x00, // open_time
+        ];
+        assert_eq!(initialize_instruction_data(&args), expected);
+
+        // Matches Anchor's own Borsh encoding of the args
+        let mut borsh = INITIALIZE_DISCRIMINATOR.to_vec();
+        borsh.extend(args.try_to_vec().unwrap());
+        assert_eq!(borsh, expected);
+    }
+
+    #[test]
+    fn builds_initialize_account_list_in_program_order() {
+        let keys: Vec<Pubkey> = (0..20).map(|_| Pubkey::new_unique()).collect();
+        let accounts = InitializeAccounts {
+            creator: keys[0],
+            amm_config: keys[1],
+            authority: keys[2],
+            pool_state: keys[3],
+            token_0_mint: keys[4],
+            token_1_mint: keys[5],
+            lp_mint: keys[6],
+            creator_token_0: keys[7],
+            creator_token_1: keys[8],
+            creator_lp_token: keys[9],
+            token_0_vault: keys[10],
+            token_1_vault: keys[11],
+            create_pool_fee: keys[12],
+            observation_state: keys[13],
+            token_program: keys[14],
+            token_0_program: keys[15],
+            token_1_program: keys[16],
+            associated_token_program: keys[17],
+            system_program: keys[18],
+            rent: keys[19],
+        };
+        let metas = accounts.to_account_metas();
+        assert_eq!(metas.len(), 20);
+        assert!(metas.iter().map(|m| m.pubkey).eq(keys.iter().copied()));
+
+        let signers: Vec<usize> = (0..20).filter(|&i| metas[i].is_signer).collect();
+        let writable: Vec<usize> = (0..20).filter(|&i| metas[i].is_writable).collect();
+        assert_eq!(signers, vec![0]);
+        assert_eq!(writable, vec![0, 3, 6, 7, 8, 9, 10, 11, 12, 13]);
+    }
+
+    #[test]
+    fn derives_pool_addresses_from_sorted_mints() {
+        let program_id = Pubkey::new_unique();
+        let (token_0, token_1) = sort_mints(Pubkey::new_unique(), Pubkey::new_unique());
+        assert!(token_0 < token_1);
+
+        let amm_config = amm_config_address(&program_id, 0);
+        let addresses = PoolAddresses::derive(&program_id, &amm_config, &token_0, &token_1);
+        let (pool_state, _) = Pubkey::find_program_address(
+            &[b"pool", amm_config.as_ref(), token_0.as_ref(), token_1.as_ref()],
+            &program_id,
+        );
+        assert_eq!(addresses.pool_state, pool_state);
+        assert_ne!(addresses.token_0_vault, addresses.token_1_vault);
+    }
+
+    #[test]
+    fn reads_reserves_net_of_raydium_fees() {
+        let mut data = vec![0u8; POOL_STATE_LP_SUPPLY_OFFSET + 6 * 8];
+        for (index, value) in [1_000u64, 10, 20, 1, 2, 1_700_000_000].iter().enumerate() {
+            let start = POOL_STATE_LP_SUPPLY_OFFSET + index * 8;
+            data[start..start + 8].copy_from_slice(&value.to_le_bytes());
+        }
+        assert_eq!(pool_reserves(&data, 500, 900), Some((1_000, 489, 878)));
+        assert_eq!(pool_reserves(&data, 10, 900), None);
+        assert_eq!(pool_reserves(&data[..POOL_STATE_LP_SUPPLY_OFFSET + 8], 500, 900), None);
+    }
+
+    #[test]
+    fn fee_growth_leaves_the_principal_locked() {
+        // Fresh pool: sqrt(k) = 1_000 LP, 900 of them locked
+        assert_eq!(fee_growth_lp(900, 900, 1_000, 1_000, 1_000), Some(0));
+
+        // Fees grew sqrt(k) by 10%: the principal is now worth 819 LP (rounded up)
+        let fee_lp = fee_growth_lp(900, 900, 1_000, 1_100, 1_100).unwrap();
+        assert_eq!(fee_lp, 900 - 819);
+
+        // After withdrawing them the remaining lock is all principal
+        let (lp_supply, reserve) = (1_000 - fee_lp, 1_100 - 1_100 * fee_lp / 1_000);
+        assert_eq!(fee_growth_lp(900 - fee_lp, 900, lp_supply, reserve, reserve), Some(0));
+    }
+}
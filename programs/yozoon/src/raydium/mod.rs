@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::dex::MigrationTargetKind;
 use crate::utils::constants::*;
 use std::str::FromStr;
 
+pub mod cpmm;
+
 /// Raydium Liquidity Pool state
 #[account]
 pub struct RaydiumPool {
@@ -15,17 +17,17 @@ pub struct RaydiumPool {
     /// Token B mint (SOL)
     pub token_b_mint: Pubkey,
     
-    /// Token A pool account
+    /// Token A pool vault
     pub token_a_account: Pubkey,
     
-    /// Token B pool account
+    /// Token B pool vault
     pub token_b_account: Pubkey,
     
-    /// LP token mint
+    /// LP token mint, or position NFT mint (default pubkey when the target locks the position itself)
     pub lp_mint: Pubkey,
     
-    /// Fee account (for fee distribution)
-    pub fee_account: Pubkey,
+    /// Pool state account on the target DEX
+    pub pool_state: Pubkey,
     
     /// Pool initialization timestamp
     pub init_timestamp: i64,
@@ -35,6 +37,34 @@ pub struct RaydiumPool {
     
     /// Whether the pool has been initialized
     pub is_initialized: bool,
+    
+    /// LP tokens received at pool creation and then burned or locked
+    pub lp_amount: u64,
+    
+    /// How the LP tokens were taken out of circulation
+    pub lp_lock_mode: LpLockMode,
+    
+    /// Lock vault holding the LP tokens (default pubkey when burned, the position when locked by the target)
+    pub lp_lock: Pubkey,
+    
+    /// Project tokens collected from the LP position into the fee vault, ever
+    pub total_fees_token: u64,
+    
+    /// Wrapped SOL collected from the LP position into the fee vault, ever
+    pub total_fees_sol: u64,
+    
+    /// DEX the pool lives on; fee claims only support Raydium CPMM
+    pub target: MigrationTargetKind,
+}
+
+/// What migration does with the LP tokens it receives
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LpLockMode {
+    /// Burn the LP tokens, reducing the LP supply
+    Burn,
+    /// Move the LP tokens into a program-owned vault; only `claim_pool_fees` withdraws
+    /// from it, and only the fee growth above the principal locked at migration
+    Lock,
 }
 
 impl RaydiumPool {
@@ -44,10 +74,16 @@ impl RaydiumPool {
                            32 + // token_a_account
                            32 + // token_b_account
                            32 + // lp_mint
-                           32 + // fee_account
+                           32 + // pool_state
                            8 +  // init_timestamp
                            1 +  // bump
-                           1;   // is_initialized
+                           1 +  // is_initialized
+                           8 +  // lp_amount
+                           1 +  // lp_lock_mode
+                           32 + // lp_lock
+                           8 +  // total_fees_token
+                           8 +  // total_fees_sol
+                           1;   // target
 }
 
 /// Raydium Fee Key NFT state
@@ -62,7 +98,7 @@ pub struct FeeKeyNft {
     /// Associated pool
     pub pool: Pubkey,
     
-    /// Fee percentage in basis points (e.g., 500 = 5%)
+    /// Share of the pool fees in basis points; the keys of a pool add up to `FEE_SHARE_TOTAL_BPS`
     pub fee_percentage: u64,
     
     /// Last claimed timestamp
@@ -70,6 +106,18 @@ pub struct FeeKeyNft {
     
     /// Bump for PDA derivation
     pub bump: u8,
+    
+    /// `RaydiumPool::total_fees_token` when this key was last settled
+    pub fees_checkpoint_token: u64,
+    
+    /// `RaydiumPool::total_fees_sol` when this key was last settled
+    pub fees_checkpoint_sol: u64,
+    
+    /// Project tokens owed from before the last share change
+    pub unclaimed_token: u64,
+    
+    /// Wrapped SOL owed from before the last share change
+    pub unclaimed_sol: u64,
 }
 
 impl FeeKeyNft {
@@ -78,119 +126,56 @@ impl FeeKeyNft {
                            32 + // pool
                            8 +  // fee_percentage
                            8 +  // last_claimed
-                           1;   // bump
-}
-
-/// Accounts required for creating a Raydium liquidity pool
-#[derive(Accounts)]
-pub struct CreateRaydiumPool<'info> {
-    /// Pool state account (PDA)
-    #[account(
-        init,
-        payer = payer,
-        space = 8 + RaydiumPool::LEN,
-        seeds = [seeds::RAYDIUM_POOL, token_a_mint.key().as_ref()],
-        bump
-    )]
-    pub pool: Account<'info, RaydiumPool>,
-    
-    /// Token A mint (project token)
-    pub token_a_mint: Account<'info, Mint>,
-    
-    /// Token B mint (SOL)
-    pub token_b_mint: Account<'info, Mint>,
-    
-    /// Token A pool account (will hold project tokens)
-    #[account(mut)]
-    pub token_a_account: Account<'info, TokenAccount>,
-    
-    /// Token B pool account (will hold SOL)
-    #[account(mut)]
-    pub token_b_account: Account<'info, TokenAccount>,
-    
-    /// LP token mint (to be created)
-    #[account(mut)]
-    pub lp_mint: Account<'info, Mint>,
-    
-    /// Fee account (for collecting fees)
-    #[account(mut)]
-    pub fee_account: Account<'info, TokenAccount>,
-    
-    /// Raydium AMM program
-    pub raydium_program: Program<'info, Raydium>,
-    
-    /// User paying for the transaction
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    
-    /// System program
-    pub system_program: Program<'info, System>,
-    
-    /// Token program
-    pub token_program: Program<'info, Token>,
-    
-    /// Rent sysvar
-    pub rent: Sysvar<'info, Rent>,
-}
-
-/// Accounts required for creating a Fee Key NFT
-#[derive(Accounts)]
-pub struct CreateFeeKeyNft<'info> {
-    /// Fee Key NFT state account (PDA)
-    #[account(
-        init,
-        payer = payer,
-        space = 8 + FeeKeyNft::LEN,
-        seeds = [seeds::NFT_FEE_KEY, pool.key().as_ref()],
-        bump
-    )]
-    pub fee_key_nft: Account<'info, FeeKeyNft>,
-    
-    /// NFT mint account
-    #[account(mut)]
-    pub nft_mint: Account<'info, Mint>,
-    
-    /// Associated pool
-    pub pool: Account<'info, RaydiumPool>,
-    
-    /// Owner of the NFT (usually the admin)
-    #[account(mut)]
-    pub owner: SystemAccount<'info>,
-    
-    /// User paying for the transaction
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    
-    /// Raydium Fee Key program
-    pub fee_key_program: Program<'info, FeeKeyProgram>,
-    
-    /// System program
-    pub system_program: Program<'info, System>,
-    
-    /// Token program
-    pub token_program: Program<'info, Token>,
+                           1 +  // bump
+                           8 +  // fees_checkpoint_token
+                           8 +  // fees_checkpoint_sol
+                           8 +  // unclaimed_token
+                           8;   // unclaimed_sol
+    
+    /// Move this key's share of the fees collected since its checkpoint into `unclaimed_*`
+    pub fn settle(&mut self, total_fees_token: u64, total_fees_sol: u64) -> Option<()> {
+        let share = |total: u64, checkpoint: u64| {
+            let earned = (total.checked_sub(checkpoint)? as u128) * self.fee_percentage as u128
+                / FEE_SHARE_TOTAL_BPS as u128;
+            u64::try_from(earned).ok()
+        };
+        self.unclaimed_token = self
+            .unclaimed_token
+            .checked_add(share(total_fees_token, self.fees_checkpoint_token)?)?;
+        self.unclaimed_sol = self
+            .unclaimed_sol
+            .checked_add(share(total_fees_sol, self.fees_checkpoint_sol)?)?;
+        self.fees_checkpoint_token = total_fees_token;
+        self.fees_checkpoint_sol = total_fees_sol;
+        Some(())
+    }
     
-    /// Rent sysvar
-    pub rent: Sysvar<'info, Rent>,
+    /// Take everything owed, returning (tokens, lamports); call `settle` first
+    pub fn take_unclaimed(&mut self) -> (u64, u64) {
+        let owed = (self.unclaimed_token, self.unclaimed_sol);
+        self.unclaimed_token = 0;
+        self.unclaimed_sol = 0;
+        owed
+    }
 }
 
-/// Raydium program (just for type-checking)
+/// Raydium CPMM program (just for type-checking)
 #[derive(Clone)]
 pub struct Raydium;
 
 impl anchor_lang::Id for Raydium {
     fn id() -> Pubkey {
-        Pubkey::from_str(RAYDIUM_AMM_PROGRAM_ID).unwrap()
+        Pubkey::from_str(RAYDIUM_CPMM_PROGRAM_ID).unwrap()
     }
 }
 
-/// Fee Key program (just for type-checking)
+/// SPL Memo program, passed to CPMM `withdraw` (just for type-checking)
 #[derive(Clone)]
-pub struct FeeKeyProgram;
+pub struct Memo;
 
-impl anchor_lang::Id for FeeKeyProgram {
+impl anchor_lang::Id for Memo {
     fn id() -> Pubkey {
-        Pubkey::from_str(RAYDIUM_FEE_KEY_PROGRAM_ID).unwrap()
+        Pubkey::from_str(cpmm::MEMO_PROGRAM_ID).unwrap()
     }
 }
 
@@ -202,50 +187,81 @@ pub fn find_raydium_pool_pda(token_mint: &Pubkey) -> (Pubkey, u8) {
     )
 }
 
-/// Helper function to find the Fee Key NFT PDA
-pub fn find_fee_key_nft_pda(pool: &Pubkey) -> (Pubkey, u8) {
+/// Helper function to find the LP lock vault PDA
+pub fn find_lp_lock_pda(pool: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(
-        &[seeds::NFT_FEE_KEY, pool.as_ref()],
+        &[seeds::LP_LOCK, pool.as_ref()],
         &crate::ID
     )
 }
 
-/// Helper function to create Raydium pool instruction data
-pub fn create_pool_instruction_data(
-    token_a_amount: u64,
-    token_b_amount: u64,
-    fee_rate: u64,
-    lock_period: u64
-) -> Vec<u8> {
-    let mut data = Vec::new();
-    
-    // Instruction index for create_pool
-    data.push(1);
-    
-    // Token amounts
-    data.extend_from_slice(&token_a_amount.to_le_bytes());
-    data.extend_from_slice(&token_b_amount.to_le_bytes());
-    
-    // Fee rate
-    data.extend_from_slice(&fee_rate.to_le_bytes());
-    
-    // Lock period
-    data.extend_from_slice(&lock_period.to_le_bytes());
-    
-    data
+/// Helper function to find the Fee Key NFT PDA for one of a pool's NFTs
+pub fn find_fee_key_nft_pda(pool: &Pubkey, nft_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::NFT_FEE_KEY, pool.as_ref(), nft_mint.as_ref()],
+        &crate::ID
+    )
 }
 
-/// Helper function to create NFT fee key instruction data
-pub fn create_nft_fee_key_instruction_data(
-    fee_percentage: u64
-) -> Vec<u8> {
-    let mut data = Vec::new();
-    
-    // Instruction index for create_nft_fee_key
-    data.push(1);
-    
-    // Fee percentage
-    data.extend_from_slice(&fee_percentage.to_le_bytes());
-    
-    data
-} 
\ No newline at end of file
+/// Helper function to find a pool's fee vault PDA for `mint`
+pub fn find_fee_vault_pda(pool: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seeds::FEE_VAULT, pool.as_ref(), mint.as_ref()],
+        &crate::ID
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fee_key(fee_percentage: u64) -> FeeKeyNft {
+        FeeKeyNft {
+            mint: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            pool: Pubkey::new_unique(),
+            fee_percentage,
+            last_claimed: 0,
+            bump: 255,
+            fees_checkpoint_token: 0,
+            fees_checkpoint_sol: 0,
+            unclaimed_token: 0,
+            unclaimed_sol: 0,
+        }
+    }
+
+    #[test]
+    fn settles_pro_rata_share_since_checkpoint() {
+        let (mut team, mut community) = (fee_key(7_000), fee_key(3_000));
+        team.settle(1_000, 10_000).unwrap();
+        community.settle(1_000, 10_000).unwrap();
+        assert_eq!(team.take_unclaimed(), (700, 7_000));
+        assert_eq!(community.take_unclaimed(), (300, 3_000));
+
+        // Only fees collected after the checkpoint count on the next claim
+        team.settle(1_500, 10_000).unwrap();
+        assert_eq!(team.take_unclaimed(), (350, 0));
+        assert_eq!(team.take_unclaimed(), (0, 0));
+    }
+
+    #[test]
+    fn split_keeps_earned_fees_with_the_parent() {
+        let mut parent = fee_key(FEE_SHARE_TOTAL_BPS);
+        parent.settle(1_000, 0).unwrap();
+        parent.fee_percentage -= 2_500;
+        let mut child = fee_key(2_500);
+        child.fees_checkpoint_token = 1_000;
+
+        parent.settle(2_000, 0).unwrap();
+        child.settle(2_000, 0).unwrap();
+        assert_eq!(parent.take_unclaimed(), (1_000 + 750, 0));
+        assert_eq!(child.take_unclaimed(), (250, 0));
+    }
+
+    #[test]
+    fn settle_rejects_totals_below_checkpoint() {
+        let mut key = fee_key(FEE_SHARE_TOTAL_BPS);
+        key.settle(1_000, 1_000).unwrap();
+        assert!(key.settle(999, 1_000).is_none());
+    }
+}
@@ -0,0 +1,391 @@
+//! Original (v0) account layouts, kept so deployed accounts can be upgraded in place
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::utils::constants::{pause_flags, DEFAULT_DECIMALS, DEFAULT_MAX_SUPPLY};
+
+/// v0 `Config` layout (no version byte)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigV0 {
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub bump: u8,
+    pub paused: bool,
+    pub treasury: Pubkey,
+    pub pending_admin: Option<Pubkey>,
+    pub total_value: u64,
+    pub total_supply: u64,
+}
+
+impl ConfigV0 {
+    pub const LEN: usize = 32 + 32 + 1 + 1 + 32 + 1 + 32 + 8 + 8;
+}
+
+/// v0 `BondingCurve` layout (no version byte)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BondingCurveV0 {
+    pub total_sol_raised: u64,
+    pub total_sold_supply: u64,
+    pub price_points: Vec<u64>,
+    pub bump: u8,
+    pub is_migrated: bool,
+}
+
+impl BondingCurveV0 {
+    pub const LEN: usize = 8 + 8 + 4 + (32 * 10) + 1 + 1;
+}
+
+/// v0 `Referral` layout (no version byte)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ReferralV0 {
+    pub referrer: Pubkey,
+    pub fee_percentage: u64,
+    pub bump: u8,
+}
+
+impl ReferralV0 {
+    pub const LEN: usize = 32 + 8 + 1;
+}
+
+/// v0 `AirdropLedger` layout (no version byte)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AirdropLedgerV0 {
+    pub total_airdropped: u64,
+    pub bump: u8,
+}
+
+impl AirdropLedgerV0 {
+    pub const LEN: usize = 8 + 1;
+}
+
+impl VersionedAccount for Config {
+    const VERSION: u8 = Config::VERSION;
+    const CURRENT_LEN: usize = Config::LEN;
+    const V0_LEN: usize = ConfigV0::LEN;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn from_v0(mut data: &[u8]) -> Result<Self> {
+        let old = ConfigV0::deserialize(&mut data)?;
+        Ok(Config {
+            version: 0,
+            admin: old.admin,
+            mint: old.mint,
+            bump: old.bump,
+            // A v0 pause stopped everything
+            pause_flags: if old.paused { pause_flags::ALL } else { 0 },
+            treasury: old.treasury,
+            // v0 nominations have no deadline, so they are dropped and must be re-issued
+            pending_admin: None,
+            pending_admin_deadline: 0,
+            total_value: old.total_value,
+            total_supply: old.total_supply,
+            frozen_params: 0,
+            migration_bounty: 0,
+            oracle_feeds: Config::default_oracle_feeds(),
+            decimals: DEFAULT_DECIMALS,
+            max_supply: DEFAULT_MAX_SUPPLY,
+            reserved: [0; 15],
+        })
+    }
+    
+    fn fill_new_fields(&mut self, from_version: u8) {
+        if from_version < 3 {
+            self.oracle_feeds = Config::default_oracle_feeds();
+        }
+        if from_version < 4 {
+            self.decimals = DEFAULT_DECIMALS;
+            self.max_supply = DEFAULT_MAX_SUPPLY;
+        }
+    }
+}
+
+impl VersionedAccount for BondingCurve {
+    const VERSION: u8 = BondingCurve::VERSION;
+    const CURRENT_LEN: usize = BondingCurve::LEN;
+    const V0_LEN: usize = BondingCurveV0::LEN;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn from_v0(mut data: &[u8]) -> Result<Self> {
+        let old = BondingCurveV0::deserialize(&mut data)?;
+        let mut curve = BondingCurve {
+            version: 0,
+            total_sol_raised: old.total_sol_raised,
+            total_sold_supply: old.total_sold_supply,
+            price_points: old.price_points,
+            bump: old.bump,
+            is_migrated: old.is_migrated,
+            thresholds: MigrationThresholds::DEFAULT,
+            state: CurveState::Trading,
+            migration_target: MigrationTargetKind::RaydiumCpmm,
+            launch_deadline: 0,
+            ready_since: 0,
+            reserved: [0; 14],
+        };
+        curve.state = derived_curve_state(&curve);
+        Ok(curve)
+    }
+    
+    fn fill_new_fields(&mut self, from_version: u8) {
+        // v2 curves launched without a USD threshold and keep `usd_min = 0`
+        if from_version < 2 {
+            self.thresholds = MigrationThresholds::DEFAULT;
+        }
+        if from_version < 4 {
+            self.state = derived_curve_state(self);
+        }
+    }
+}
+
+/// State of a curve written before `state` was stored, recovered from its other fields
+fn derived_curve_state(curve: &BondingCurve) -> CurveState {
+    if curve.is_migrated {
+        CurveState::Migrated
+    } else if curve.is_migration_ready() {
+        CurveState::ReadyToMigrate
+    } else {
+        CurveState::Trading
+    }
+}
+
+impl VersionedAccount for Referral {
+    const VERSION: u8 = Referral::VERSION;
+    const CURRENT_LEN: usize = Referral::LEN;
+    const V0_LEN: usize = ReferralV0::LEN;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn from_v0(mut data: &[u8]) -> Result<Self> {
+        let old = ReferralV0::deserialize(&mut data)?;
+        Ok(Referral {
+            version: 0,
+            referrer: old.referrer,
+            fee_percentage: old.fee_percentage,
+            bump: old.bump,
+            // v0 referrals predate launches; the mint is set when they are upgraded
+            mint: Pubkey::default(),
+        })
+    }
+}
+
+impl VersionedAccount for AirdropLedger {
+    const VERSION: u8 = AirdropLedger::VERSION;
+    const CURRENT_LEN: usize = AirdropLedger::LEN;
+    const V0_LEN: usize = AirdropLedgerV0::LEN;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn from_v0(mut data: &[u8]) -> Result<Self> {
+        let old = AirdropLedgerV0::deserialize(&mut data)?;
+        Ok(AirdropLedger {
+            version: 0,
+            total_airdropped: old.total_airdropped,
+            bump: old.bump,
+            reserved: [0; 32],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    /// Lay out v0 account data the way the old program allocated it
+    fn v0_account_data<T: AnchorSerialize>(discriminator: [u8; 8], account: &T, len: usize) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        data.extend(account.try_to_vec().unwrap());
+        data.resize(8 + len, 0);
+        data
+    }
+
+    #[test]
+    fn upgrades_v0_config_to_current_layout() {
+        let old = ConfigV0 {
+            admin: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            bump: 254,
+            paused: true,
+            treasury: Pubkey::new_unique(),
+            pending_admin: Some(Pubkey::new_unique()),
+            total_value: 42,
+            total_supply: 7,
+        };
+        let data = v0_account_data(Config::discriminator(), &old, ConfigV0::LEN);
+
+        let (mut config, from_version) = load_any_layout::<Config>(&data).unwrap();
+        assert_eq!(from_version, 0);
+        assert_eq!(config.admin, old.admin);
+        assert_eq!(config.mint, old.mint);
+        assert_eq!(config.bump, old.bump);
+        assert_eq!(config.pause_flags, pause_flags::ALL);
+        assert_eq!(config.treasury, old.treasury);
+        assert_eq!(config.pending_admin, None);
+        assert_eq!(config.total_value, old.total_value);
+        assert_eq!(config.total_supply, old.total_supply);
+        assert_eq!(config.oracle_feeds, Config::default_oracle_feeds());
+        assert_eq!(config.decimals, DEFAULT_DECIMALS);
+        assert_eq!(config.max_supply, DEFAULT_MAX_SUPPLY);
+
+        // Write it back in the current layout and read it again
+        config.set_version(Config::VERSION);
+        let mut upgraded = vec![0u8; 8 + Config::LEN];
+        config.try_serialize(&mut &mut upgraded[..]).unwrap();
+        let (reloaded, version) = load_any_layout::<Config>(&upgraded).unwrap();
+        assert_eq!(version, Config::VERSION);
+        assert_eq!(reloaded.admin, old.admin);
+        assert_eq!(reloaded.treasury, old.treasury);
+    }
+
+    #[test]
+    fn upgrades_v0_bonding_curve_to_current_layout() {
+        let old = BondingCurveV0 {
+            total_sol_raised: 1_000,
+            total_sold_supply: 2_000,
+            price_points: vec![1, 2, 3],
+            bump: 255,
+            is_migrated: false,
+        };
+        let data = v0_account_data(BondingCurve::discriminator(), &old, BondingCurveV0::LEN);
+
+        let (curve, from_version) = load_any_layout::<BondingCurve>(&data).unwrap();
+        assert_eq!(from_version, 0);
+        assert_eq!(curve.total_sol_raised, old.total_sol_raised);
+        assert_eq!(curve.total_sold_supply, old.total_sold_supply);
+        assert_eq!(curve.price_points, old.price_points);
+        assert_eq!(curve.bump, old.bump);
+        assert_eq!(curve.thresholds, MigrationThresholds::DEFAULT);
+    }
+
+    #[test]
+    fn upgrades_v1_bonding_curve_with_default_thresholds() {
+        // v1 layout: the fields up to `is_migrated`, then reserved padding, allocated
+        // with the v1 `LEN` (room for 40 price points)
+        let mut data = BondingCurve::discriminator().to_vec();
+        data.push(1);
+        data.extend(3_000u64.to_le_bytes());
+        data.extend(4_000u64.to_le_bytes());
+        data.extend(vec![5u64, 6].try_to_vec().unwrap());
+        data.push(253);
+        data.push(0);
+        data.resize(8 + 1 + 8 + 8 + 4 + 32 * 10 + 1 + 1 + 64, 0);
+
+        // Read directly, the carved-out fields are zero but the accessors fall back
+        let raw = BondingCurve::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(raw.thresholds(), MigrationThresholds::DEFAULT);
+
+        let (curve, from_version) = load_any_layout::<BondingCurve>(&data).unwrap();
+        assert_eq!(from_version, 1);
+        assert_eq!(curve.total_sol_raised, 3_000);
+        assert_eq!(curve.price_points, vec![5, 6]);
+        assert_eq!(curve.bump, 253);
+        assert_eq!(curve.thresholds, MigrationThresholds::DEFAULT);
+        assert_eq!(curve.state, CurveState::Trading);
+    }
+
+    #[test]
+    fn upgrades_v3_bonding_curve_past_thresholds_as_ready_to_migrate() {
+        // v3 layout: `state`, `migration_target` and `launch_deadline` still reserved padding
+        let thresholds = MigrationThresholds {
+            sol_min: 100,
+            sol_max: 200,
+            supply_threshold: 10,
+            usd_min: 0,
+        };
+        let mut data = BondingCurve::discriminator().to_vec();
+        data.push(3);
+        data.extend(150u64.to_le_bytes());
+        data.extend(20u64.to_le_bytes());
+        data.extend(vec![5u64, 6].try_to_vec().unwrap());
+        data.push(252);
+        data.push(0);
+        data.extend(thresholds.try_to_vec().unwrap());
+        data.resize(8 + BondingCurve::LEN, 0);
+
+        let (curve, from_version) = load_any_layout::<BondingCurve>(&data).unwrap();
+        assert_eq!(from_version, 3);
+        assert_eq!(curve.thresholds, thresholds);
+        assert_eq!(curve.state, CurveState::ReadyToMigrate);
+        assert_eq!(curve.migration_target, MigrationTargetKind::RaydiumCpmm);
+        assert_eq!(curve.launch_deadline, 0);
+    }
+
+    #[test]
+    fn upgrades_v1_config_with_zeroed_new_fields() {
+        // A v1 account has the current size, with the fields added since still zeroed padding
+        assert_eq!(Config::LEN, 1 + 32 + 32 + 1 + 1 + 32 + 1 + 32 + 8 + 8 + 8 + 1 + 128);
+        let mut data = vec![0u8; 8 + Config::LEN];
+        data[..8].copy_from_slice(&Config::discriminator());
+        data[8] = 1;
+        let admin = Pubkey::new_unique();
+        data[9..41].copy_from_slice(admin.as_ref());
+
+        // Read directly, the accessors supply the defaults of the zeroed fields
+        let raw = Config::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(raw.max_supply(), DEFAULT_MAX_SUPPLY);
+        assert_eq!(raw.token_unit(), 1_000_000_000);
+        assert!(raw.is_oracle_feed_allowed(&Config::default_oracle_feeds()[0]));
+
+        let (config, from_version) = load_any_layout::<Config>(&data).unwrap();
+        assert_eq!(from_version, 1);
+        assert_eq!(config.admin, admin);
+        assert_eq!(config.migration_bounty, 0);
+        assert_eq!(config.oracle_feeds, Config::default_oracle_feeds());
+        assert!(config.is_oracle_feed_allowed(&Config::default_oracle_feeds()[0]));
+        assert!(!config.is_oracle_feed_allowed(&Pubkey::default()));
+        assert_eq!(config.token_unit(), 1_000_000_000);
+        assert_eq!(config.max_supply, DEFAULT_MAX_SUPPLY);
+    }
+
+    #[test]
+    fn upgrades_v1_referral_in_place() {
+        // The mint took over the v1 reserved bytes, so the account keeps its size
+        assert_eq!(Referral::LEN, 1 + 32 + 8 + 1 + 32);
+        let referrer = Pubkey::new_unique();
+        let mut data = Referral::discriminator().to_vec();
+        data.push(1);
+        data.extend(referrer.as_ref());
+        data.extend(150u64.to_le_bytes());
+        data.push(251);
+        data.resize(8 + Referral::LEN, 0);
+
+        let (referral, from_version) = load_any_layout::<Referral>(&data).unwrap();
+        assert_eq!(from_version, 1);
+        assert_eq!(referral.referrer, referrer);
+        assert_eq!(referral.fee_percentage, 150);
+        assert_eq!(referral.bump, 251);
+        assert_eq!(referral.mint, Pubkey::default());
+    }
+
+    #[test]
+    fn rejects_unknown_future_version() {
+        let mut config_data = vec![0u8; 8 + Config::LEN];
+        config_data[..8].copy_from_slice(&Config::discriminator());
+        config_data[8] = Config::VERSION + 1;
+        assert!(load_any_layout::<Config>(&config_data).is_err());
+    }
+}
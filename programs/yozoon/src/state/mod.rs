@@ -1,8 +1,80 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::dex::MigrationTargetKind;
+use crate::errors::YozoonError;
+use std::str::FromStr;
+use crate::utils::constants::{
+    freeze_flags, pause_flags, ADMIN_TRANSFER_EXPIRY, DEFAULT_DECIMALS, DEFAULT_MAX_SUPPLY, MAX_LAUNCH_CREATORS, MAX_ORACLE_FEEDS, MAX_PRICE_POINTS,
+    MIGRATION_REFUND_TIMEOUT, OBSERVATION_CAPACITY, OBSERVATION_INTERVAL, PYTH_SOL_USD_PRICE_ACCOUNT,
+};
+
+pub mod legacy;
+
+/// Accounts with a versioned layout that `migrate_account_layout` can upgrade in place.
+///
+/// New fields are carved out of the front of `reserved`, so the serialized size never
+/// changes and an older layout decodes into the current struct with the new fields
+/// zeroed. Fields whose zero is not a sensible default are read through accessors
+/// that fall back to the default for older versions.
+pub trait VersionedAccount: AccountSerialize + AccountDeserialize + Discriminator + Sized {
+    /// Layout version written by this program
+    const VERSION: u8;
+    
+    /// Size of the current layout (without discriminator)
+    const CURRENT_LEN: usize;
+    
+    /// Size of the original unversioned layout (without discriminator)
+    const V0_LEN: usize;
+    
+    /// Layout version stored in the account
+    fn version(&self) -> u8;
+    
+    /// Set the stored layout version
+    fn set_version(&mut self, version: u8);
+    
+    /// Decode the unversioned v0 layout (without discriminator) into the current layout
+    fn from_v0(data: &[u8]) -> Result<Self>;
+    
+    /// Fill fields added after `from_version` whose zero value is not a sensible default
+    fn fill_new_fields(&mut self, _from_version: u8) {}
+}
+
+/// Decode account data (with discriminator) in any known layout into the current layout
+///
+/// Returns the decoded account and the layout version it was stored in
+pub fn load_any_layout<T: VersionedAccount>(data: &[u8]) -> Result<(T, u8)> {
+    require!(
+        data.len() >= 8 && data[..8] == T::discriminator(),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    
+    // v0 has no version byte, so it is identified by its allocated size
+    if data.len() == 8 + T::V0_LEN {
+        return Ok((T::from_v0(&data[8..])?, 0));
+    }
+    
+    // Zero-pad accounts allocated before `LEN` grew (curves sized for fewer price points)
+    let mut buf = vec![0u8; data.len().max(8 + T::CURRENT_LEN)];
+    buf[..data.len()].copy_from_slice(data);
+    let mut account = T::try_deserialize(&mut &buf[..])?;
+    
+    let version = account.version();
+    require!(
+        version >= 1 && version <= T::VERSION,
+        YozoonError::UnsupportedAccountVersion
+    );
+    if version < T::VERSION {
+        account.fill_new_fields(version);
+    }
+    Ok((account, version))
+}
 
 /// Configuration account holding admin info and program settings
 #[account]
 pub struct Config {
+    /// Layout version
+    pub version: u8,
+    
     /// Admin public key for restricted actions
     pub admin: Pubkey,
     
@@ -12,8 +84,8 @@ pub struct Config {
     /// Bump seed for PDA
     pub bump: u8,
     
-    /// Emergency pause flag
-    pub paused: bool,
+    /// Emergency pause bitmask (see `constants::pause_flags`)
+    pub pause_flags: u8,
     
     /// Treasury account for project fees
     pub treasury: Pubkey,
@@ -21,28 +93,254 @@ pub struct Config {
     /// Pending admin for ownership transfer
     pub pending_admin: Option<Pubkey>,
     
+    /// Unix timestamp after which the pending admin can no longer accept
+    pub pending_admin_deadline: i64,
+    
     /// Total value of the project
     pub total_value: u64,
     
     /// Total supply of the project
     pub total_supply: u64,
+    
+    /// Permanently frozen settings bitmask (see `constants::freeze_flags`)
+    pub frozen_params: u8,
+    
+    /// Lamports paid from the reserve to the account that runs the migration (v2)
+    pub migration_bounty: u64,
+    
+    /// Pyth price accounts the program reads SOL/USD from; unused slots are the default key
+    /// (v3, read through `is_oracle_feed_allowed`)
+    pub oracle_feeds: [Pubkey; MAX_ORACLE_FEEDS],
+    
+    /// Decimals of the project mint (v4, read through `token_unit`)
+    pub decimals: u8,
+    
+    /// Most base units the curve, airdrops and migration may ever mint (v4, read through `max_supply()`)
+    pub max_supply: u64,
+    
+    /// Reserved for future fields
+    pub reserved: [u8; 15],
 }
 
 impl Config {
-    pub const LEN: usize = 32 + // admin
+    pub const VERSION: u8 = 4;
+    
+    pub const LEN: usize = 1 + // version
+                            32 + // admin
                             32 + // mint
                             1 + // bump
-                            1 + // paused
+                            1 + // pause_flags
                             32 + // treasury
                             1 + // option tag
                             32 + // pending_admin
+                            8 + // pending_admin_deadline
                             8 + // total_value
-                            8; // total_supply
+                            8 + // total_supply
+                            1 + // frozen_params
+                            8 + // migration_bounty
+                            32 * MAX_ORACLE_FEEDS + // oracle_feeds
+                            1 + // decimals
+                            8 + // max_supply
+                            15; // reserved (128 at v1, less the fields carved out since)
+    
+    /// Allowlist of a new config: the mainnet Pyth SOL/USD account
+    pub fn default_oracle_feeds() -> [Pubkey; MAX_ORACLE_FEEDS] {
+        let mut feeds = [Pubkey::default(); MAX_ORACLE_FEEDS];
+        feeds[0] = Pubkey::from_str(PYTH_SOL_USD_PRICE_ACCOUNT).unwrap();
+        feeds
+    }
+
+    /// Seeds the config PDA signs with, keyed by the launch's mint
+    pub fn signer_seeds(&self) -> [&[u8]; 3] {
+        [crate::utils::constants::seeds::CONFIG, self.mint.as_ref(), std::slice::from_ref(&self.bump)]
+    }
+    
+    /// Most base units the launch may ever mint
+    pub fn max_supply(&self) -> u64 {
+        if self.version < 4 {
+            DEFAULT_MAX_SUPPLY
+        } else {
+            self.max_supply
+        }
+    }
+    
+    /// Base units in one whole token (10^decimals), the unit curve prices are quoted per
+    pub fn token_unit(&self) -> u64 {
+        let decimals = if self.version < 4 { DEFAULT_DECIMALS } else { self.decimals };
+        10u64.pow(u32::from(decimals))
+    }
+    
+    /// Returns true if the operation(s) in `flag` are paused
+    pub fn is_paused(&self, flag: u8) -> bool {
+        self.pause_flags & flag != 0
+    }
+    
+    /// Returns true if the setting(s) in `flag` are frozen
+    pub fn is_frozen(&self, flag: u8) -> bool {
+        self.frozen_params & flag != 0
+    }
+    
+    /// Returns true if `feed` is on the oracle allowlist
+    pub fn is_oracle_feed_allowed(&self, feed: &Pubkey) -> bool {
+        let feeds = if self.version < 3 { Self::default_oracle_feeds() } else { self.oracle_feeds };
+        *feed != Pubkey::default() && feeds.contains(feed)
+    }
+    
+    /// Point the protocol's SOL at `new_treasury`, returning the old treasury
+    pub fn set_treasury(&mut self, new_treasury: Pubkey) -> Result<Pubkey> {
+        require!(!self.is_frozen(freeze_flags::TREASURY), YozoonError::ParametersFrozen);
+        
+        // Reject the default key and no-op updates
+        require!(new_treasury != Pubkey::default(), YozoonError::InvalidParameter);
+        require!(self.treasury != new_treasury, YozoonError::InvalidParameter);
+        
+        Ok(std::mem::replace(&mut self.treasury, new_treasury))
+    }
+    
+    /// Replace the pause flags with `pause_mask`; returns false if they were already set so
+    pub fn set_pause_flags(&mut self, pause_mask: u8) -> Result<bool> {
+        // Reject unknown bits
+        require!(pause_mask & !pause_flags::ALL == 0, YozoonError::InvalidParameter);
+        
+        let changed = self.pause_flags != pause_mask;
+        self.pause_flags = pause_mask;
+        Ok(changed)
+    }
+    
+    /// Add `freeze_mask` to the frozen settings for good, returning the bits it newly froze
+    pub fn freeze(&mut self, freeze_mask: u8) -> Result<u8> {
+        // Reject empty and unknown masks
+        require!(freeze_mask != 0, YozoonError::InvalidParameter);
+        require!(freeze_mask & !freeze_flags::ALL == 0, YozoonError::InvalidParameter);
+        
+        let newly_frozen = freeze_mask & !self.frozen_params;
+        self.frozen_params |= freeze_mask;
+        Ok(newly_frozen)
+    }
+    
+    /// Clear the admin and any pending transfer; no key can sign as the default admin
+    ///
+    /// Nobody could unpause afterwards, so every operation must be unpaused first.
+    pub fn renounce_admin(&mut self) -> Result<()> {
+        require!(self.pause_flags == 0, YozoonError::PausedOnRenounce);
+        
+        self.admin = Pubkey::default();
+        self.pending_admin = None;
+        self.pending_admin_deadline = 0;
+        Ok(())
+    }
+    
+    /// Nominate `new_admin`, replacing any earlier nomination; returns when it expires
+    pub fn nominate_admin(&mut self, new_admin: Pubkey, now: i64) -> Result<i64> {
+        // Renouncing goes through `renounce_admin`
+        require!(self.admin != new_admin, YozoonError::InvalidParameter);
+        require!(new_admin != Pubkey::default(), YozoonError::InvalidParameter);
+        
+        let expires_at = now.checked_add(ADMIN_TRANSFER_EXPIRY).ok_or(YozoonError::MathOverflow)?;
+        self.pending_admin = Some(new_admin);
+        self.pending_admin_deadline = expires_at;
+        Ok(expires_at)
+    }
+    
+    /// Withdraw the pending nomination, returning who was nominated
+    pub fn cancel_admin_nomination(&mut self) -> Result<Pubkey> {
+        let cancelled_admin = self.pending_admin.ok_or(YozoonError::NoPendingAdmin)?;
+        self.pending_admin = None;
+        self.pending_admin_deadline = 0;
+        Ok(cancelled_admin)
+    }
+    
+    /// Make the nominee admin if accepted by `now`, returning the previous admin
+    pub fn accept_admin(&mut self, now: i64) -> Result<Pubkey> {
+        let new_admin = self.pending_admin.ok_or(YozoonError::NoPendingAdmin)?;
+        require!(now <= self.pending_admin_deadline, YozoonError::AdminTransferExpired);
+        
+        self.pending_admin = None;
+        self.pending_admin_deadline = 0;
+        Ok(std::mem::replace(&mut self.admin, new_admin))
+    }
+}
+
+/// Global launchpad account: who may create launches and what it costs
+#[account]
+pub struct Launchpad {
+    /// Layout version
+    pub version: u8,
+    
+    /// May change the fee and the creator allowlist
+    pub authority: Pubkey,
+    
+    /// Receives the creation fees
+    pub fee_recipient: Pubkey,
+    
+    /// Lamports paid to `fee_recipient` by each `initialize_mint`
+    pub creation_fee: u64,
+    
+    /// Accounts besides `authority` allowed to create launches; unused slots are the default key
+    pub creators: [Pubkey; MAX_LAUNCH_CREATORS],
+    
+    /// Number of launches created so far
+    pub launch_count: u64,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+    
+    /// Mint of the launch re-keyed from the singleton accounts of before the launchpad
+    /// (v2; default until `rekey_legacy_launch` runs)
+    pub legacy_mint: Pubkey,
+    
+    /// Nominee who may accept the authority role (v3; default when none is pending)
+    pub pending_authority: Pubkey,
+}
+
+impl Launchpad {
+    pub const VERSION: u8 = 3;
+    
+    pub const LEN: usize = 1 + // version
+                            32 + // authority
+                            32 + // fee_recipient
+                            8 + // creation_fee
+                            32 * MAX_LAUNCH_CREATORS + // creators
+                            8 + // launch_count
+                            1 + // bump
+                            32 + // legacy_mint
+                            32; // pending_authority (the last of the 64 bytes reserved at v1)
+    
+    /// Returns true if `creator` may create a launch
+    pub fn is_creator_allowed(&self, creator: &Pubkey) -> bool {
+        *creator == self.authority
+            || (*creator != Pubkey::default() && self.creators.contains(creator))
+    }
+    
+    /// Nominate `new_authority`, replacing any earlier nomination
+    pub fn nominate_authority(&mut self, new_authority: Pubkey) -> Result<()> {
+        require!(self.authority != new_authority, YozoonError::InvalidParameter);
+        require!(new_authority != Pubkey::default(), YozoonError::InvalidParameter);
+        
+        self.pending_authority = new_authority;
+        Ok(())
+    }
+    
+    /// Withdraw the pending nomination, returning who was nominated
+    pub fn cancel_authority_nomination(&mut self) -> Result<Pubkey> {
+        require!(self.pending_authority != Pubkey::default(), YozoonError::NoPendingAuthority);
+        Ok(std::mem::take(&mut self.pending_authority))
+    }
+    
+    /// Make the nominee authority, returning the previous authority
+    pub fn accept_authority(&mut self) -> Result<Pubkey> {
+        require!(self.pending_authority != Pubkey::default(), YozoonError::NoPendingAuthority);
+        let new_authority = std::mem::take(&mut self.pending_authority);
+        Ok(std::mem::replace(&mut self.authority, new_authority))
+    }
 }
 
 /// Bonding curve state account storing price points and supply data
 #[account]
 pub struct BondingCurve {
+    /// Layout version
+    pub version: u8,
+    
     /// Total SOL collected (net of fees)
     pub total_sol_raised: u64,
     
@@ -57,20 +355,223 @@ pub struct BondingCurve {
     
     /// Migration status
     pub is_migrated: bool,
+    
+    /// Conditions `migrate` checks before migrating (v2, `usd_min` v3; read through `thresholds()`)
+    pub thresholds: MigrationThresholds,
+    
+    /// Lifecycle state; only `Trading` accepts buys and sells (v4)
+    pub state: CurveState,
+    
+    /// DEX `migrate` moves the liquidity to (v5; older curves target Raydium CPMM)
+    pub migration_target: MigrationTargetKind,
+    
+    /// Unix timestamp after which anyone can put an unmigrated curve into refund mode
+    /// (0 = no deadline) (v6)
+    pub launch_deadline: i64,
+    
+    /// Unix timestamp at which the curve became `ReadyToMigrate` (0 = not yet, or
+    /// before v7) (v7)
+    pub ready_since: i64,
+    
+    /// Reserved for future fields
+    pub reserved: [u8; 14],
 }
 
 impl BondingCurve {
-    pub const LEN: usize = 8 + // total_sold_supply
+    pub const VERSION: u8 = 7;
+    
+    pub const LEN: usize = 1 + // version
+                            8 + // total_sold_supply
                             8 + // total_sol_raised
                             4 + // vec length
-                            (32 * 10) + // price_points (max 10 points)
+                            8 * MAX_PRICE_POINTS + // price_points
                             1 + // bump
-                            1; // is_migrated
+                            1 + // is_migrated
+                            MigrationThresholds::LEN + // thresholds
+                            1 + // state
+                            1 + // migration_target
+                            8 + // launch_deadline
+                            8 + // ready_since
+                            14; // reserved (64 at v1, less the fields carved out since)
+    
+    /// Migration thresholds; curves older than v2 use the original compile-time ones
+    pub fn thresholds(&self) -> MigrationThresholds {
+        if self.version < 2 {
+            MigrationThresholds::DEFAULT
+        } else {
+            self.thresholds
+        }
+    }
+
+    /// True once both the SOL and the supply thresholds are reached, or once the
+    /// curve is full at `sol_max` (it can take no more buys, whatever was sold); the
+    /// buy that gets there moves the curve to `CurveState::ReadyToMigrate`
+    pub fn is_migration_ready(&self) -> bool {
+        let thresholds = self.thresholds();
+        self.total_sol_raised >= thresholds.sol_max
+            || (self.total_sol_raised >= thresholds.sol_min
+                && self.total_sold_supply >= thresholds.supply_threshold)
+    }
+    
+    /// Lamports (net of fees) the curve can still take before reaching `sol_max`
+    pub fn remaining_sol_capacity(&self) -> u64 {
+        self.thresholds().sol_max.saturating_sub(self.total_sol_raised)
+    }
+    
+    /// Tokens `migrate` will still mint as pool liquidity: the sold supply, until it has run
+    pub fn pending_liquidity(&self) -> u64 {
+        match self.state {
+            CurveState::Trading | CurveState::ReadyToMigrate => self.total_sold_supply,
+            CurveState::Migrated | CurveState::Refunding => 0,
+        }
+    }
+    
+    /// Fail unless minting `minted` more tokens, `sold` of them through the curve, keeps
+    /// the mint supply plus the pool liquidity still to come within `max_supply`
+    ///
+    /// Buys, airdrops and the migration's liquidity mint all draw on this one budget,
+    /// so whatever is minted first can never leave `migrate` short of room.
+    pub fn require_within_supply(&self, mint_supply: u64, minted: u64, sold: u64, max_supply: u64) -> Result<()> {
+        let total = [minted, self.pending_liquidity(), sold]
+            .into_iter()
+            .try_fold(mint_supply, u64::checked_add)
+            .ok_or(YozoonError::MathOverflow)?;
+        require!(total <= max_supply, YozoonError::SupplyExceeded);
+        Ok(())
+    }
+    
+    /// Fail unless the curve is open for buys and sells
+    pub fn require_trading(&self) -> Result<()> {
+        match self.state {
+            CurveState::Trading => Ok(()),
+            CurveState::ReadyToMigrate => err!(YozoonError::MigrationPending),
+            CurveState::Migrated => err!(YozoonError::Migrated),
+            CurveState::Refunding => err!(YozoonError::RefundModeActive),
+        }
+    }
+    
+    /// Fail unless refund mode may be entered at `now`: by anyone once the launch
+    /// deadline has passed or the curve has waited `MIGRATION_REFUND_TIMEOUT` to
+    /// migrate, or by the admin while a ready migration keeps failing
+    ///
+    /// Curves that became ready before v7 did not record when, and count as having
+    /// waited long enough.
+    pub fn require_refund_allowed(&self, now: i64, by_admin: bool) -> Result<()> {
+        match self.state {
+            CurveState::Trading | CurveState::ReadyToMigrate => {}
+            CurveState::Migrated => return err!(YozoonError::Migrated),
+            CurveState::Refunding => return err!(YozoonError::RefundModeActive),
+        }
+        let deadline_passed = self.launch_deadline != 0 && now >= self.launch_deadline;
+        let migration_stuck = self.state == CurveState::ReadyToMigrate
+            && (by_admin || now >= self.ready_since.saturating_add(MIGRATION_REFUND_TIMEOUT));
+        require!(deadline_passed || migration_stuck, YozoonError::RefundNotAvailable);
+        Ok(())
+    }
+}
+
+/// Lifecycle of a bonding curve
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CurveState {
+    /// Buys and sells go through the curve
+    Trading,
+    /// Migration thresholds reached; trading is frozen until `migrate` runs
+    ReadyToMigrate,
+    /// Liquidity has moved to the pool
+    Migrated,
+    /// The launch failed; holders burn tokens for a share of the reserve per token sold
+    Refunding,
+}
+
+/// Migration thresholds, set at `initialize_bonding_curve`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MigrationThresholds {
+    /// Minimum SOL raised (lamports, net of fees)
+    pub sol_min: u64,
+    
+    /// Maximum SOL raised (lamports); the buy that reaches it is only partially filled
+    pub sol_max: u64,
+    
+    /// Minimum sold supply (token base units)
+    pub supply_threshold: u64,
+    
+    /// Minimum USD value of the raised SOL at the Pyth SOL/USD price (`USD_DECIMALS`
+    /// decimals, 0 disables the check)
+    pub usd_min: u64,
+}
+
+impl MigrationThresholds {
+    pub const LEN: usize = 8 + // sol_min
+                            8 + // sol_max
+                            8 + // supply_threshold
+                            8; // usd_min
+    
+    /// Original compile-time thresholds, used for curves created before they were configurable
+    pub const DEFAULT: Self = Self {
+        sol_min: crate::utils::constants::DEFAULT_MIGRATION_SOL_MIN,
+        sol_max: crate::utils::constants::DEFAULT_MIGRATION_SOL_MAX,
+        supply_threshold: crate::utils::constants::DEFAULT_MIGRATION_SUPPLY_THRESHOLD,
+        usd_min: crate::utils::constants::DEFAULT_MIGRATION_USD_MIN,
+    };
+    
+    /// Check the window is non-empty and reachable for a launch of `max_supply`
+    pub fn validate(&self, max_supply: u64) -> Result<()> {
+        require!(
+            self.sol_min > 0
+                && self.sol_min <= self.sol_max
+                && self.supply_threshold <= max_supply,
+            YozoonError::InvalidParameter
+        );
+        Ok(())
+    }
+}
+
+/// Token-2022 extensions chosen at `initialize_mint`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MintExtensions {
+    /// Fee withheld from every transfer; the admin may withdraw what is withheld
+    pub transfer_fee: Option<TransferFeeParams>,
+    
+    /// Point the mint at its Metaplex metadata account
+    pub metadata_pointer: bool,
+    
+    /// New token accounts start frozen, so tokens only move once `migrate` opens transfers
+    pub non_transferable_until_migration: bool,
+}
+
+impl MintExtensions {
+    /// Check the extensions are valid for a mint under `token_program`
+    pub fn validate(&self, token_program: &Pubkey) -> Result<()> {
+        require!(
+            *self == Self::default() || *token_program == anchor_spl::token_2022::ID,
+            YozoonError::Token2022Required
+        );
+        if let Some(fee) = self.transfer_fee {
+            require!(
+                fee.basis_points <= crate::utils::constants::MAX_TRANSFER_FEE_BPS,
+                YozoonError::InvalidParameter
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Token-2022 transfer fee settings
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransferFeeParams {
+    /// Fee rate (basis points of the amount transferred)
+    pub basis_points: u16,
+    
+    /// Fee cap per transfer (token base units)
+    pub maximum_fee: u64,
 }
 
 /// Referral state account storing referrer info and fee percentage
 #[account]
 pub struct Referral {
+    /// Layout version
+    pub version: u8,
+    
     /// Referrer's public key
     pub referrer: Pubkey,
     
@@ -79,25 +580,501 @@ pub struct Referral {
     
     /// Bump seed for PDA
     pub bump: u8,
+    
+    /// Mint of the launch the referral belongs to (v2; default on older referrals
+    /// until they are upgraded)
+    pub mint: Pubkey,
 }
 
 impl Referral {
-    pub const LEN: usize = 32 + // referrer
+    pub const VERSION: u8 = 3;
+    
+    pub const LEN: usize = 1 + // version
+                            32 + // referrer
                             8 + // fee_percentage
-                            1; // bump
+                            1 + // bump
+                            32; // mint (the 32 bytes reserved at v1)
 }
 
 /// Airdrop ledger account tracking total tokens airdropped
 #[account]
 pub struct AirdropLedger {
+    /// Layout version
+    pub version: u8,
+    
     /// Total tokens airdropped
     pub total_airdropped: u64,
     
     /// Bump seed for PDA
     pub bump: u8,
+    
+    /// Reserved for future fields
+    pub reserved: [u8; 32],
 }
 
 impl AirdropLedger {
-    pub const LEN: usize = 8 + // total_airdropped
-                            1; // bump
+    pub const VERSION: u8 = 1;
+    
+    pub const LEN: usize = 1 + // version
+                            8 + // total_airdropped
+                            1 + // bump
+                            32; // reserved
+}
+
+/// Running price × time sum of the curve, with snapshots for TWAP lookups
+///
+/// Every trade first adds the price the curve held since the previous update,
+/// so a trade can only move the average for the time its price actually stood.
+/// Snapshots of the running sum are kept at least `OBSERVATION_INTERVAL` apart
+/// in a ring buffer, which bounds the longest window that can be averaged.
+#[account]
+pub struct PriceObservations {
+    /// Layout version
+    pub version: u8,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+    
+    /// Sum of price × seconds up to `last_timestamp`
+    pub cumulative_price: u128,
+    
+    /// Time of the last update
+    pub last_timestamp: i64,
+    
+    /// Slot of the newest snapshot
+    pub head: u16,
+    
+    /// Number of snapshots written so far (at most `OBSERVATION_CAPACITY`)
+    pub count: u16,
+    
+    /// Ring buffer of snapshots
+    pub observations: [Observation; OBSERVATION_CAPACITY],
+    
+    /// Reserved for future fields
+    pub reserved: [u8; 32],
+}
+
+/// Snapshot of the running price × time sum
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Observation {
+    pub timestamp: i64,
+    pub cumulative_price: u128,
+}
+
+impl Observation {
+    pub const LEN: usize = 8 + // timestamp
+                            16; // cumulative_price
+}
+
+impl PriceObservations {
+    pub const VERSION: u8 = 1;
+    
+    pub const LEN: usize = 1 + // version
+                            1 + // bump
+                            16 + // cumulative_price
+                            8 + // last_timestamp
+                            2 + // head
+                            2 + // count
+                            Observation::LEN * OBSERVATION_CAPACITY + // observations
+                            32; // reserved
+    
+    /// Start an empty history at `now`
+    pub fn initialize(&mut self, bump: u8, now: i64) {
+        self.version = Self::VERSION;
+        self.bump = bump;
+        self.cumulative_price = 0;
+        self.last_timestamp = now;
+        self.head = 0;
+        self.count = 1;
+        self.observations = [Observation::default(); OBSERVATION_CAPACITY];
+        self.observations[0] = Observation {
+            timestamp: now,
+            cumulative_price: 0,
+        };
+    }
+    
+    /// Account for `price`, the curve price since the last update, up to `now`
+    ///
+    /// Call before the trade moves the price.
+    pub fn record(&mut self, price: u64, now: i64) {
+        let elapsed = now - self.last_timestamp;
+        if elapsed <= 0 {
+            return;
+        }
+        // Wraps like any price accumulator; differences stay correct
+        self.cumulative_price = self.cumulative_price.wrapping_add(price as u128 * elapsed as u128);
+        self.last_timestamp = now;
+        
+        if now - self.observations[self.head as usize].timestamp >= OBSERVATION_INTERVAL {
+            self.head = ((self.head as usize + 1) % OBSERVATION_CAPACITY) as u16;
+            self.observations[self.head as usize] = Observation {
+                timestamp: now,
+                cumulative_price: self.cumulative_price,
+            };
+            self.count = (self.count + 1).min(OBSERVATION_CAPACITY as u16);
+        }
+    }
+    
+    /// Running sum at `now`, given the curve price since the last update
+    pub fn cumulative_at(&self, current_price: u64, now: i64) -> Option<u128> {
+        let elapsed = u128::try_from(now.checked_sub(self.last_timestamp)?).ok()?;
+        Some(self.cumulative_price.wrapping_add(current_price as u128 * elapsed))
+    }
+    
+    /// Time-weighted average price over the `window_secs` seconds ending at `now`
+    ///
+    /// The sum at the start of the window is interpolated between the two
+    /// surrounding points. `None` if the window is empty or reaches back
+    /// before the oldest snapshot.
+    pub fn twap(&self, current_price: u64, now: i64, window_secs: u32) -> Option<u64> {
+        if window_secs == 0 {
+            return None;
+        }
+        let start = now - window_secs as i64;
+        let end = (now, self.cumulative_at(current_price, now)?);
+        
+        // Walk back from the present: the last update, then the snapshots newest first
+        let snapshots = (0..self.count as usize).map(|age| {
+            let slot = (self.head as usize + OBSERVATION_CAPACITY - age) % OBSERVATION_CAPACITY;
+            let observation = self.observations[slot];
+            (observation.timestamp, observation.cumulative_price)
+        });
+        let mut later = end;
+        for (timestamp, cumulative) in std::iter::once((self.last_timestamp, self.cumulative_price)).chain(snapshots) {
+            if timestamp <= start {
+                let span = (later.0 - timestamp) as u128;
+                let growth = later.1.wrapping_sub(cumulative);
+                let cumulative_at_start = cumulative
+                    .wrapping_add(growth.checked_mul((start - timestamp) as u128)? / span);
+                return u64::try_from(end.1.wrapping_sub(cumulative_at_start) / window_secs as u128).ok();
+            }
+            later = (timestamp, cumulative);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        let admin = Pubkey::new_unique();
+        Config {
+            version: Config::VERSION,
+            admin,
+            mint: Pubkey::new_unique(),
+            bump: 255,
+            pause_flags: 0,
+            treasury: admin,
+            pending_admin: None,
+            pending_admin_deadline: 0,
+            total_value: 0,
+            total_supply: 0,
+            frozen_params: 0,
+            migration_bounty: 0,
+            oracle_feeds: Config::default_oracle_feeds(),
+            decimals: DEFAULT_DECIMALS,
+            max_supply: DEFAULT_MAX_SUPPLY,
+            reserved: [0; 15],
+        }
+    }
+
+    #[test]
+    fn treasury_is_pinned_once_frozen() {
+        let mut config = config();
+        let (admin, treasury) = (config.admin, Pubkey::new_unique());
+        assert!(config.set_treasury(Pubkey::default()).is_err());
+        assert!(config.set_treasury(admin).is_err());
+        assert_eq!(config.set_treasury(treasury).unwrap(), admin);
+        assert_eq!(config.treasury, treasury);
+
+        config.freeze(freeze_flags::TREASURY).unwrap();
+        assert!(config.set_treasury(Pubkey::new_unique()).is_err());
+        assert_eq!(config.treasury, treasury);
+    }
+
+    #[test]
+    fn pause_mask_pauses_each_operation_on_its_own() {
+        let mut config = config();
+        assert!(config.set_pause_flags(pause_flags::BUY | pause_flags::REFERRAL).unwrap());
+        assert!(config.is_paused(pause_flags::BUY) && config.is_paused(pause_flags::REFERRAL));
+        assert!(!config.is_paused(pause_flags::SELL | pause_flags::AIRDROP | pause_flags::MIGRATE));
+
+        // The mask replaces the flags; setting it again changes nothing
+        assert!(config.set_pause_flags(pause_flags::SELL).unwrap());
+        assert!(!config.is_paused(pause_flags::BUY));
+        assert!(!config.set_pause_flags(pause_flags::SELL).unwrap());
+
+        // Unknown bits are rejected and leave the flags alone
+        assert!(config.set_pause_flags(pause_flags::ALL + 1).is_err());
+        assert_eq!(config.pause_flags, pause_flags::SELL);
+    }
+
+    #[test]
+    fn frozen_parameters_and_renounced_admin_stay_that_way() {
+        let mut config = config();
+        assert!(config.freeze(0).is_err());
+        assert!(config.freeze(freeze_flags::ALL + 1).is_err());
+        let mask = freeze_flags::FEES | freeze_flags::TREASURY;
+        assert_eq!(config.freeze(mask).unwrap(), mask);
+
+        // Freezing again only adds bits; there is no way to clear one
+        assert_eq!(config.freeze(freeze_flags::FEES).unwrap(), 0);
+        assert_eq!(config.freeze(freeze_flags::METADATA).unwrap(), freeze_flags::METADATA);
+        assert!(config.is_frozen(freeze_flags::FEES | freeze_flags::TREASURY | freeze_flags::METADATA));
+
+        // Renouncing drops a pending nomination, so nobody can take the role back
+        config.nominate_admin(Pubkey::new_unique(), 0).unwrap();
+        config.renounce_admin().unwrap();
+        assert_eq!(config.admin, Pubkey::default());
+        assert!(config.accept_admin(0).is_err());
+        assert!(config.nominate_admin(Pubkey::default(), 0).is_err());
+    }
+
+    #[test]
+    fn admin_cannot_renounce_while_anything_is_paused() {
+        let mut config = config();
+        let admin = config.admin;
+        config.set_pause_flags(pause_flags::AIRDROP).unwrap();
+        assert!(config.renounce_admin().is_err());
+        assert_eq!(config.admin, admin);
+
+        config.set_pause_flags(0).unwrap();
+        config.renounce_admin().unwrap();
+        assert_eq!(config.admin, Pubkey::default());
+    }
+
+    #[test]
+    fn admin_nomination_expires_and_can_be_cancelled() {
+        let mut config = config();
+        let (admin, nominee) = (config.admin, Pubkey::new_unique());
+        assert!(config.nominate_admin(admin, 0).is_err());
+        assert!(config.cancel_admin_nomination().is_err());
+
+        // Expired nominations cannot be accepted
+        let expires_at = config.nominate_admin(nominee, 1_000).unwrap();
+        assert_eq!(expires_at, 1_000 + ADMIN_TRANSFER_EXPIRY);
+        assert!(config.accept_admin(expires_at + 1).is_err());
+        assert_eq!(config.admin, admin);
+
+        // Cancelled nominations cannot either
+        assert_eq!(config.cancel_admin_nomination().unwrap(), nominee);
+        assert!(config.accept_admin(1_000).is_err());
+        assert_eq!(config.pending_admin_deadline, 0);
+
+        config.nominate_admin(nominee, 1_000).unwrap();
+        assert_eq!(config.accept_admin(expires_at).unwrap(), admin);
+        assert_eq!((config.admin, config.pending_admin), (nominee, None));
+    }
+
+    fn curve(state: CurveState, launch_deadline: i64) -> BondingCurve {
+        BondingCurve {
+            version: BondingCurve::VERSION,
+            total_sold_supply: 0,
+            total_sol_raised: 0,
+            price_points: vec![1, 2],
+            bump: 255,
+            is_migrated: state == CurveState::Migrated,
+            thresholds: MigrationThresholds::DEFAULT,
+            state,
+            migration_target: MigrationTargetKind::RaydiumCpmm,
+            launch_deadline,
+            ready_since: 0,
+            reserved: [0; 14],
+        }
+    }
+
+    #[test]
+    fn refund_mode_opens_to_anyone_after_the_deadline() {
+        let trading = curve(CurveState::Trading, 1_000);
+        assert!(trading.require_refund_allowed(999, false).is_err());
+        assert!(trading.require_refund_allowed(999, true).is_err());
+        assert!(trading.require_refund_allowed(1_000, false).is_ok());
+        assert!(curve(CurveState::ReadyToMigrate, 1_000).require_refund_allowed(1_000, false).is_ok());
+
+        // Without a deadline only a stuck migration qualifies
+        assert!(curve(CurveState::Trading, 0).require_refund_allowed(i64::MAX, false).is_err());
+    }
+
+    #[test]
+    fn admin_can_refund_a_stuck_migration_early() {
+        let mut ready = curve(CurveState::ReadyToMigrate, 0);
+        ready.ready_since = 1_000;
+        assert!(ready.require_refund_allowed(1_000, true).is_ok());
+        assert!(ready.require_refund_allowed(1_000, false).is_err());
+    }
+
+    #[test]
+    fn anyone_can_refund_a_migration_stuck_past_the_timeout() {
+        let mut ready = curve(CurveState::ReadyToMigrate, 0);
+        ready.ready_since = 1_000;
+        assert!(ready.require_refund_allowed(1_000 + MIGRATION_REFUND_TIMEOUT - 1, false).is_err());
+        assert!(ready.require_refund_allowed(1_000 + MIGRATION_REFUND_TIMEOUT, false).is_ok());
+
+        // A curve still trading has no such timeout
+        assert!(curve(CurveState::Trading, 0).require_refund_allowed(i64::MAX, false).is_err());
+    }
+
+    #[test]
+    fn refund_mode_is_entered_at_most_once_and_never_after_migration() {
+        for state in [CurveState::Migrated, CurveState::Refunding] {
+            let done = curve(state, 1_000);
+            assert!(done.require_refund_allowed(2_000, true).is_err());
+            assert!(done.require_trading().is_err());
+        }
+    }
+
+    #[test]
+    fn a_full_curve_is_ready_even_below_the_supply_threshold() {
+        let mut trading = curve(CurveState::Trading, 0);
+        let thresholds = trading.thresholds;
+        trading.total_sol_raised = thresholds.sol_min;
+        trading.total_sold_supply = thresholds.supply_threshold - 1;
+        assert!(!trading.is_migration_ready());
+
+        trading.total_sol_raised = thresholds.sol_max;
+        assert!(trading.is_migration_ready());
+        assert_eq!(trading.remaining_sol_capacity(), 0);
+    }
+
+    fn observations(now: i64) -> Box<PriceObservations> {
+        let mut observations = Box::new(PriceObservations {
+            version: 0,
+            bump: 0,
+            cumulative_price: 0,
+            last_timestamp: 0,
+            head: 0,
+            count: 0,
+            observations: [Observation::default(); OBSERVATION_CAPACITY],
+            reserved: [0; 32],
+        });
+        observations.initialize(255, now);
+        observations
+    }
+
+    #[test]
+    fn twap_weights_prices_by_how_long_they_stood() {
+        let mut history = observations(0);
+        // 100 from t=0, 200 from t=600, 400 from t=900
+        history.record(100, 600);
+        history.record(200, 900);
+
+        for (now, window, expected) in [
+            (900, 900, (100 * 600 + 200 * 300) / 900),
+            (900, 300, 200),
+            (1_200, 600, (200 * 300 + 400 * 300) / 600),
+            // Window start between snapshots is interpolated
+            (900, 450, (100 * 150 + 200 * 300) / 450),
+            (1_000, 100, 400),
+        ] {
+            assert_eq!(history.twap(400, now, window), Some(expected), "now {} window {}", now, window);
+        }
+    }
+
+    #[test]
+    fn twap_needs_history_covering_the_window() {
+        let mut history = observations(1_000);
+        assert_eq!(history.twap(5, 1_000, 0), None);
+        assert_eq!(history.twap(5, 1_500, 500), Some(5));
+        assert_eq!(history.twap(5, 1_500, 501), None);
+
+        // Once the ring wraps, the oldest snapshots are gone
+        for step in 1..=OBSERVATION_CAPACITY as i64 {
+            history.record(5, 1_000 + step * OBSERVATION_INTERVAL);
+        }
+        assert_eq!(history.count as usize, OBSERVATION_CAPACITY);
+        let now = 1_000 + OBSERVATION_CAPACITY as i64 * OBSERVATION_INTERVAL;
+        let covered = ((OBSERVATION_CAPACITY as i64 - 1) * OBSERVATION_INTERVAL) as u32;
+        assert_eq!(history.twap(5, now, covered), Some(5));
+        assert_eq!(history.twap(5, now, covered + 1), None);
+    }
+
+    #[test]
+    fn trades_within_an_interval_update_the_sum_but_not_the_ring() {
+        let mut history = observations(0);
+        history.record(10, 1);
+        history.record(1_000_000, 2);
+        history.record(10, 2);
+        assert_eq!(history.count, 1);
+        assert_eq!(history.cumulative_price, 10 + 1_000_000);
+        // A one-second spike barely moves a five-minute average
+        assert_eq!(history.twap(10, 300, 300), Some((10 * 299 + 1_000_000) / 300));
+    }
+
+    #[test]
+    fn launchpad_allows_its_authority_and_listed_creators() {
+        let authority = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let mut creators = [Pubkey::default(); MAX_LAUNCH_CREATORS];
+        creators[0] = creator;
+        let launchpad = Launchpad {
+            version: Launchpad::VERSION,
+            authority,
+            fee_recipient: authority,
+            creation_fee: 0,
+            creators,
+            launch_count: 0,
+            bump: 255,
+            legacy_mint: Pubkey::default(),
+            pending_authority: Pubkey::default(),
+        };
+
+        assert!(launchpad.is_creator_allowed(&authority));
+        assert!(launchpad.is_creator_allowed(&creator));
+        assert!(!launchpad.is_creator_allowed(&Pubkey::new_unique()));
+        // Unused slots do not let the default key in
+        assert!(!launchpad.is_creator_allowed(&Pubkey::default()));
+    }
+
+    #[test]
+    fn launchpad_authority_moves_only_when_the_nominee_accepts() {
+        let authority = Pubkey::new_unique();
+        let nominee = Pubkey::new_unique();
+        let mut launchpad = Launchpad {
+            version: Launchpad::VERSION,
+            authority,
+            fee_recipient: authority,
+            creation_fee: 0,
+            creators: [Pubkey::default(); MAX_LAUNCH_CREATORS],
+            launch_count: 0,
+            bump: 255,
+            legacy_mint: Pubkey::default(),
+            pending_authority: Pubkey::default(),
+        };
+
+        assert!(launchpad.nominate_authority(authority).is_err());
+        assert!(launchpad.nominate_authority(Pubkey::default()).is_err());
+        assert!(launchpad.cancel_authority_nomination().is_err());
+        assert!(launchpad.accept_authority().is_err());
+
+        // Cancelled nominations cannot be accepted
+        launchpad.nominate_authority(nominee).unwrap();
+        assert_eq!(launchpad.cancel_authority_nomination().unwrap(), nominee);
+        assert!(launchpad.accept_authority().is_err());
+        assert_eq!(launchpad.authority, authority);
+
+        launchpad.nominate_authority(nominee).unwrap();
+        assert_eq!(launchpad.accept_authority().unwrap(), authority);
+        assert_eq!(launchpad.authority, nominee);
+        assert_eq!(launchpad.pending_authority, Pubkey::default());
+    }
+
+    #[test]
+    fn supply_budget_keeps_room_for_the_pool_liquidity() {
+        let mut curve = curve(CurveState::Trading, 0);
+        curve.total_sold_supply = 300;
+
+        // 300 sold + 100 airdropped in circulation, 300 still to mint for the pool
+        assert!(curve.require_within_supply(400, 0, 0, 700).is_ok());
+        assert!(curve.require_within_supply(400, 1, 0, 700).is_err());
+        // A buy counts twice: minted to the buyer and matched in the pool
+        assert!(curve.require_within_supply(400, 50, 50, 800).is_ok());
+        assert!(curve.require_within_supply(400, 51, 51, 800).is_err());
+        assert!(curve.require_within_supply(u64::MAX, 1, 0, u64::MAX).is_err());
+
+        // Once migrated, the liquidity is part of the mint supply
+        curve.state = CurveState::Migrated;
+        assert!(curve.require_within_supply(700, 0, 0, 700).is_ok());
+    }
 }
@@ -1,8 +1,14 @@
 // Constants for the Yozoon token ecosystem
 use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
 
-/// Total token supply (1e18 tokens)
-pub const TOTAL_SUPPLY: u64 = 1_000_000_000_000_000_000;
+/// Max supply of launches created before it was configurable (1e18 base units)
+pub const DEFAULT_MAX_SUPPLY: u64 = 1_000_000_000_000_000_000;
+
+/// Decimals of launches created before they were configurable
+pub const DEFAULT_DECIMALS: u8 = 9;
+
+/// Maximum mint decimals, so one whole token still fits in a `u64`
+pub const MAX_DECIMALS: u8 = 18;
 
 /// Precision factor used for calculations (1e9)
 pub const PRECISION_FACTOR: u64 = 1_000_000_000;
@@ -16,14 +22,20 @@ pub const MAX_REFERRAL_FEE: u64 = 500;
 /// Maximum number of price points allowed for bonding curve
 pub const MAX_PRICE_POINTS: usize = 100;
 
-/// Minimum SOL value for migration ($60k in SOL)
-pub const MIGRATION_SOL_MIN: u64 = 60_000 * LAMPORTS_PER_SOL;
+/// Default minimum SOL raised before migration (60,000 SOL; the live value is in `BondingCurve`)
+pub const DEFAULT_MIGRATION_SOL_MIN: u64 = 60_000 * LAMPORTS_PER_SOL;
+
+/// Default maximum SOL raised for migration (63,000 SOL)
+pub const DEFAULT_MIGRATION_SOL_MAX: u64 = 63_000 * LAMPORTS_PER_SOL;
+
+/// Default minimum sold supply before migration (1e9 base units, 1 token at 9 decimals)
+pub const DEFAULT_MIGRATION_SUPPLY_THRESHOLD: u64 = 1_000_000_000;
 
-/// Maximum SOL value for migration ($63k in SOL)
-pub const MIGRATION_SOL_MAX: u64 = 63_000 * LAMPORTS_PER_SOL;
+/// Default bounty paid from the reserve to whoever runs the migration (1 SOL)
+pub const DEFAULT_MIGRATION_BOUNTY: u64 = LAMPORTS_PER_SOL;
 
-/// Supply threshold for migration (1B tokens)
-pub const MIGRATION_SUPPLY_THRESHOLD: u64 = 1_000_000_000;
+/// Upper bound for the migration bounty (10 SOL)
+pub const MAX_MIGRATION_BOUNTY: u64 = 10 * LAMPORTS_PER_SOL;
 
 /// Minimum SOL purchase amount (0.001 SOL in lamports)
 pub const MINIMUM_SOL_PURCHASE: u64 = 1_000_000;
@@ -31,32 +43,121 @@ pub const MINIMUM_SOL_PURCHASE: u64 = 1_000_000;
 /// Maximum staleness period for oracle data (5 minutes in seconds)
 pub const MAX_PRICE_STALENESS: i64 = 300;
 
+/// How far ahead of the cluster clock an oracle publish time may be (seconds)
+pub const MAX_PRICE_CLOCK_SKEW: i64 = 10;
+
+/// Maximum number of oracle feeds on the `Config` allowlist
+pub const MAX_ORACLE_FEEDS: usize = 3;
+
+/// Maximum number of creators on the `Launchpad` allowlist
+pub const MAX_LAUNCH_CREATORS: usize = 32;
+
+/// Maximum Token-2022 transfer fee a launch may set (10% in basis points)
+pub const MAX_TRANSFER_FEE_BPS: u16 = 1_000;
+
+/// Maximum oracle confidence interval as a share of the price (1% in basis points)
+pub const MAX_PRICE_CONFIDENCE_BPS: u64 = 100;
+
+/// Decimals of USD amounts (micro-dollars)
+pub const USD_DECIMALS: u8 = 6;
+
+/// Default minimum USD value of the reserve before migration ($60,000)
+pub const DEFAULT_MIGRATION_USD_MIN: u64 = 60_000 * 1_000_000;
+
+/// Pyth oracle program (mainnet)
+pub const PYTH_PROGRAM_ID: &str = "FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH";
+
+/// Pyth SOL/USD price account (mainnet); the default oracle allowlist
+pub const PYTH_SOL_USD_PRICE_ACCOUNT: &str = "H6ARHf6YXhGYeQfUzQNGk6rDNnLBQKrenN712K4AQJEG";
+
+/// Number of snapshots kept in `PriceObservations`
+pub const OBSERVATION_CAPACITY: usize = 64;
+
+/// Minimum spacing between `PriceObservations` snapshots (5 minutes in seconds)
+pub const OBSERVATION_INTERVAL: i64 = 300;
+
+/// Time a curve may wait in `ReadyToMigrate` before anyone can put it into refund
+/// mode (14 days in seconds)
+pub const MIGRATION_REFUND_TIMEOUT: i64 = 14 * 24 * 60 * 60;
+
+/// Time a nominated admin has to accept the role (7 days in seconds)
+pub const ADMIN_TRANSFER_EXPIRY: i64 = 7 * 24 * 60 * 60;
+
 /// Raydium swap program ID (for migration)
 pub const RAYDIUM_SWAP_PROGRAM_ID: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
 
-/// Raydium AMM program ID
-pub const RAYDIUM_AMM_PROGRAM_ID: &str = "SwaPpA9LAaLfeLi3a68M4DjnLqgtticKg6CnyNwgAC8";
+/// Raydium CPMM program ID (pool target for migration)
+pub const RAYDIUM_CPMM_PROGRAM_ID: &str = "CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C";
+
+/// Raydium CPMM account that receives the pool creation fee
+pub const RAYDIUM_CPMM_CREATE_POOL_FEE_RECEIVER: &str = "DNXgeM9EiiaAbaWvwjHj9fQQLAX5ZsfHyvmYUNRAdNC8";
+
+/// Raydium CPMM AMM config index used for the pool (index 0 = 0.25% fee tier)
+pub const RAYDIUM_CPMM_AMM_CONFIG_INDEX: u16 = 0;
 
 /// Raydium liquidity pool fee (basis points, 0.25%)
 pub const RAYDIUM_POOL_FEE: u64 = 25;
 
-/// Raydium LP NFT Fee key program
-pub const RAYDIUM_FEE_KEY_PROGRAM_ID: &str = "FeeKedCBd6AvpXjWUFLa8rZwJTASXTJXwSK89JS6QFmQ";
+/// Orca Whirlpool program ID (pool target for migration)
+pub const ORCA_WHIRLPOOL_PROGRAM_ID: &str = "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc";
+
+/// Whirlpool tick spacing used for the pool (64 = 0.30% fee tier)
+pub const ORCA_WHIRLPOOL_TICK_SPACING: u16 = 64;
+
+/// Meteora DAMM v2 program ID (pool target for migration)
+pub const METEORA_DAMM_PROGRAM_ID: &str = "cpamdpZCGKUy5JxQXB4dcpGPiikHawvSWAd6mEn1sGG";
+
+/// Largest part of the migration liquidity a pool may leave undeposited (basis points)
+pub const MAX_LIQUIDITY_DUST_BPS: u64 = 1;
+
+/// Lamports given to the pool creator PDA to cover the pool creation fee and account rent
+pub const POOL_CREATION_LAMPORTS: u64 = 200_000_000;
+
+/// Sum of the fee key shares of a pool (basis points, 100%)
+pub const FEE_SHARE_TOTAL_BPS: u64 = 10_000;
+
+/// Metaplex Token Metadata program
+pub const TOKEN_METADATA_PROGRAM_ID: &str = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s";
 
 /// SOL token Mint (Wrapped SOL)
 pub const WRAPPED_SOL_MINT: &str = "So11111111111111111111111111111111111111112";
 
-/// Liquidity lock period in seconds (permanent lock, represented by u64::MAX)
-pub const LIQUIDITY_LOCK_PERIOD: u64 = u64::MAX;
+/// Pause flags stored in `Config::pause_flags`, one bit per operation
+pub mod pause_flags {
+    pub const BUY: u8 = 1 << 0;
+    pub const SELL: u8 = 1 << 1;
+    pub const AIRDROP: u8 = 1 << 2;
+    pub const REFERRAL: u8 = 1 << 3;
+    pub const MIGRATE: u8 = 1 << 4;
+    pub const ALL: u8 = BUY | SELL | AIRDROP | REFERRAL | MIGRATE;
+}
+
+/// Freeze flags stored in `Config::frozen_params`, one bit per settings group.
+/// Once set a bit can never be cleared.
+pub mod freeze_flags {
+    pub const PRICE_POINTS: u8 = 1 << 0;
+    pub const FEES: u8 = 1 << 1;
+    pub const TREASURY: u8 = 1 << 2;
+    pub const MIGRATION_THRESHOLDS: u8 = 1 << 3;
+    pub const ORACLE_FEEDS: u8 = 1 << 4;
+    pub const METADATA: u8 = 1 << 5;
+    pub const ALL: u8 = PRICE_POINTS | FEES | TREASURY | MIGRATION_THRESHOLDS | ORACLE_FEEDS | METADATA;
+}
 
 /// Common PDA seeds
 pub mod seeds {
+    pub const LAUNCHPAD: &[u8] = b"launchpad";
     pub const CONFIG: &[u8] = b"config";
     pub const BONDING_CURVE: &[u8] = b"bonding_curve";
     pub const REFERRAL_PREFIX: &[u8] = b"referral";
     pub const AIRDROP_LEDGER: &[u8] = b"airdrop_ledger";
     pub const RAYDIUM_POOL: &[u8] = b"raydium_pool";
     pub const NFT_FEE_KEY: &[u8] = b"nft_fee_key";
+    pub const POOL_CREATOR: &[u8] = b"pool_creator";
+    pub const LP_LOCK: &[u8] = b"lp_lock";
+    pub const SOL_RESERVE: &[u8] = b"sol_reserve";
+    pub const FEE_VAULT: &[u8] = b"fee_vault";
+    pub const PRICE_OBSERVATIONS: &[u8] = b"price_observations";
 }
 
 pub const MINIMUM_TOKEN_SALE: u64 = 1000; // Minimum amount of tokens that can be sold
@@ -1,5 +1,3 @@
-use crate::utils::constants::TOTAL_SUPPLY;
-
 /// Calculate token price at a specific supply level
 /// 
 /// Uses linear interpolation between price points
@@ -8,15 +6,17 @@ use crate::utils::constants::TOTAL_SUPPLY;
 /// 
 /// * `supply` - Current token supply
 /// * `price_points` - Vector of price points for the bonding curve
+/// * `max_supply` - The launch's max supply, which the price points divide evenly
 /// 
 /// # Returns
 /// 
 /// The calculated token price at the given supply
-pub fn calculate_token_price(supply: u64, price_points: &[u64]) -> u64 {
+pub fn calculate_token_price(supply: u64, price_points: &[u64], max_supply: u64) -> u64 {
     let n = price_points.len() as u64;
-    let w = TOTAL_SUPPLY / n;  // Width of each segment
-    let m = supply / w;        // Current segment index
-    let r_x = supply % w;      // Position within segment
+    let w = max_supply / n;    // Width of each segment
+    // Current segment index; the top of the curve stays on the last point
+    let m = (supply / w).min(n - 1);
+    let r_x = supply - m * w;  // Position within segment
     
     // Linear interpolation between price points
     let p_m = price_points[m as usize]; // Current price point
@@ -27,7 +27,20 @@ pub fn calculate_token_price(supply: u64, price_points: &[u64]) -> u64 {
     };
     
     // Linear interpolation formula: p_m + ((p_m1 - p_m) * r_x) / w
-    p_m + ((p_m1 - p_m) * r_x) / w
+    // (128-bit intermediate; on the last segment p_m1 == p_m, so the result never exceeds p_m1)
+    p_m + ((p_m1 - p_m) as u128 * r_x as u128 / w as u128) as u64
+}
+
+/// Compute `a * b / c` with a 128-bit intermediate
+/// 
+/// # Returns
+/// 
+/// `None` if `c` is zero or the result does not fit in a u64
+pub fn mul_div(a: u64, b: u64, c: u64) -> Option<u64> {
+    if c == 0 {
+        return None;
+    }
+    u64::try_from(a as u128 * b as u128 / c as u128).ok()
 }
 
 /// Calculate tokens to be received for a specific SOL amount
@@ -52,8 +65,8 @@ pub fn calculate_tokens_for_sol_amount(
     let fee = sol_amount * fee_percentage / 10_000;
     let net_sol = sol_amount - fee;
     
-    // Calculate tokens with precision
-    let tokens = net_sol * precision_factor / price;
+    // Calculate tokens with precision (saturates; callers reject it via the supply check)
+    let tokens = mul_div(net_sol, precision_factor, price).unwrap_or(u64::MAX);
     
     (tokens, net_sol)
 }
@@ -74,17 +87,26 @@ pub fn calculate_fee_split(fee: u64) -> (u64, u64) {
     (referrer_share, project_share)
 }
 
-/// Check if a price feed is stale
-/// 
-/// # Arguments
-/// 
-/// * `price_timestamp` - Timestamp of the price feed
-/// * `current_time` - Current timestamp
-/// * `max_staleness` - Maximum allowed staleness in seconds
-/// 
-/// # Returns
-/// 
-/// `true` if price is fresh, `false` if stale
-pub fn is_price_fresh(price_timestamp: i64, current_time: i64, max_staleness: i64) -> bool {
-    current_time - price_timestamp < max_staleness
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_price_interpolates_between_points() {
+        let points = [100, 200, 400];
+        assert_eq!(calculate_token_price(0, &points, 3_000), 100);
+        assert_eq!(calculate_token_price(500, &points, 3_000), 150);
+        assert_eq!(calculate_token_price(1_500, &points, 3_000), 300);
+        assert_eq!(calculate_token_price(2_999, &points, 3_000), 400);
+        assert_eq!(calculate_token_price(3_000, &points, 3_000), 400);
+    }
+
+    #[test]
+    fn token_price_stays_in_bounds_for_uneven_max_supply() {
+        // 1_000 / 3 leaves a remainder of 1 past the last full segment
+        let points = [100, 200, 400];
+        assert_eq!(calculate_token_price(999, &points, 1_000), 400);
+        assert_eq!(calculate_token_price(1_000, &points, 1_000), 400);
+        assert_eq!(calculate_token_price(u64::MAX, &points, 1_000), 400);
+    }
 }
@@ -1,3 +1,5 @@
 pub mod constants;
-pub mod price;
+pub mod oracle;
 pub mod helpers;
+pub mod twap;
+pub mod token;
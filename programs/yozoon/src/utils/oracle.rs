@@ -0,0 +1,181 @@
+//! Pyth price accounts, validated for on-chain use
+//!
+//! Every oracle read in the program goes through `load_validated_price`, which
+//! checks the account against the `Config` allowlist and rejects stale,
+//! future-dated, non-positive and low-confidence prices with their own errors.
+use anchor_lang::prelude::*;
+use pyth_sdk_solana::{load_price_feed_from_account_info, Price};
+use std::str::FromStr;
+use crate::errors::YozoonError;
+use crate::state::Config;
+use crate::utils::constants::{
+    MAX_PRICE_CLOCK_SKEW, MAX_PRICE_CONFIDENCE_BPS, MAX_PRICE_STALENESS, PYTH_PROGRAM_ID, USD_DECIMALS,
+};
+
+/// Check a publish time against the cluster clock
+///
+/// A price is fresh up to and including `max_staleness` seconds old. Publishers
+/// run slightly ahead of the cluster clock at times, so up to `MAX_PRICE_CLOCK_SKEW`
+/// seconds in the future is accepted; anything further is rejected.
+pub fn check_price_age(publish_time: i64, current_time: i64, max_staleness: i64) -> Result<()> {
+    require!(
+        publish_time <= current_time.saturating_add(MAX_PRICE_CLOCK_SKEW),
+        YozoonError::FuturePrice
+    );
+    require!(
+        current_time.saturating_sub(publish_time) <= max_staleness,
+        YozoonError::StalePrice
+    );
+    Ok(())
+}
+
+/// Check a price for on-chain use: age, sign and confidence interval
+///
+/// Rejects prices older than `MAX_PRICE_STALENESS`, non-positive prices and prices
+/// whose confidence interval is wider than `MAX_PRICE_CONFIDENCE_BPS` of the price
+pub fn validate_price(price: &Price, current_time: i64) -> Result<()> {
+    check_price_age(price.publish_time, current_time, MAX_PRICE_STALENESS)?;
+    require!(price.price > 0, YozoonError::InvalidOraclePrice);
+    require!(
+        (price.conf as u128) * 10_000 <= (price.price as u128) * MAX_PRICE_CONFIDENCE_BPS as u128,
+        YozoonError::PriceConfidenceTooLow
+    );
+    Ok(())
+}
+
+/// Read a Pyth price account on the `Config` allowlist and validate its price
+pub fn load_validated_price(config: &Config, price_account: &AccountInfo, current_time: i64) -> Result<Price> {
+    require!(
+        config.is_oracle_feed_allowed(price_account.key),
+        YozoonError::OracleFeedNotAllowed
+    );
+    require_keys_eq!(
+        *price_account.owner,
+        Pubkey::from_str(PYTH_PROGRAM_ID).unwrap(),
+        YozoonError::InvalidOracleAccount
+    );
+    let feed = load_price_feed_from_account_info(price_account)
+        .map_err(|_| error!(YozoonError::InvalidOracleAccount))?;
+    let price = feed.get_price_unchecked();
+    validate_price(&price, current_time)?;
+    
+    Ok(price)
+}
+
+/// Convert lamports to USD (with `USD_DECIMALS` decimals) at a SOL/USD price
+pub fn lamports_to_usd(lamports: u64, sol_usd: &Price) -> Option<u64> {
+    if sol_usd.price <= 0 {
+        return None;
+    }
+    
+    // usd = lamports / 1e9 * price * 10^expo, scaled up by 10^USD_DECIMALS
+    let exponent = sol_usd.expo + USD_DECIMALS as i32 - 9;
+    let value = (lamports as u128).checked_mul(sol_usd.price as u128)?;
+    let scaled = if exponent >= 0 {
+        value.checked_mul(10u128.checked_pow(exponent as u32)?)?
+    } else {
+        value / 10u128.checked_pow(exponent.unsigned_abs())?
+    };
+    u64::try_from(scaled).ok()
+}
+
+/// Convert USD (with `USD_DECIMALS` decimals) to lamports at a SOL/USD price
+pub fn usd_to_lamports(usd: u64, sol_usd: &Price) -> Option<u64> {
+    if sol_usd.price <= 0 {
+        return None;
+    }
+    
+    // lamports = usd / 10^USD_DECIMALS / (price * 10^expo) * 1e9
+    let exponent = 9 - USD_DECIMALS as i32 - sol_usd.expo;
+    let price = sol_usd.price as u128;
+    let lamports = if exponent >= 0 {
+        (usd as u128).checked_mul(10u128.checked_pow(exponent as u32)?)? / price
+    } else {
+        usd as u128 / price.checked_mul(10u128.checked_pow(exponent.unsigned_abs())?)?
+    };
+    u64::try_from(lamports).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn sol_usd(price: i64, expo: i32) -> Price {
+        Price { price, conf: 0, expo, publish_time: 0 }
+    }
+    
+    #[test]
+    fn price_age_edges() {
+        let now = 1_000_000;
+        for (publish_time, expected) in [
+            (now, Ok(())),
+            (now - MAX_PRICE_STALENESS, Ok(())),
+            (now - MAX_PRICE_STALENESS - 1, Err(YozoonError::StalePrice)),
+            (now + MAX_PRICE_CLOCK_SKEW, Ok(())),
+            (now + MAX_PRICE_CLOCK_SKEW + 1, Err(YozoonError::FuturePrice)),
+            (i64::MIN, Err(YozoonError::StalePrice)),
+            (i64::MAX, Err(YozoonError::FuturePrice)),
+        ] {
+            assert_eq!(
+                check_price_age(publish_time, now, MAX_PRICE_STALENESS),
+                expected.map_err(Into::into),
+                "publish time {}",
+                publish_time
+            );
+        }
+    }
+    
+    #[test]
+    fn price_validation_edges() {
+        let now = 1_000_000;
+        let price = |price: i64, conf: u64, publish_time: i64| Price { price, conf, expo: -8, publish_time };
+        for (case, expected) in [
+            (price(15_000_000_000, 0, now), Ok(())),
+            // Confidence up to MAX_PRICE_CONFIDENCE_BPS of the price
+            (price(15_000_000_000, 150_000_000, now), Ok(())),
+            (price(15_000_000_000, 150_000_001, now), Err(YozoonError::PriceConfidenceTooLow)),
+            (price(0, 0, now), Err(YozoonError::InvalidOraclePrice)),
+            (price(-15_000_000_000, 0, now), Err(YozoonError::InvalidOraclePrice)),
+            // Age is checked first
+            (price(-1, 0, now - MAX_PRICE_STALENESS - 1), Err(YozoonError::StalePrice)),
+            (price(-1, 0, now + MAX_PRICE_CLOCK_SKEW + 1), Err(YozoonError::FuturePrice)),
+        ] {
+            assert_eq!(validate_price(&case, now), expected.map_err(Into::into), "{:?}", case);
+        }
+    }
+    
+    #[test]
+    fn converts_lamports_to_usd() {
+        // 2 SOL at $150.00000000 (expo -8) = $300.000000
+        let price = sol_usd(15_000_000_000, -8);
+        assert_eq!(lamports_to_usd(2_000_000_000, &price), Some(300_000_000));
+        
+        // Exponents above the lamport/USD decimal gap scale up: 1 SOL at $100,000
+        assert_eq!(lamports_to_usd(1_000_000_000, &sol_usd(1, 5)), Some(100_000_000_000));
+        
+        // Non-positive prices have no USD value
+        assert_eq!(lamports_to_usd(1_000_000_000, &sol_usd(0, -8)), None);
+        assert_eq!(lamports_to_usd(1_000_000_000, &sol_usd(-1, -8)), None);
+    }
+    
+    #[test]
+    fn usd_conversion_reports_overflow() {
+        assert_eq!(lamports_to_usd(u64::MAX, &sol_usd(i64::MAX, 0)), None);
+        assert_eq!(usd_to_lamports(u64::MAX, &sol_usd(1, -8)), None);
+    }
+    
+    #[test]
+    fn converts_usd_to_lamports() {
+        // $300.000000 at $150.00000000 (expo -8) = 2 SOL
+        let price = sol_usd(15_000_000_000, -8);
+        assert_eq!(usd_to_lamports(300_000_000, &price), Some(2_000_000_000));
+        // Rounds down: $0.000001 is 6.67 lamports
+        assert_eq!(usd_to_lamports(1, &price), Some(6));
+        
+        // Positive exponents divide: $100,000 at 1e5 = 1 SOL
+        assert_eq!(usd_to_lamports(100_000_000_000, &sol_usd(1, 5)), Some(1_000_000_000));
+        
+        assert_eq!(usd_to_lamports(1_000_000, &sol_usd(0, -8)), None);
+        assert_eq!(usd_to_lamports(1_000_000, &sol_usd(-1, -8)), None);
+    }
+}
@@ -0,0 +1,225 @@
+//! Helpers for project mints under either SPL Token or Token-2022
+//!
+//! Mint extensions are read by walking the mint's TLV entries directly: the
+//! `spl-token-2022` release Anchor depends on predates the metadata pointer and
+//! refuses to search past an extension it does not know.
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{transfer_fee::TransferFeeConfig, ExtensionType},
+    pod::pod_from_bytes,
+    state::{Account, AccountState, Mint},
+};
+use anchor_spl::token_interface::{self, TokenAccount};
+use crate::errors::YozoonError;
+use crate::state::MintExtensions;
+
+/// Token-2022 instruction holding the metadata pointer sub-instructions
+pub const METADATA_POINTER_EXTENSION: u8 = 39;
+
+/// TLV type of the metadata pointer extension
+pub const METADATA_POINTER: u16 = 18;
+
+/// Token-2022 `SetAuthority` instruction
+const SET_AUTHORITY: u8 = 6;
+
+/// `SetAuthority` type of the metadata pointer's authority
+const METADATA_POINTER_AUTHORITY: u8 = 12;
+
+/// Length of the metadata pointer extension: authority and metadata address
+const METADATA_POINTER_LEN: usize = 64;
+
+/// Length of a TLV entry header: type and length
+const TLV_HEADER_LEN: usize = 4;
+
+/// Offset of the first TLV entry in an extended mint: base state padded to the
+/// token account size, then the account type byte
+const TLV_START: usize = Account::LEN + 1;
+
+/// Data of the `extension_type` extension in an extended mint's account data
+pub fn mint_extension(data: &[u8], extension_type: u16) -> Option<&[u8]> {
+    let mut tlv = data.get(TLV_START..)?;
+    while tlv.len() >= TLV_HEADER_LEN {
+        let entry_type = u16::from_le_bytes([tlv[0], tlv[1]]);
+        let length = u16::from_le_bytes([tlv[2], tlv[3]]) as usize;
+        if entry_type == u16::from(ExtensionType::Uninitialized) {
+            return None;
+        }
+        let value = tlv.get(TLV_HEADER_LEN..TLV_HEADER_LEN + length)?;
+        if entry_type == extension_type {
+            return Some(value);
+        }
+        tlv = &tlv[TLV_HEADER_LEN + length..];
+    }
+    None
+}
+
+/// Token-2022 fee on a transfer of `amount` this epoch (0 for mints without a transfer fee)
+pub fn transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    let data = mint.try_borrow_data()?;
+    let Some(config) = mint_extension(&data, u16::from(ExtensionType::TransferFeeConfig)) else {
+        return Ok(0);
+    };
+    pod_from_bytes::<TransferFeeConfig>(config)?
+        .calculate_epoch_fee(Clock::get()?.epoch, amount)
+        .ok_or_else(|| error!(YozoonError::MathOverflow))
+}
+
+/// True while new token accounts of `mint` start frozen (until `migrate` opens transfers)
+pub fn is_default_frozen(mint: &AccountInfo) -> Result<bool> {
+    let data = mint.try_borrow_data()?;
+    Ok(mint_extension(&data, u16::from(ExtensionType::DefaultAccountState)) == Some(&[AccountState::Frozen as u8][..]))
+}
+
+/// True for mints launched frozen until migration, before and after it
+pub fn has_default_account_state(mint: &AccountInfo) -> Result<bool> {
+    let data = mint.try_borrow_data()?;
+    Ok(mint_extension(&data, u16::from(ExtensionType::DefaultAccountState)).is_some())
+}
+
+/// Account size of a mint with `extensions`
+pub fn mint_space(extensions: &MintExtensions) -> usize {
+    let mut extension_types = vec![];
+    if extensions.transfer_fee.is_some() {
+        extension_types.push(ExtensionType::TransferFeeConfig);
+    }
+    if extensions.non_transferable_until_migration {
+        extension_types.push(ExtensionType::DefaultAccountState);
+    }
+    let space = ExtensionType::get_account_len::<Mint>(&extension_types);
+    if extensions.metadata_pointer {
+        space.max(TLV_START) + TLV_HEADER_LEN + METADATA_POINTER_LEN
+    } else {
+        space
+    }
+}
+
+/// Build the metadata pointer's `Initialize`, which must come before `InitializeMint2`
+pub fn initialize_metadata_pointer(
+    token_program: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    metadata: &Pubkey,
+) -> Instruction {
+    let mut data = vec![METADATA_POINTER_EXTENSION, 0];
+    data.extend(authority.as_ref());
+    data.extend(metadata.as_ref());
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![AccountMeta::new(*mint, false)],
+        data,
+    }
+}
+
+/// Authority of the mint's metadata pointer, if it has one
+pub fn metadata_pointer_authority(mint: &AccountInfo) -> Result<Option<Pubkey>> {
+    let data = mint.try_borrow_data()?;
+    Ok(mint_extension(&data, METADATA_POINTER)
+        .and_then(|pointer| pointer.get(..32))
+        .map(|authority| Pubkey::try_from(authority).unwrap())
+        .filter(|authority| *authority != Pubkey::default()))
+}
+
+/// Build `SetAuthority` handing the metadata pointer's authority to `new_authority`
+pub fn set_metadata_pointer_authority(
+    token_program: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    new_authority: &Pubkey,
+) -> Instruction {
+    let mut data = vec![SET_AUTHORITY, METADATA_POINTER_AUTHORITY, 1];
+    data.extend(new_authority.as_ref());
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(*mint, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data,
+    }
+}
+
+/// Run `operation` on `account`, thawing it first and refreezing it after if it is frozen
+///
+/// Frozen-until-migration mints freeze every new account; the config PDA, their
+/// freeze authority, lets the curve mint into and burn from them all the same.
+pub fn with_thawed<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    account: &InterfaceAccount<'info, TokenAccount>,
+    freeze_authority: &AccountInfo<'info>,
+    signer_seeds: &[&[u8]],
+    operation: impl FnOnce() -> Result<()>,
+) -> Result<()> {
+    if !account.is_frozen() {
+        return operation();
+    }
+
+    token_interface::thaw_account(CpiContext::new_with_signer(
+        token_program.clone(),
+        token_interface::ThawAccount {
+            account: account.to_account_info(),
+            mint: mint.clone(),
+            authority: freeze_authority.clone(),
+        },
+        &[signer_seeds],
+    ))?;
+    operation()?;
+    token_interface::freeze_account(CpiContext::new_with_signer(
+        token_program.clone(),
+        token_interface::FreezeAccount {
+            account: account.to_account_info(),
+            mint: mint.clone(),
+            authority: freeze_authority.clone(),
+        },
+        &[signer_seeds],
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tlv(entries: &[(u16, &[u8])]) -> Vec<u8> {
+        let mut data = vec![0; TLV_START];
+        for (entry_type, value) in entries {
+            data.extend(entry_type.to_le_bytes());
+            data.extend((value.len() as u16).to_le_bytes());
+            data.extend(*value);
+        }
+        data
+    }
+
+    #[test]
+    fn finds_extensions_past_unknown_types() {
+        let data = tlv(&[(METADATA_POINTER, &[7; METADATA_POINTER_LEN]), (6, &[2])]);
+        assert_eq!(mint_extension(&data, 6), Some(&[2u8][..]));
+        assert_eq!(mint_extension(&data, METADATA_POINTER), Some(&[7u8; METADATA_POINTER_LEN][..]));
+        assert_eq!(mint_extension(&data, 1), None);
+
+        // Plain mints have no extension area, and the search stops at padding
+        assert_eq!(mint_extension(&[0; Mint::LEN], 6), None);
+        assert_eq!(mint_extension(&tlv(&[(0, &[]), (6, &[2])]), 6), None);
+    }
+
+    #[test]
+    fn mint_space_covers_each_extension() {
+        assert_eq!(mint_space(&MintExtensions::default()), Mint::LEN);
+        let all = MintExtensions {
+            transfer_fee: Some(crate::state::TransferFeeParams {
+                basis_points: 100,
+                maximum_fee: 1,
+            }),
+            metadata_pointer: true,
+            non_transferable_until_migration: true,
+        };
+        let fee = std::mem::size_of::<TransferFeeConfig>();
+        assert_eq!(mint_space(&all), TLV_START + 3 * TLV_HEADER_LEN + fee + 1 + METADATA_POINTER_LEN);
+        let pointer_only = MintExtensions {
+            metadata_pointer: true,
+            ..MintExtensions::default()
+        };
+        assert_eq!(mint_space(&pointer_only), TLV_START + TLV_HEADER_LEN + METADATA_POINTER_LEN);
+    }
+}
@@ -0,0 +1,37 @@
+//! Off-chain TWAP helpers working on raw account data
+//!
+//! Clients fetch the `PriceObservations`, `BondingCurve` and `Config` accounts and compute
+//! the same average `get_twap` would, without sending a transaction.
+use anchor_lang::prelude::*;
+use crate::errors::YozoonError;
+use crate::state::{load_any_layout, BondingCurve, Config, PriceObservations};
+use crate::utils::helpers::calculate_token_price;
+
+/// Decode `PriceObservations` account data (with discriminator)
+pub fn load_price_observations(data: &[u8]) -> Result<PriceObservations> {
+    PriceObservations::try_deserialize(&mut &data[..])
+}
+
+/// Current curve price from `BondingCurve` and `Config` account data in any layout
+pub fn spot_price_from_account_data(curve_data: &[u8], config_data: &[u8]) -> Result<u64> {
+    let (curve, _) = load_any_layout::<BondingCurve>(curve_data)?;
+    let (config, _) = load_any_layout::<Config>(config_data)?;
+    Ok(calculate_token_price(curve.total_sold_supply, &curve.price_points, config.max_supply()))
+}
+
+/// Time-weighted average price over the `window_secs` seconds ending at `now`
+///
+/// `now` should be the cluster time (the `Clock` sysvar), not the local clock.
+pub fn twap_from_account_data(
+    observations_data: &[u8],
+    curve_data: &[u8],
+    config_data: &[u8],
+    now: i64,
+    window_secs: u32,
+) -> Result<u64> {
+    let observations = load_price_observations(observations_data)?;
+    let price = spot_price_from_account_data(curve_data, config_data)?;
+    observations
+        .twap(price, now, window_secs)
+        .ok_or_else(|| error!(YozoonError::InsufficientObservations))
+}
@@ -0,0 +1,845 @@
+//! End-to-end buy → threshold → migrate flow against the mock CPMM program.
+//!
+//! Runs both programs as native processors inside `solana-program-test`, so no
+//! validator or mainnet Raydium is needed.
+use anchor_lang::{AccountDeserialize, AccountSerialize, Id, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address;
+use solana_program_test::*;
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    clock::Clock,
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::Instruction,
+    native_token::LAMPORTS_PER_SOL,
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::{Keypair, Signer},
+    system_instruction, system_program, sysvar,
+    transaction::Transaction,
+};
+use pyth_sdk_solana::state::{AccountType, PriceAccount, PriceInfo, PriceStatus, MAGIC, VERSION_2};
+use spl_token::state::{Account as TokenAccount, AccountState, Mint};
+use std::str::FromStr;
+use yozoon::dex::{raydium_cpmm::CpmmAccounts, MigrationTargetKind};
+use yozoon::metadata::{metadata_address, TokenMetadataProgram};
+use yozoon::raydium::{cpmm, FeeKeyNft, LpLockMode, RaydiumPool};
+use yozoon::state::{BondingCurve, CurveState, Launchpad, MigrationThresholds, MintExtensions};
+use yozoon::utils::constants::*;
+use yozoon::utils::twap;
+
+/// The token metadata program is not part of this flow; accept any call
+fn program_stub(_program_id: &Pubkey, _accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    Ok(())
+}
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &yozoon::ID).0
+}
+
+fn packed<T: Pack>(state: T) -> Vec<u8> {
+    let mut data = vec![0; T::LEN];
+    state.pack_into_slice(&mut data);
+    data
+}
+
+/// Native (wrapped SOL) token account holding `amount` lamports on top of its rent
+fn wrapped_sol_account(owner: Pubkey, amount: u64) -> AccountSharedData {
+    let rent = Rent::default().minimum_balance(TokenAccount::LEN);
+    AccountSharedData::from(Account {
+        lamports: rent + amount,
+        data: packed(TokenAccount {
+            mint: Pubkey::from_str(WRAPPED_SOL_MINT).unwrap(),
+            owner,
+            amount,
+            delegate: COption::None,
+            state: AccountState::Initialized,
+            is_native: COption::Some(rent),
+            delegated_amount: 0,
+            close_authority: COption::None,
+        }),
+        owner: spl_token::ID,
+        executable: false,
+        rent_epoch: 0,
+    })
+}
+
+/// Pyth SOL/USD price account at `price` × 10^-8 USD, published at `publish_time`
+fn pyth_sol_usd_account(price: i64, publish_time: i64) -> AccountSharedData {
+    let price_account = PriceAccount {
+        magic: MAGIC,
+        ver: VERSION_2,
+        atype: AccountType::Price as u32,
+        size: std::mem::size_of::<PriceAccount>() as u32,
+        expo: -8,
+        timestamp: publish_time,
+        agg: PriceInfo {
+            price,
+            conf: price as u64 / 1_000,
+            status: PriceStatus::Trading,
+            ..PriceInfo::default()
+        },
+        ..PriceAccount::default()
+    };
+    let data = bytemuck::bytes_of(&price_account).to_vec();
+    let mut account = AccountSharedData::new(LAMPORTS_PER_SOL, data.len(), &Pubkey::from_str(PYTH_PROGRAM_ID).unwrap());
+    account.set_data(data);
+    account
+}
+
+async fn process(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn get_account(context: &mut ProgramTestContext, address: Pubkey) -> Account {
+    context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .expect("account not found")
+}
+
+async fn token_balance(context: &mut ProgramTestContext, address: Pubkey) -> u64 {
+    TokenAccount::unpack(&get_account(context, address).await.data).unwrap().amount
+}
+
+async fn total_fees_sol(context: &mut ProgramTestContext, raydium_pool: Pubkey) -> u64 {
+    let data = get_account(context, raydium_pool).await.data;
+    RaydiumPool::try_deserialize(&mut data.as_slice()).unwrap().total_fees_sol
+}
+
+async fn create_token_account(
+    context: &mut ProgramTestContext,
+    account: &Keypair,
+    mint: Pubkey,
+    owner: Pubkey,
+) {
+    let rent = Rent::default().minimum_balance(TokenAccount::LEN);
+    process(
+        context,
+        &[
+            system_instruction::create_account(
+                &context.payer.pubkey(),
+                &account.pubkey(),
+                rent,
+                TokenAccount::LEN as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_account3(&spl_token::ID, &account.pubkey(), &mint, &owner)
+                .unwrap(),
+        ],
+        &[account],
+    )
+    .await
+    .unwrap();
+}
+
+fn buy_instruction(mint: Pubkey, buyer_token_account: Pubkey, buyer: Pubkey, sol_amount: u64) -> Instruction {
+    Instruction {
+        program_id: yozoon::ID,
+        accounts: yozoon::accounts::BuyTokens {
+            config: pda(&[seeds::CONFIG, mint.as_ref()]),
+            bonding_curve: pda(&[seeds::BONDING_CURVE, mint.as_ref()]),
+            price_observations: pda(&[seeds::PRICE_OBSERVATIONS, mint.as_ref()]),
+            mint,
+            buyer_token_account,
+            buyer,
+            sol_reserve: pda(&[seeds::SOL_RESERVE, mint.as_ref()]),
+            referral: None,
+            referrer: None,
+            sol_usd_price: None,
+            system_program: system_program::ID,
+            token_program: spl_token::ID,
+        }
+        .to_account_metas(None),
+        data: yozoon::instruction::BuyTokens { sol_amount }.data(),
+    }
+}
+
+#[tokio::test]
+async fn buy_to_threshold_then_migrate_and_lock_lp() {
+    run_migration_flow(LpLockMode::Lock).await;
+}
+
+#[tokio::test]
+async fn buy_to_threshold_then_migrate_and_burn_lp() {
+    run_migration_flow(LpLockMode::Burn).await;
+}
+
+/// Buy → threshold → migrate, then check the pool, reserves, LP handling and fee claims
+async fn run_migration_flow(lp_lock_mode: LpLockMode) {
+    let mut program_test = ProgramTest::new("yozoon", yozoon::ID, processor!(yozoon::entry));
+    program_test.prefer_bpf(false);
+    program_test.add_program("mock_amm", mock_amm::ID, processor!(mock_amm::entry));
+    program_test.add_program("metadata_stub", TokenMetadataProgram::id(), processor!(program_stub));
+
+    // Wrapped SOL mint and the CPMM fee tier config
+    let wsol = Pubkey::from_str(WRAPPED_SOL_MINT).unwrap();
+    program_test.add_account(
+        wsol,
+        Account {
+            lamports: LAMPORTS_PER_SOL,
+            data: packed(Mint {
+                mint_authority: COption::None,
+                supply: 0,
+                decimals: 9,
+                is_initialized: true,
+                freeze_authority: COption::None,
+            }),
+            owner: spl_token::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    let amm_config = cpmm::amm_config_address(&mock_amm::ID, RAYDIUM_CPMM_AMM_CONFIG_INDEX);
+    program_test.add_account(
+        amm_config,
+        Account {
+            lamports: LAMPORTS_PER_SOL,
+            data: vec![0; 8],
+            owner: mock_amm::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // A buyer rich enough to overshoot the migration threshold in one purchase
+    let buyer = Keypair::new();
+    let buyer_lamports = DEFAULT_MIGRATION_SOL_MAX + 10 * LAMPORTS_PER_SOL;
+    program_test.add_account(
+        buyer.pubkey(),
+        Account {
+            lamports: buyer_lamports,
+            owner: system_program::ID,
+            ..Account::default()
+        },
+    );
+
+    // Anyone can run the migration; this account does it and collects the bounty
+    let caller = Keypair::new();
+    program_test.add_account(
+        caller.pubkey(),
+        Account {
+            lamports: 10 * LAMPORTS_PER_SOL,
+            owner: system_program::ID,
+            ..Account::default()
+        },
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let admin = context.payer.pubkey();
+    let mint = Keypair::new();
+    let config = pda(&[seeds::CONFIG, mint.pubkey().as_ref()]);
+    let bonding_curve = pda(&[seeds::BONDING_CURVE, mint.pubkey().as_ref()]);
+    let sol_reserve = pda(&[seeds::SOL_RESERVE, mint.pubkey().as_ref()]);
+
+    // 1. Launch: launchpad, then the mint, config and bonding curve. Natively run programs
+    //    have no program data for `initialize_launchpad` to check, so write the launchpad
+    //    as that instruction would.
+    let (launchpad, launchpad_bump) = Pubkey::find_program_address(&[seeds::LAUNCHPAD], &yozoon::ID);
+    let mut launchpad_data = Vec::with_capacity(8 + Launchpad::LEN);
+    Launchpad {
+        version: Launchpad::VERSION,
+        authority: admin,
+        fee_recipient: admin,
+        creation_fee: LAMPORTS_PER_SOL / 10,
+        creators: [Pubkey::default(); MAX_LAUNCH_CREATORS],
+        launch_count: 0,
+        bump: launchpad_bump,
+        legacy_mint: Pubkey::default(),
+        pending_authority: Pubkey::default(),
+    }
+    .try_serialize(&mut launchpad_data)
+    .unwrap();
+    context.set_account(
+        &launchpad,
+        &AccountSharedData::from(Account {
+            lamports: Rent::default().minimum_balance(launchpad_data.len()),
+            data: launchpad_data,
+            owner: yozoon::ID,
+            executable: false,
+            rent_epoch: 0,
+        }),
+    );
+
+    process(
+        &mut context,
+        &[Instruction {
+            program_id: yozoon::ID,
+            accounts: yozoon::accounts::InitializeMint {
+                launchpad,
+                fee_recipient: admin,
+                config,
+                mint: mint.pubkey(),
+                metadata: metadata_address(&mint.pubkey()),
+                admin,
+                token_metadata_program: TokenMetadataProgram::id(),
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+                rent: sysvar::rent::ID,
+            }
+            .to_account_metas(None),
+            data: yozoon::instruction::InitializeMint {
+                name: "Yozoon".to_string(),
+                symbol: "YOZ".to_string(),
+                uri: "https://yozoon.io/token.json".to_string(),
+                decimals: DEFAULT_DECIMALS,
+                max_supply: DEFAULT_MAX_SUPPLY,
+                extensions: MintExtensions::default(),
+            }
+            .data(),
+        }],
+        &[&mint],
+    )
+    .await
+    .unwrap();
+
+    let launchpad_state = Launchpad::try_deserialize(&mut get_account(&mut context, launchpad).await.data.as_slice()).unwrap();
+    assert_eq!(launchpad_state.launch_count, 1);
+
+    // A USD threshold needs a launch deadline to fall back on
+    let launch_deadline = context.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp + 30 * 24 * 60 * 60;
+
+    process(
+        &mut context,
+        &[Instruction {
+            program_id: yozoon::ID,
+            accounts: yozoon::accounts::InitializeBondingCurve {
+                config,
+                bonding_curve,
+                price_observations: pda(&[seeds::PRICE_OBSERVATIONS, mint.pubkey().as_ref()]),
+                sol_reserve,
+                admin,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: yozoon::instruction::InitializeBondingCurve {
+                price_points: vec![PRECISION_FACTOR, 2 * PRECISION_FACTOR],
+                thresholds: MigrationThresholds::DEFAULT,
+                migration_target: MigrationTargetKind::RaydiumCpmm,
+                launch_deadline,
+            }
+            .data(),
+        }],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    // 2. Buy past the migration threshold; only the part up to `sol_max` is filled
+    let buyer_tokens = Keypair::new();
+    create_token_account(&mut context, &buyer_tokens, mint.pubkey(), buyer.pubkey()).await;
+    process(
+        &mut context,
+        &[buy_instruction(
+            mint.pubkey(),
+            buyer_tokens.pubkey(),
+            buyer.pubkey(),
+            DEFAULT_MIGRATION_SOL_MAX + 5 * LAMPORTS_PER_SOL,
+        )],
+        &[&buyer],
+    )
+    .await
+    .unwrap();
+
+    let curve = BondingCurve::try_deserialize(
+        &mut get_account(&mut context, bonding_curve).await.data.as_slice(),
+    )
+    .unwrap();
+    assert_eq!(curve.total_sol_raised, DEFAULT_MIGRATION_SOL_MAX);
+    assert_eq!(curve.state, CurveState::ReadyToMigrate);
+    assert_eq!(token_balance(&mut context, buyer_tokens.pubkey()).await, curve.total_sold_supply);
+    assert_eq!(
+        get_account(&mut context, buyer.pubkey()).await.lamports,
+        buyer_lamports - DEFAULT_MIGRATION_SOL_MAX
+    );
+
+    // The price history computes offline; with no trade since, a later window averages the spot price
+    let observations = get_account(&mut context, pda(&[seeds::PRICE_OBSERVATIONS, mint.pubkey().as_ref()])).await.data;
+    let curve_data = get_account(&mut context, bonding_curve).await.data;
+    let config_data = get_account(&mut context, config).await.data;
+    let clock_now = context.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+    assert_eq!(
+        twap::twap_from_account_data(&observations, &curve_data, &config_data, clock_now + 60, 60).unwrap(),
+        twap::spot_price_from_account_data(&curve_data, &config_data).unwrap()
+    );
+
+    // 3. Threshold reached, with SOL at $150
+    let sol_usd_price = Pubkey::from_str(PYTH_SOL_USD_PRICE_ACCOUNT).unwrap();
+    let now = context.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+    context.set_account(&sol_usd_price, &pyth_sol_usd_account(15_000_000_000, now));
+    process(
+        &mut context,
+        &[Instruction {
+            program_id: yozoon::ID,
+            accounts: yozoon::accounts::CheckAutoMigration { config, bonding_curve, sol_usd_price }.to_account_metas(None),
+            data: yozoon::instruction::CheckAutoMigration {}.data(),
+        }],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    // Trading is frozen until someone migrates
+    let result = process(
+        &mut context,
+        &[buy_instruction(mint.pubkey(), buyer_tokens.pubkey(), buyer.pubkey(), MINIMUM_SOL_PURCHASE)],
+        &[&buyer],
+    )
+    .await;
+    assert!(result.is_err());
+
+    // The pool creator's liquidity ATAs are created by the migration itself
+    let pool_creator = pda(&[seeds::POOL_CREATOR, mint.pubkey().as_ref()]);
+    let token_account = get_associated_token_address(&pool_creator, &mint.pubkey());
+    let sol_token_account = get_associated_token_address(&pool_creator, &wsol);
+
+    // Fee-key NFT mint, created by the migration and minted to the treasury (the admin here)
+    let nft_mint = Keypair::new();
+    let admin_nft = get_associated_token_address(&admin, &nft_mint.pubkey());
+
+    // 4. Migrate
+    let reserve_before = get_account(&mut context, sol_reserve).await.lamports;
+    let (token_0_mint, _) = cpmm::sort_mints(mint.pubkey(), wsol);
+    let pool = CpmmAccounts::derive(amm_config, &mint.pubkey(), &pool_creator);
+    let creator_lp_token = pool.creator_lp_token;
+    let raydium_pool = pda(&[seeds::RAYDIUM_POOL, mint.pubkey().as_ref()]);
+    let lp_lock = pda(&[seeds::LP_LOCK, raydium_pool.as_ref()]);
+    let fee_key_nft = pda(&[seeds::NFT_FEE_KEY, raydium_pool.as_ref(), nft_mint.pubkey().as_ref()]);
+
+    let mut migrate_accounts = yozoon::accounts::Migrate {
+        config,
+        bonding_curve,
+        mint: mint.pubkey(),
+        wrapped_sol: wsol,
+        sol_reserve,
+        pool_creator,
+        token_account,
+        sol_token_account,
+        nft_mint: nft_mint.pubkey(),
+        treasury: admin,
+        treasury_nft_account: admin_nft,
+        raydium_pool,
+        lp_lock,
+        fee_key_nft,
+        caller: caller.pubkey(),
+        sol_usd_price,
+        dex_program: mock_amm::ID,
+        system_program: system_program::ID,
+        token_program: spl_token::ID,
+        spl_token_program: spl_token::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+        rent: sysvar::rent::ID,
+    }
+    .to_account_metas(None);
+    // The CPMM adapter reads its accounts from the remaining accounts
+    migrate_accounts.extend(pool.account_metas());
+    let migrate_ix = Instruction {
+        program_id: yozoon::ID,
+        accounts: migrate_accounts,
+        data: yozoon::instruction::Migrate { lp_lock_mode }.data(),
+    };
+
+    // A stale oracle price blocks migration
+    context.set_account(
+        &sol_usd_price,
+        &pyth_sol_usd_account(15_000_000_000, now - MAX_PRICE_STALENESS - 1),
+    );
+    let result = process(&mut context, std::slice::from_ref(&migrate_ix), &[&caller, &nft_mint]).await;
+    assert!(result.is_err());
+
+    // With a fresh price anyone can migrate
+    context.set_account(&sol_usd_price, &pyth_sol_usd_account(15_000_000_000, now));
+    context.last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+    process(&mut context, &[migrate_ix], &[&caller, &nft_mint]).await.unwrap();
+
+    // The treasury holds the only fee key NFT there will ever be
+    let nft = Mint::unpack(&get_account(&mut context, nft_mint.pubkey()).await.data).unwrap();
+    assert_eq!((nft.supply, nft.decimals, nft.mint_authority), (1, 0, COption::None));
+    assert_eq!(token_balance(&mut context, admin_nft).await, 1);
+
+    // Curve is closed to trading and the pool holds the reserves
+    let curve = BondingCurve::try_deserialize(
+        &mut get_account(&mut context, bonding_curve).await.data.as_slice(),
+    )
+    .unwrap();
+    assert!(curve.is_migrated);
+    assert_eq!(curve.state, CurveState::Migrated);
+
+    let record = RaydiumPool::try_deserialize(
+        &mut get_account(&mut context, raydium_pool).await.data.as_slice(),
+    )
+    .unwrap();
+    assert!(record.is_initialized);
+    assert_eq!(record.target, MigrationTargetKind::RaydiumCpmm);
+    assert_eq!(record.pool_state, pool.pool_state);
+    assert_eq!(record.lp_mint, pool.lp_mint);
+    assert_eq!(token_balance(&mut context, record.token_a_account).await, curve.total_sold_supply);
+    assert_eq!(
+        token_balance(&mut context, record.token_b_account).await,
+        curve.total_sol_raised - DEFAULT_MIGRATION_BOUNTY
+    );
+
+    // The reserve paid the pool and the caller's bounty, and the tokens were freshly minted
+    let reserve_after = get_account(&mut context, sol_reserve).await.lamports;
+    assert_eq!(reserve_before - reserve_after, curve.total_sol_raised);
+    assert_eq!(token_balance(&mut context, token_account).await, 0);
+    assert_eq!(token_balance(&mut context, sol_token_account).await, 0);
+    let supply = Mint::unpack(&get_account(&mut context, mint.pubkey()).await.data).unwrap().supply;
+    assert_eq!(supply, 2 * curve.total_sold_supply);
+
+    let mock_pool = mock_amm::PoolState::try_deserialize(
+        &mut get_account(&mut context, pool.pool_state).await.data.as_slice(),
+    )
+    .unwrap();
+    assert_eq!(mock_pool.lp_mint, pool.lp_mint);
+    assert_eq!(mock_pool.locked_lp, mock_amm::LOCKED_LP_AMOUNT);
+
+    // The creator's LP left circulation, provably
+    let creator_lp = mock_pool.lp_supply - mock_amm::LOCKED_LP_AMOUNT;
+    assert_eq!(token_balance(&mut context, creator_lp_token).await, 0);
+    assert_eq!(record.lp_amount, creator_lp);
+    assert_eq!(record.lp_lock_mode, lp_lock_mode);
+    let lp_supply = Mint::unpack(&get_account(&mut context, pool.lp_mint).await.data).unwrap().supply;
+    match lp_lock_mode {
+        LpLockMode::Burn => {
+            assert_eq!(record.lp_lock, Pubkey::default());
+            assert_eq!(lp_supply, 0);
+        }
+        LpLockMode::Lock => {
+            assert_eq!(record.lp_lock, lp_lock);
+            assert_eq!(lp_supply, creator_lp);
+            assert_eq!(token_balance(&mut context, lp_lock).await, creator_lp);
+            let vault = TokenAccount::unpack(&get_account(&mut context, lp_lock).await.data).unwrap();
+            assert_eq!(vault.owner, lp_lock);
+        }
+    }
+
+    // Further buys are rejected (fresh blockhash so this is not the earlier rejected transaction)
+    context.last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let result = process(
+        &mut context,
+        &[buy_instruction(mint.pubkey(), buyer_tokens.pubkey(), buyer.pubkey(), MINIMUM_SOL_PURCHASE)],
+        &[&buyer],
+    )
+    .await;
+    assert!(result.is_err());
+
+    // Finalizing revokes minting and returns the curve and reserve rent to the treasury
+    let finalize_ix = Instruction {
+        program_id: yozoon::ID,
+        accounts: yozoon::accounts::FinalizeMigration {
+            config,
+            bonding_curve,
+            mint: mint.pubkey(),
+            sol_reserve,
+            treasury: admin,
+            caller: caller.pubkey(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: yozoon::instruction::FinalizeMigration {}.data(),
+    };
+    let treasury_before = get_account(&mut context, admin).await.lamports;
+    process(&mut context, &[finalize_ix], &[&caller]).await.unwrap();
+    let mint_state = Mint::unpack(&get_account(&mut context, mint.pubkey()).await.data).unwrap();
+    assert_eq!(mint_state.mint_authority, COption::None);
+    assert_eq!(mint_state.freeze_authority, COption::None);
+    assert!(context.banks_client.get_account(bonding_curve).await.unwrap().is_none());
+    assert!(context.banks_client.get_account(sol_reserve).await.unwrap().is_none());
+    assert!(get_account(&mut context, admin).await.lamports > treasury_before);
+
+    // 5. Trading fees accrue to the LP (simulated by the buyer paying them in)
+    let buyer_wsol = Keypair::new().pubkey();
+    context.set_account(&buyer_wsol, &wrapped_sol_account(buyer.pubkey(), LAMPORTS_PER_SOL));
+    let (token_fees, sol_fees) = (curve.total_sold_supply / 100, LAMPORTS_PER_SOL);
+    let token_is_0 = token_0_mint == mint.pubkey();
+    let ((payer_token_0, amount_0), (payer_token_1, amount_1)) = if token_is_0 {
+        ((buyer_tokens.pubkey(), token_fees), (buyer_wsol, sol_fees))
+    } else {
+        ((buyer_wsol, sol_fees), (buyer_tokens.pubkey(), token_fees))
+    };
+    process(
+        &mut context,
+        &[Instruction {
+            program_id: mock_amm::ID,
+            accounts: mock_amm::accounts::AccrueFees {
+                payer: buyer.pubkey(),
+                pool_state: pool.pool_state,
+                payer_token_0,
+                payer_token_1,
+                token_0_vault: pool.token_0_vault,
+                token_1_vault: pool.token_1_vault,
+                token_program: spl_token::ID,
+            }
+            .to_account_metas(None),
+            data: mock_amm::instruction::AccrueFees { amount_0, amount_1 }.data(),
+        }],
+        &[&buyer],
+    )
+    .await
+    .unwrap();
+
+    // The admin, who is also the treasury, opens accounts for the payout
+    let admin_tokens = Keypair::new();
+    let admin_wsol = Keypair::new().pubkey();
+    create_token_account(&mut context, &admin_tokens, mint.pubkey(), admin).await;
+    context.set_account(&admin_wsol, &wrapped_sol_account(admin, 0));
+
+    let claim_ix = |holder: Pubkey, holder_nft_account: Pubkey, fee_key_nft: Pubkey, holder_token_account: Pubkey, holder_sol_account: Pubkey| Instruction {
+        program_id: yozoon::ID,
+        accounts: yozoon::accounts::ClaimPoolFees {
+            raydium_pool,
+            fee_key_nft,
+            holder_nft_account,
+            holder_token_account,
+            holder_sol_account,
+            holder,
+            mint: mint.pubkey(),
+            wrapped_sol: wsol,
+            fee_vault_token: pda(&[seeds::FEE_VAULT, raydium_pool.as_ref(), mint.pubkey().as_ref()]),
+            fee_vault_sol: pda(&[seeds::FEE_VAULT, raydium_pool.as_ref(), wsol.as_ref()]),
+            lp_lock,
+            pool_authority: pool.authority,
+            pool_state: pool.pool_state,
+            token_vault: record.token_a_account,
+            sol_vault: record.token_b_account,
+            lp_mint: pool.lp_mint,
+            raydium_program: mock_amm::ID,
+            token_program: spl_token::ID,
+            spl_token_program: spl_token::ID,
+            token_2022_program: anchor_spl::token_2022::ID,
+            memo_program: Pubkey::from_str(cpmm::MEMO_PROGRAM_ID).unwrap(),
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        }
+        .to_account_metas(None),
+        data: yozoon::instruction::ClaimPoolFees {}.data(),
+    };
+    let admin_claim_ix = claim_ix(admin, admin_nft, fee_key_nft, admin_tokens.pubkey(), admin_wsol);
+
+    // Burned LP has no position to collect from
+    if lp_lock_mode == LpLockMode::Burn {
+        let result = process(&mut context, std::slice::from_ref(&admin_claim_ix), &[]).await;
+        assert!(result.is_err());
+        return;
+    }
+
+    // Someone without the NFT cannot claim
+    let result = process(
+        &mut context,
+        &[claim_ix(buyer.pubkey(), admin_nft, fee_key_nft, buyer_tokens.pubkey(), buyer_wsol)],
+        &[&buyer],
+    )
+    .await;
+    assert!(result.is_err());
+
+    // The NFT holder receives the reserves withdrawn for the locked LP's fee growth
+    let (vault_0, vault_1) = (
+        token_balance(&mut context, pool.token_0_vault).await,
+        token_balance(&mut context, pool.token_1_vault).await,
+    );
+    let fee_lp = cpmm::fee_growth_lp(creator_lp, creator_lp, mock_pool.lp_supply, vault_0, vault_1).unwrap();
+    assert!(fee_lp > 0);
+    let payout = |reserve: u64| (reserve as u128 * fee_lp as u128 / mock_pool.lp_supply as u128) as u64;
+    let (token_payout, sol_payout) = if token_is_0 {
+        (payout(vault_0), payout(vault_1))
+    } else {
+        (payout(vault_1), payout(vault_0))
+    };
+    process(&mut context, std::slice::from_ref(&admin_claim_ix), &[]).await.unwrap();
+    assert_eq!(token_balance(&mut context, admin_tokens.pubkey()).await, token_payout);
+    assert_eq!(token_balance(&mut context, admin_wsol).await, sol_payout);
+    assert_eq!(token_balance(&mut context, lp_lock).await, creator_lp - fee_lp);
+
+    // What stays locked is the principal: nothing more to withdraw without new fees
+    let mock_pool = mock_amm::PoolState::try_deserialize(
+        &mut get_account(&mut context, pool.pool_state).await.data.as_slice(),
+    )
+    .unwrap();
+    let (vault_0, vault_1) = (
+        token_balance(&mut context, pool.token_0_vault).await,
+        token_balance(&mut context, pool.token_1_vault).await,
+    );
+    assert_eq!(
+        cpmm::fee_growth_lp(creator_lp - fee_lp, creator_lp, mock_pool.lp_supply, vault_0, vault_1),
+        Some(0)
+    );
+
+    let fee_key = FeeKeyNft::try_deserialize(&mut get_account(&mut context, fee_key_nft).await.data.as_slice())
+        .unwrap();
+    assert_eq!(fee_key.owner, admin);
+    assert_eq!(fee_key.fee_percentage, FEE_SHARE_TOTAL_BPS);
+    assert_eq!(fee_key.last_claimed, context.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp);
+
+    // 6. More fees accrue, then the admin splits off 30% into a second fee key NFT and
+    //    hands it to the buyer
+    let sol_fees = LAMPORTS_PER_SOL / 4;
+    context.set_account(&buyer_wsol, &wrapped_sol_account(buyer.pubkey(), sol_fees));
+    let (amount_0, amount_1) = if token_is_0 { (0, sol_fees) } else { (sol_fees, 0) };
+    process(
+        &mut context,
+        &[Instruction {
+            program_id: mock_amm::ID,
+            accounts: mock_amm::accounts::AccrueFees {
+                payer: buyer.pubkey(),
+                pool_state: pool.pool_state,
+                payer_token_0,
+                payer_token_1,
+                token_0_vault: pool.token_0_vault,
+                token_1_vault: pool.token_1_vault,
+                token_program: spl_token::ID,
+            }
+            .to_account_metas(None),
+            data: mock_amm::instruction::AccrueFees { amount_0, amount_1 }.data(),
+        }],
+        &[&buyer],
+    )
+    .await
+    .unwrap();
+    let fees_before_split = total_fees_sol(&mut context, raydium_pool).await;
+
+    let community_mint = Keypair::new();
+    let community_key = pda(&[seeds::NFT_FEE_KEY, raydium_pool.as_ref(), community_mint.pubkey().as_ref()]);
+    let admin_community_nft = get_associated_token_address(&admin, &community_mint.pubkey());
+    process(
+        &mut context,
+        &[Instruction {
+            program_id: yozoon::ID,
+            accounts: yozoon::accounts::SplitFeeKey {
+                raydium_pool,
+                fee_key_nft,
+                holder_nft_account: admin_nft,
+                new_nft_mint: community_mint.pubkey(),
+                new_nft_account: admin_community_nft,
+                new_fee_key_nft: community_key,
+                holder: admin,
+                mint: mint.pubkey(),
+                wrapped_sol: wsol,
+                fee_vault_token: pda(&[seeds::FEE_VAULT, raydium_pool.as_ref(), mint.pubkey().as_ref()]),
+                fee_vault_sol: pda(&[seeds::FEE_VAULT, raydium_pool.as_ref(), wsol.as_ref()]),
+                lp_lock,
+                pool_authority: pool.authority,
+                pool_state: pool.pool_state,
+                token_vault: record.token_a_account,
+                sol_vault: record.token_b_account,
+                lp_mint: pool.lp_mint,
+                raydium_program: mock_amm::ID,
+                token_program: spl_token::ID,
+                spl_token_program: spl_token::ID,
+                token_2022_program: anchor_spl::token_2022::ID,
+                memo_program: Pubkey::from_str(cpmm::MEMO_PROGRAM_ID).unwrap(),
+                system_program: system_program::ID,
+                associated_token_program: anchor_spl::associated_token::ID,
+                rent: sysvar::rent::ID,
+            }
+            .to_account_metas(None),
+            data: yozoon::instruction::SplitFeeKey { share_bps: 3_000 }.data(),
+        }],
+        &[&community_mint],
+    )
+    .await
+    .unwrap();
+    let nft = Mint::unpack(&get_account(&mut context, community_mint.pubkey()).await.data).unwrap();
+    assert_eq!((nft.supply, nft.decimals, nft.mint_authority), (1, 0, COption::None));
+
+    // The split collected the fees earned before it, and they stay with the admin's key
+    let split_collected = total_fees_sol(&mut context, raydium_pool).await - fees_before_split;
+    assert!(split_collected > 0);
+    let fee_key = FeeKeyNft::try_deserialize(&mut get_account(&mut context, fee_key_nft).await.data.as_slice())
+        .unwrap();
+    assert_eq!((fee_key.fee_percentage, fee_key.unclaimed_sol), (7_000, split_collected));
+
+    let buyer_community_nft = Keypair::new();
+    create_token_account(&mut context, &buyer_community_nft, community_mint.pubkey(), buyer.pubkey()).await;
+    process(
+        &mut context,
+        &[spl_token::instruction::transfer(
+            &spl_token::ID,
+            &admin_community_nft,
+            &buyer_community_nft.pubkey(),
+            &admin,
+            &[],
+            1,
+        )
+        .unwrap()],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    // New fees are shared 70/30 between the two NFT holders
+    let sol_fees = LAMPORTS_PER_SOL / 2;
+    context.set_account(&buyer_wsol, &wrapped_sol_account(buyer.pubkey(), sol_fees));
+    let (amount_0, amount_1) = if token_is_0 { (0, sol_fees) } else { (sol_fees, 0) };
+    process(
+        &mut context,
+        &[Instruction {
+            program_id: mock_amm::ID,
+            accounts: mock_amm::accounts::AccrueFees {
+                payer: buyer.pubkey(),
+                pool_state: pool.pool_state,
+                payer_token_0,
+                payer_token_1,
+                token_0_vault: pool.token_0_vault,
+                token_1_vault: pool.token_1_vault,
+                token_program: spl_token::ID,
+            }
+            .to_account_metas(None),
+            data: mock_amm::instruction::AccrueFees { amount_0, amount_1 }.data(),
+        }],
+        &[&buyer],
+    )
+    .await
+    .unwrap();
+
+    // Each key's share is of the fees collected since the split, as of its own claim;
+    // the admin also receives what the split collected
+    let admin_wsol_before = token_balance(&mut context, admin_wsol).await;
+    let fees_at_split = total_fees_sol(&mut context, raydium_pool).await;
+    context.last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+    process(&mut context, &[admin_claim_ix], &[]).await.unwrap();
+    let collected = total_fees_sol(&mut context, raydium_pool).await - fees_at_split;
+    assert!(collected > 0);
+    assert_eq!(
+        token_balance(&mut context, admin_wsol).await - admin_wsol_before,
+        split_collected + collected * 7_000 / 10_000
+    );
+
+    process(
+        &mut context,
+        &[claim_ix(
+            buyer.pubkey(),
+            buyer_community_nft.pubkey(),
+            community_key,
+            buyer_tokens.pubkey(),
+            buyer_wsol,
+        )],
+        &[&buyer],
+    )
+    .await
+    .unwrap();
+    let collected = total_fees_sol(&mut context, raydium_pool).await - fees_at_split;
+    assert_eq!(token_balance(&mut context, buyer_wsol).await, collected * 3_000 / 10_000);
+
+    let community = FeeKeyNft::try_deserialize(&mut get_account(&mut context, community_key).await.data.as_slice())
+        .unwrap();
+    assert_eq!(community.owner, buyer.pubkey());
+    assert_eq!(community.fee_percentage, 3_000);
+}
@@ -4,264 +4,321 @@ import * as chai from "chai";
 
 // Temporary workaround until build issues are resolved
 type Yozoon = any;
-import { PublicKey, Keypair, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import { PublicKey, Keypair, SystemProgram, SYSVAR_RENT_PUBKEY, AccountMeta, BPF_LOADER_UPGRADEABLE_PROGRAM_ID } from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount
+} from "@solana/spl-token";
+
+const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+const RAYDIUM_CPMM_PROGRAM_ID = new PublicKey("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
+const RAYDIUM_CPMM_CREATE_POOL_FEE_RECEIVER = new PublicKey("DNXgeM9EiiaAbaWvwjHj9fQQLAX5ZsfHyvmYUNRAdNC8");
+const WRAPPED_SOL_MINT = new PublicKey("So11111111111111111111111111111111111111112");
+
+// Migration reads a live Pyth SOL/USD price and creates a real CPMM pool, so it only
+// runs against a cluster that has both, e.g. devnet:
+//   YOZOON_SOL_USD_FEED=<Pyth SOL/USD account> YOZOON_CPMM_AMM_CONFIG=<CPMM fee tier> anchor test
+const SOL_USD_FEED = process.env.YOZOON_SOL_USD_FEED;
+const CPMM_AMM_CONFIG = process.env.YOZOON_CPMM_AMM_CONFIG;
 
 describe("Yozoon Raydium Migration", () => {
   // Set up Anchor provider and program
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
   const program = anchor.workspace.Yozoon as Program<Yozoon>;
-  
+
   // Main accounts
   const admin = provider.wallet.publicKey;
-  let mint: PublicKey = null;
+  const mintKeypair = Keypair.generate();
+  const mint = mintKeypair.publicKey;
+  let launchpadPda: PublicKey = null;
   let configPda: PublicKey = null;
-  let configBump: number = null;
   let bondingCurvePda: PublicKey = null;
-  
+  let priceObservationsPda: PublicKey = null;
+  let solReservePda: PublicKey = null;
+
   // Raydium migration accounts
+  let poolCreatorPda: PublicKey = null;
   let raydiumPoolPda: PublicKey = null;
-  let feeKeyNftPda: PublicKey = null;
-  let lp_mint: Keypair = null;
-  let tokenPoolAccount: PublicKey = null;
-  let solPoolAccount: PublicKey = null;
-  let feeAccount: PublicKey = null;
+  let lpLockPda: PublicKey = null;
   let nftMint: Keypair = null;
-  
-  // Migration state
-  let migrationReady = false;
-  
+
+  // Curve that migrates once 1 SOL is raised
+  const solMax = new anchor.BN(anchor.web3.LAMPORTS_PER_SOL);
+  const buyer = Keypair.generate();
+  let buyerTokenAccount: PublicKey = null;
+
+  const pda = (seeds: Buffer[]) => PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+
+  function buyAccounts() {
+    return {
+      config: configPda,
+      bondingCurve: bondingCurvePda,
+      priceObservations: priceObservationsPda,
+      mint,
+      buyerTokenAccount,
+      buyer: buyer.publicKey,
+      solReserve: solReservePda,
+      referral: null,
+      referrer: null,
+      solUsdPrice: null,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    };
+  }
+
   before(async () => {
-    // Derive PDAs
-    [configPda, configBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("config")],
-      program.programId
-    );
-    
-    [bondingCurvePda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("bonding_curve")],
-      program.programId
-    );
-    
+    // Derive PDAs; everything but the launchpad is per launch, keyed by the mint
+    launchpadPda = pda([Buffer.from("launchpad")]);
+    configPda = pda([Buffer.from("config"), mint.toBuffer()]);
+    bondingCurvePda = pda([Buffer.from("bonding_curve"), mint.toBuffer()]);
+    priceObservationsPda = pda([Buffer.from("price_observations"), mint.toBuffer()]);
+    solReservePda = pda([Buffer.from("sol_reserve"), mint.toBuffer()]);
+
     console.log("PDAs:");
     console.log("- Config:", configPda.toBase58());
     console.log("- Bonding Curve:", bondingCurvePda.toBase58());
-    
-    // Create the mint keypair for initialization
-    const mintKeypair = Keypair.generate();
-    mint = mintKeypair.publicKey;
-    
-    // Initialize migration accounts
-    lp_mint = Keypair.generate();
-    nftMint = Keypair.generate();
-    
-    // Create the token pool account
-    tokenPoolAccount = await getOrCreateAssociatedTokenAccount(
-      provider.connection,
-      // Cast wallet to any to access payer property
-      Keypair.fromSecretKey((provider.wallet as any).payer.secretKey),
-      mint,
-      configPda,
-      true
-    ).then(account => account.address);
-    
-    // Create the SOL pool account
-    const wrappedSolMint = new PublicKey("So11111111111111111111111111111111111111112");
-    solPoolAccount = await getOrCreateAssociatedTokenAccount(
-      provider.connection,
-      // Cast wallet to any to access payer property
-      Keypair.fromSecretKey((provider.wallet as any).payer.secretKey),
-      wrappedSolMint,
-      configPda,
-      true
-    ).then(account => account.address);
-    
-    // Create the fee account
-    feeAccount = await getOrCreateAssociatedTokenAccount(
-      provider.connection,
-      // Cast wallet to any to access payer property
-      Keypair.fromSecretKey((provider.wallet as any).payer.secretKey),
-      mint,
-      configPda,
-      true
-    ).then(account => account.address);
-    
+
     // Calculate PDAs for Raydium integration
-    [raydiumPoolPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("raydium_pool"), mint.toBuffer()],
-      program.programId
-    );
-    
-    [feeKeyNftPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("nft_fee_key"), raydiumPoolPda.toBuffer()],
-      program.programId
-    );
-    
+    poolCreatorPda = pda([Buffer.from("pool_creator"), mint.toBuffer()]);
+    raydiumPoolPda = pda([Buffer.from("raydium_pool"), mint.toBuffer()]);
+    lpLockPda = pda([Buffer.from("lp_lock"), raydiumPoolPda.toBuffer()]);
+    nftMint = Keypair.generate();
+
     console.log("Raydium Integration PDAs:");
+    console.log("- Pool Creator:", poolCreatorPda.toBase58());
     console.log("- Raydium Pool:", raydiumPoolPda.toBase58());
-    console.log("- Fee Key NFT:", feeKeyNftPda.toBase58());
-    
+
+    // The launchpad is shared by every launch, so another test file may have created it
+    if ((await provider.connection.getAccountInfo(launchpadPda)) === null) {
+      await program.methods
+        .initializeLaunchpad(new anchor.BN(0))
+        .accounts({
+          launchpad: launchpadPda,
+          authority: admin,
+          program: program.programId,
+          programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], BPF_LOADER_UPGRADEABLE_PROGRAM_ID)[0],
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+    const launchpad = await program.account.launchpad.fetch(launchpadPda);
+
     // Initialize mint
+    const [metadataPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+      TOKEN_METADATA_PROGRAM_ID
+    );
     await program.methods
-      .initializeMint()
+      .initializeMint(
+        "Yozoon",
+        "YOZ",
+        "https://yozoon.io/token.json",
+        9,
+        new anchor.BN("1000000000000000000"),
+        { transferFee: null, metadataPointer: false, nonTransferableUntilMigration: false }
+      )
       .accounts({
+        launchpad: launchpadPda,
+        feeRecipient: launchpad.feeRecipient,
         config: configPda,
-        mint: mintKeypair.publicKey,
+        mint,
+        metadata: metadataPda,
         admin,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
         rent: SYSVAR_RENT_PUBKEY,
       })
       .signers([mintKeypair])
       .rpc();
-      
+
     console.log("Mint initialized with address:", mint.toBase58());
-    
-    // Initialize bonding curve
+
+    // Initialize bonding curve with a small migration window so the test can reach it
     const pricePoints = [
       new anchor.BN(10_000_000), // 0.01 SOL per token at 0 supply
-      new anchor.BN(15_000_000), // 0.015 SOL per token at 25% supply
-      new anchor.BN(25_000_000), // 0.025 SOL per token at 50% supply
-      new anchor.BN(50_000_000), // 0.05 SOL per token at 75% supply
-      new anchor.BN(100_000_000)  // 0.1 SOL per token at max supply
+      new anchor.BN(20_000_000)  // 0.02 SOL per token at max supply
     ];
-    
+    const thresholds = {
+      solMin: solMax,
+      solMax,
+      supplyThreshold: new anchor.BN(0),
+      usdMin: new anchor.BN(0),
+    };
+
     await program.methods
-      .initializeBondingCurve(pricePoints)
+      .initializeBondingCurve(pricePoints, thresholds, { raydiumCpmm: {} }, new anchor.BN(0))
       .accounts({
+        config: configPda,
         bondingCurve: bondingCurvePda,
+        priceObservations: priceObservationsPda,
+        solReserve: solReservePda,
         admin,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
-      
+
     console.log("Bonding curve initialized");
+
+    // Fund the buyer and create its token account
+    const signature = await provider.connection.requestAirdrop(buyer.publicKey, 3 * anchor.web3.LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(signature);
+    buyerTokenAccount = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      // Cast wallet to any to access payer property
+      Keypair.fromSecretKey((provider.wallet as any).payer.secretKey),
+      mint,
+      buyer.publicKey
+    ).then(account => account.address);
   });
-  
-  it("Monitors migration threshold", async () => {
-    console.log("Checking migration threshold monitoring");
-    
-    // Call the check_migration_threshold endpoint
+
+  it("Keeps trading below the migration threshold", async () => {
     await program.methods
-      .checkMigrationThreshold()
-      .accounts({
-        bondingCurve: bondingCurvePda,
-      })
+      .buyTokens(new anchor.BN(0.1 * anchor.web3.LAMPORTS_PER_SOL))
+      .accounts(buyAccounts())
+      .signers([buyer])
       .rpc();
-    
-    // Get the bonding curve state
+
     const bondingCurveAccount = await program.account.bondingCurve.fetch(bondingCurvePda);
-    console.log("Current SOL raised:", (bondingCurveAccount as any).totalSolRaised.toString());
-    
-    // Migration is not ready yet as no SOL has been raised
-    migrationReady = false;
-    chai.expect((bondingCurveAccount as any).isMigrated).to.equal(false);
+    console.log("Current SOL raised:", bondingCurveAccount.totalSolRaised.toString());
+
+    chai.expect(bondingCurveAccount.state).to.deep.equal({ trading: {} });
   });
-  
-  it("Simulates reaching the migration threshold", async () => {
-    // We can't actually buy tokens until we reach the threshold in a real scenario,
-    // but for testing purposes, we'll directly modify the bonding curve state
-    console.log("Simulating reaching the migration threshold");
-    
-    // Mock reaching the migration threshold by setting the raised amount
-    // This would require direct manipulation of the contract state, not possible in a real scenario
-    console.log("In a real scenario, users would buy tokens until the threshold is reached");
-    console.log("Migration threshold: 60,000-63,000 SOL");
-    
-    migrationReady = true;
+
+  it("Becomes ready to migrate once sol_max is raised", async () => {
+    // Only the part up to sol_max is filled; the rest never leaves the buyer
+    const balanceBefore = await provider.connection.getBalance(buyer.publicKey);
+
+    await program.methods
+      .buyTokens(new anchor.BN(2 * anchor.web3.LAMPORTS_PER_SOL))
+      .accounts(buyAccounts())
+      .signers([buyer])
+      .rpc();
+
+    const balanceAfter = await provider.connection.getBalance(buyer.publicKey);
+    const bondingCurveAccount = await program.account.bondingCurve.fetch(bondingCurvePda);
+    console.log("SOL raised:", bondingCurveAccount.totalSolRaised.toString());
+
+    chai.expect(bondingCurveAccount.state).to.deep.equal({ readyToMigrate: {} });
+    chai.expect(bondingCurveAccount.totalSolRaised.toString()).to.equal(solMax.toString());
+    chai.expect(bondingCurveAccount.readySince.toNumber()).to.be.greaterThan(0);
+    chai.expect(balanceBefore - balanceAfter).to.be.lessThan(anchor.web3.LAMPORTS_PER_SOL);
+  });
+
+  it("Freezes trading while migration is pending", async () => {
+    try {
+      await program.methods
+        .buyTokens(new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL))
+        .accounts(buyAccounts())
+        .signers([buyer])
+        .rpc();
+      chai.expect.fail("Should not be able to buy while migration is pending");
+    } catch (error) {
+      chai.expect(error.toString()).to.include("MigrationPending");
+    }
   });
-  
-  it("Migrates to Raydium and locks liquidity permanently", async () => {
-    // Skip if migration is not ready
-    if (!migrationReady) {
-      console.log("Skipping migration test as threshold not reached");
+
+  it("Migrates to Raydium CPMM and locks liquidity", async function() {
+    if (!SOL_USD_FEED || !CPMM_AMM_CONFIG) {
+      console.log("Skipping migration: set YOZOON_SOL_USD_FEED and YOZOON_CPMM_AMM_CONFIG");
+      this.skip();
       return;
     }
-    
+    const solUsdPrice = new PublicKey(SOL_USD_FEED);
+    const ammConfig = new PublicKey(CPMM_AMM_CONFIG);
+
+    // The feed must be on the launch's oracle allowlist
+    await program.methods
+      .setOracleFeeds([solUsdPrice])
+      .accounts({
+        config: configPda,
+        admin,
+      })
+      .rpc();
+
+    // CPMM pool accounts, in the order `migrate` expects them
+    const cpmm = (seeds: Buffer[]) => PublicKey.findProgramAddressSync(seeds, RAYDIUM_CPMM_PROGRAM_ID)[0];
+    const [token0Mint, token1Mint] = Buffer.compare(mint.toBuffer(), WRAPPED_SOL_MINT.toBuffer()) < 0
+      ? [mint, WRAPPED_SOL_MINT]
+      : [WRAPPED_SOL_MINT, mint];
+    const poolState = cpmm([Buffer.from("pool"), ammConfig.toBuffer(), token0Mint.toBuffer(), token1Mint.toBuffer()]);
+    const lpMint = cpmm([Buffer.from("pool_lp_mint"), poolState.toBuffer()]);
+    const writable = (pubkey: PublicKey): AccountMeta => ({ pubkey, isSigner: false, isWritable: true });
+    const dexAccounts: AccountMeta[] = [
+      { pubkey: ammConfig, isSigner: false, isWritable: false },
+      { pubkey: cpmm([Buffer.from("vault_and_lp_mint_auth_seed")]), isSigner: false, isWritable: false },
+      writable(poolState),
+      writable(lpMint),
+      writable(getAssociatedTokenAddressSync(lpMint, poolCreatorPda, true)),
+      writable(cpmm([Buffer.from("pool_vault"), poolState.toBuffer(), token0Mint.toBuffer()])),
+      writable(cpmm([Buffer.from("pool_vault"), poolState.toBuffer(), token1Mint.toBuffer()])),
+      writable(cpmm([Buffer.from("observation"), poolState.toBuffer()])),
+      writable(RAYDIUM_CPMM_CREATE_POOL_FEE_RECEIVER),
+    ];
+
+    // Anyone may trigger the migration; the fee key NFT goes to the treasury
+    const treasury = admin;
+    await program.methods
+      .migrate({ lock: {} })
+      .accounts({
+        config: configPda,
+        bondingCurve: bondingCurvePda,
+        mint,
+        wrappedSol: WRAPPED_SOL_MINT,
+        solReserve: solReservePda,
+        poolCreator: poolCreatorPda,
+        tokenAccount: getAssociatedTokenAddressSync(mint, poolCreatorPda, true),
+        solTokenAccount: getAssociatedTokenAddressSync(WRAPPED_SOL_MINT, poolCreatorPda, true),
+        nftMint: nftMint.publicKey,
+        treasury,
+        treasuryNftAccount: getAssociatedTokenAddressSync(nftMint.publicKey, treasury),
+        raydiumPool: raydiumPoolPda,
+        lpLock: lpLockPda,
+        feeKeyNft: pda([Buffer.from("nft_fee_key"), raydiumPoolPda.toBuffer(), nftMint.publicKey.toBuffer()]),
+        caller: admin,
+        solUsdPrice,
+        dexProgram: RAYDIUM_CPMM_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        splTokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .remainingAccounts(dexAccounts)
+      .signers([nftMint])
+      .preInstructions([anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 })])
+      .rpc();
+
+    // Verify migration state
+    const bondingCurveAccount = await program.account.bondingCurve.fetch(bondingCurvePda);
+    chai.expect(bondingCurveAccount.state).to.deep.equal({ migrated: {} });
+
+    const raydiumPoolAccount = await program.account.raydiumPool.fetch(raydiumPoolPda);
+    console.log("Pool state:", raydiumPoolAccount.poolState.toBase58());
+    console.log("LP token mint:", raydiumPoolAccount.lpMint.toBase58());
+    chai.expect(raydiumPoolAccount.poolState.toBase58()).to.equal(poolState.toBase58());
+    chai.expect(raydiumPoolAccount.lpLockMode).to.deep.equal({ lock: {} });
+
+    // The treasury holds the fee key NFT
+    const nftBalance = await provider.connection.getTokenAccountBalance(
+      getAssociatedTokenAddressSync(nftMint.publicKey, treasury)
+    );
+    chai.expect(nftBalance.value.amount).to.equal("1");
+
+    // Try to buy tokens after migration (should fail)
     try {
-      console.log("Executing migration to Raydium");
-      
-      // Mock the migration with simulated accounts
-      // In a real scenario, the on-chain validation would ensure the threshold is met
       await program.methods
-        .migrateToRaydium()
-        .accounts({
-          config: configPda,
-          bondingCurve: bondingCurvePda,
-          mint: mint,
-          wrappedSol: new PublicKey("So11111111111111111111111111111111111111112"),
-          treasury: admin,
-          tokenAccount: tokenPoolAccount,
-          solTokenAccount: solPoolAccount,
-          lpMint: lp_mint.publicKey,
-          feeAccount: feeAccount,
-          nftMint: nftMint.publicKey,
-          raydiumPool: raydiumPoolPda,
-          feeKeyNft: feeKeyNftPda,
-          admin: admin,
-          raydiumProgram: new PublicKey("SwaPpA9LAaLfeLi3a68M4DjnLqgtticKg6CnyNwgAC8"),
-          feeKeyProgram: new PublicKey("FeeKedCBd6AvpXjWUFLa8rZwJTASXTJXwSK89JS6QFmQ"),
-          systemProgram: SystemProgram.programId,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          rent: SYSVAR_RENT_PUBKEY,
-        })
-        .signers([lp_mint, nftMint])
+        .buyTokens(new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL))
+        .accounts(buyAccounts())
+        .signers([buyer])
         .rpc();
-      
-      // Verify migration state
-      const bondingCurveAccount = await program.account.bondingCurve.fetch(bondingCurvePda);
-      chai.expect((bondingCurveAccount as any).isMigrated).to.equal(true);
-      
-      console.log("Migration to Raydium completed successfully");
-      
-      // Try to fetch the created Raydium pool
-      try {
-        const raydiumPoolAccount = await program.account.raydiumPool.fetch(raydiumPoolPda);
-        console.log("Raydium pool created successfully with token mint:", (raydiumPoolAccount as any).tokenAMint.toBase58());
-        console.log("LP token mint:", (raydiumPoolAccount as any).lpMint.toBase58());
-      } catch (error) {
-        console.log("Note: In this test, we're just simulating the Raydium integration");
-        console.log("In a real deployment, the Raydium pool account would be created");
-      }
-      
-      // Try to buy tokens after migration (should fail)
-      try {
-        const buyer = Keypair.generate();
-        await provider.connection.requestAirdrop(buyer.publicKey, 1 * anchor.web3.LAMPORTS_PER_SOL);
-        
-        const buyerTokenAccount = await getOrCreateAssociatedTokenAccount(
-          provider.connection,
-          // Cast wallet to any to access payer property
-          Keypair.fromSecretKey((provider.wallet as any).payer.secretKey),
-          mint,
-          buyer.publicKey
-        ).then(account => account.address);
-        
-        await program.methods
-          .buyTokens(new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL))
-          .accounts({
-            config: configPda,
-            bondingCurve: bondingCurvePda,
-            mint,
-            buyerTokenAccount: buyerTokenAccount,
-            buyer: buyer.publicKey,
-            treasury: admin,
-            systemProgram: SystemProgram.programId,
-            tokenProgram: TOKEN_PROGRAM_ID,
-          })
-          .signers([buyer])
-          .rpc();
-        
-        // If we reach here, the buy tokens call didn't throw an error, which is bad
-        chai.expect.fail("Should not be able to buy tokens after migration");
-      } catch (error) {
-        // Expected error
-        console.log("Successfully blocked token purchase after migration");
-      }
+      chai.expect.fail("Should not be able to buy tokens after migration");
     } catch (error) {
-      console.error("Migration test failed with error:", error);
-      throw error;
+      chai.expect(error.toString()).to.include("Migrated");
+      console.log("Successfully blocked token purchase after migration");
     }
   });
-}); 
\ No newline at end of file
+});
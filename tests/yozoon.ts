@@ -5,68 +5,81 @@ import * as chai from "chai";
 // Temporary workaround until build issues are resolved
 // This type definition substitutes the auto-generated one
 type Yozoon = any;
-import { PublicKey, Keypair, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js";
+import { PublicKey, Keypair, SystemProgram, SYSVAR_RENT_PUBKEY, BPF_LOADER_UPGRADEABLE_PROGRAM_ID } from "@solana/web3.js";
 import { TOKEN_PROGRAM_ID, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
 
+const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+const PAUSE_BUY = 1 << 0;
+
 describe("Yozoon", () => {
   // Set up Anchor provider and program
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
   // @ts-ignore - Using any type as a workaround for missing generated types
 const program = anchor.workspace.Yozoon as Program<Yozoon>;
-  
+
   // Main accounts
   const admin = provider.wallet.publicKey;
-  let mint: PublicKey = null;
+  const mintKeypair = Keypair.generate();
+  const mint = mintKeypair.publicKey;
+  let launchpadPda: PublicKey = null;
   let configPda: PublicKey = null;
-  let configBump: number = null;
   let bondingCurvePda: PublicKey = null;
-  
+  let priceObservationsPda: PublicKey = null;
+  let solReservePda: PublicKey = null;
+
   // Test accounts
   const newAdmin = Keypair.generate();
   const user = Keypair.generate();
   const referrer = Keypair.generate();
-  
+
   // Test states
   let userTokenAccount: PublicKey = null;
   let referrerTokenAccount: PublicKey = null;
-  let adminTokenAccount: PublicKey = null;
   let userReferralPda: PublicKey = null;
-  let bondingCurveInitialized = false;
-  
+
+  const pda = (seeds: Buffer[]) => PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+
   before(async () => {
     // Fund test accounts
     await fundAccount(newAdmin.publicKey, 0.1);
     await fundAccount(user.publicKey, 1); // More SOL for buying tokens
     await fundAccount(referrer.publicKey, 0.1);
-    
+
     console.log("Funded test accounts:");
     console.log("- New admin:", newAdmin.publicKey.toBase58());
     console.log("- User:", user.publicKey.toBase58());
     console.log("- Referrer:", referrer.publicKey.toBase58());
-    
-    // Derive PDAs
-    [configPda, configBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("config")],
-      program.programId
-    );
-    
-    [bondingCurvePda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("bonding_curve")],
-      program.programId
-    );
-    
-    [userReferralPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("referral"), user.publicKey.toBuffer()],
-      program.programId
-    );
-    
+
+    // Derive PDAs; everything but the launchpad is per launch, keyed by the mint
+    launchpadPda = pda([Buffer.from("launchpad")]);
+    configPda = pda([Buffer.from("config"), mint.toBuffer()]);
+    bondingCurvePda = pda([Buffer.from("bonding_curve"), mint.toBuffer()]);
+    priceObservationsPda = pda([Buffer.from("price_observations"), mint.toBuffer()]);
+    solReservePda = pda([Buffer.from("sol_reserve"), mint.toBuffer()]);
+    userReferralPda = pda([Buffer.from("referral"), mint.toBuffer(), user.publicKey.toBuffer()]);
+
     console.log("PDAs:");
+    console.log("- Launchpad:", launchpadPda.toBase58());
     console.log("- Config:", configPda.toBase58());
     console.log("- Bonding Curve:", bondingCurvePda.toBase58());
     console.log("- User Referral:", userReferralPda.toBase58());
+
+    // The launchpad is shared by every launch, so another test file may have created it
+    if ((await provider.connection.getAccountInfo(launchpadPda)) === null) {
+      await program.methods
+        .initializeLaunchpad(new anchor.BN(0))
+        .accounts({
+          launchpad: launchpadPda,
+          authority: admin,
+          program: program.programId,
+          programData: PublicKey.findProgramAddressSync([program.programId.toBuffer()], BPF_LOADER_UPGRADEABLE_PROGRAM_ID)[0],
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
   });
-  
+
   async function fundAccount(address: PublicKey, solAmount: number) {
     const signature = await provider.connection.requestAirdrop(
       address,
@@ -74,69 +87,133 @@ const program = anchor.workspace.Yozoon as Program<Yozoon>;
     );
     await provider.connection.confirmTransaction(signature);
   }
-  
+
+  function buyAccounts(withReferral: boolean) {
+    return {
+      config: configPda,
+      bondingCurve: bondingCurvePda,
+      priceObservations: priceObservationsPda,
+      mint,
+      buyerTokenAccount: userTokenAccount,
+      buyer: user.publicKey,
+      solReserve: solReservePda,
+      referral: withReferral ? userReferralPda : null,
+      referrer: withReferral ? referrer.publicKey : null,
+      solUsdPrice: null,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    };
+  }
+
   it("Initializes mint and config", async () => {
-    // Create the mint keypair
-    const mintKeypair = Keypair.generate();
-    mint = mintKeypair.publicKey;
-    
-    // Initialize mint through program
+    const launchpad = await program.account.launchpad.fetch(launchpadPda);
+    const [metadataPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+      TOKEN_METADATA_PROGRAM_ID
+    );
+
+    // Create the launch through the launchpad
     await program.methods
-      .initializeMint()
+      .initializeMint(
+        "Yozoon",
+        "YOZ",
+        "https://yozoon.io/token.json",
+        9,
+        new anchor.BN("1000000000000000000"),
+        { transferFee: null, metadataPointer: false, nonTransferableUntilMigration: false }
+      )
       .accounts({
+        launchpad: launchpadPda,
+        feeRecipient: launchpad.feeRecipient,
         config: configPda,
-        mint: mintKeypair.publicKey,
+        mint,
+        metadata: metadataPda,
         admin,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
         rent: SYSVAR_RENT_PUBKEY,
       })
       .signers([mintKeypair])
       .rpc();
-      
+
     console.log("Mint and config initialized");
     console.log("Mint address:", mint.toBase58());
-    
+
     // Verify the config account
     const configAccount = await program.account.config.fetch(configPda);
     console.log("Admin:", configAccount.admin.toBase58());
-    console.log("Total supply:", configAccount.totalSupply.toString());
-    console.log("Paused:", configAccount.paused);
-    
+    console.log("Max supply:", configAccount.maxSupply.toString());
+    console.log("Pause flags:", configAccount.pauseFlags);
+
     // Assertions
     chai.expect(configAccount.admin.toBase58()).to.equal(admin.toBase58());
-    chai.expect(configAccount.paused).to.equal(false);
-    
+    chai.expect(configAccount.mint.toBase58()).to.equal(mint.toBase58());
+    chai.expect(configAccount.treasury.toBase58()).to.equal(admin.toBase58());
+    chai.expect(configAccount.pauseFlags).to.equal(0);
+
     // Create token accounts for tests
-    adminTokenAccount = await getOrCreateAssociatedTokenAccount(
-      provider.connection,
-      Keypair.fromSecretKey(provider.wallet.payer.secretKey),
-      mint,
-      admin
-    ).then(account => account.address);
-    
     userTokenAccount = await getOrCreateAssociatedTokenAccount(
       provider.connection,
       Keypair.fromSecretKey(provider.wallet.payer.secretKey),
       mint,
       user.publicKey
     ).then(account => account.address);
-    
+
     referrerTokenAccount = await getOrCreateAssociatedTokenAccount(
       provider.connection,
       Keypair.fromSecretKey(provider.wallet.payer.secretKey),
       mint,
       referrer.publicKey
     ).then(account => account.address);
-    
+
     console.log("Token accounts created:");
-    console.log("- Admin:", adminTokenAccount.toBase58());
     console.log("- User:", userTokenAccount.toBase58());
     console.log("- Referrer:", referrerTokenAccount.toBase58());
   });
 
-  it("Transfers admin role", async () => {
-    // Transfer admin to new wallet
+  it("Initializes bonding curve", async () => {
+    // Define price points (in lamports per token), spread evenly over the max supply
+    const pricePoints = [
+      new anchor.BN(10_000_000), // 0.01 SOL per token at 0 supply
+      new anchor.BN(15_000_000), // 0.015 SOL per token at 25% supply
+      new anchor.BN(25_000_000), // 0.025 SOL per token at 50% supply
+      new anchor.BN(50_000_000), // 0.05 SOL per token at 75% supply
+      new anchor.BN(100_000_000)  // 0.1 SOL per token at max supply
+    ];
+    const thresholds = {
+      solMin: new anchor.BN(60_000 * anchor.web3.LAMPORTS_PER_SOL),
+      solMax: new anchor.BN(63_000 * anchor.web3.LAMPORTS_PER_SOL),
+      supplyThreshold: new anchor.BN(1_000_000_000),
+      usdMin: new anchor.BN(0),
+    };
+
+    await program.methods
+      .initializeBondingCurve(pricePoints, thresholds, { raydiumCpmm: {} }, new anchor.BN(0))
+      .accounts({
+        config: configPda,
+        bondingCurve: bondingCurvePda,
+        priceObservations: priceObservationsPda,
+        solReserve: solReservePda,
+        admin,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // Verify the bonding curve account
+    const bondingCurveAccount = await program.account.bondingCurve.fetch(bondingCurvePda);
+    console.log("Bonding curve initialized with", bondingCurveAccount.pricePoints.length, "price points");
+    console.log("Price points:", bondingCurveAccount.pricePoints.map(p => p.toString()));
+
+    // Assertions
+    chai.expect(bondingCurveAccount.pricePoints.length).to.equal(pricePoints.length);
+    chai.expect(bondingCurveAccount.totalSolRaised.toString()).to.equal("0");
+    chai.expect(bondingCurveAccount.totalSoldSupply.toString()).to.equal("0");
+    chai.expect(bondingCurveAccount.state).to.deep.equal({ trading: {} });
+  });
+
+  it("Nominates and cancels an admin transfer", async () => {
+    // Nominate a new admin; the role only moves once they accept
     await program.methods
       .transferAdmin(newAdmin.publicKey)
       .accounts({
@@ -144,62 +221,84 @@ const program = anchor.workspace.Yozoon as Program<Yozoon>;
         admin,
       })
       .rpc();
-      
+
     console.log("Admin transfer initiated to:", newAdmin.publicKey.toBase58());
-    
+
     // Verify pending admin
-    const configAccount = await program.account.config.fetch(configPda);
+    let configAccount = await program.account.config.fetch(configPda);
     console.log("Current admin:", configAccount.admin.toBase58());
     console.log("Pending admin:", configAccount.pendingAdmin.toBase58());
-    
-    // Assertions
+
     chai.expect(configAccount.admin.toBase58()).to.equal(admin.toBase58());
     chai.expect(configAccount.pendingAdmin.toBase58()).to.equal(newAdmin.publicKey.toBase58());
+
+    // Withdraw the nomination so the remaining tests keep the current admin
+    await program.methods
+      .cancelAdminTransfer()
+      .accounts({
+        config: configPda,
+        admin,
+      })
+      .rpc();
+
+    configAccount = await program.account.config.fetch(configPda);
+    chai.expect(configAccount.pendingAdmin).to.equal(null);
+
+    // The nominee can no longer accept
+    try {
+      await program.methods
+        .acceptAdmin()
+        .accounts({
+          config: configPda,
+          pendingAdmin: newAdmin.publicKey,
+        })
+        .signers([newAdmin])
+        .rpc();
+      chai.expect.fail("Cancelled nominee should not be able to accept");
+    } catch (error) {
+      chai.expect(error.toString()).to.include("Unauthorized");
+    }
   });
-  
+
   it("Sets up referral", async () => {
-    // Set up referral with a 10% fee (1000 basis points)
-    const feePercentage = new anchor.BN(1000); // 10%
-    
     await program.methods
-      .setReferral(referrer.publicKey, feePercentage)
+      .setReferral(referrer.publicKey)
       .accounts({
+        config: configPda,
         referral: userReferralPda,
         user: user.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .signers([user])
       .rpc();
-      
+
     // Verify the referral account
     const referralAccount = await program.account.referral.fetch(userReferralPda);
     console.log("Referral set up for user", user.publicKey.toBase58());
     console.log("Referrer:", referralAccount.referrer.toBase58());
     console.log("Fee percentage:", referralAccount.feePercentage.toString(), "basis points");
-    
-    // Assertions
+
+    // Assertions: new referrals start at the default 1% fee
     chai.expect(referralAccount.referrer.toBase58()).to.equal(referrer.publicKey.toBase58());
-    chai.expect(referralAccount.feePercentage.toString()).to.equal(feePercentage.toString());
+    chai.expect(referralAccount.mint.toBase58()).to.equal(mint.toBase58());
+    chai.expect(referralAccount.feePercentage.toString()).to.equal("100");
   });
-  
+
   it("Admin airdrops tokens", async () => {
-    // Create airdrop ledger PDA
-    const [airdropLedgerPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("airdrop_ledger")],
-      program.programId
-    );
-    
+    const airdropLedgerPda = pda([Buffer.from("airdrop_ledger"), mint.toBuffer()]);
+
     const airdropAmount = new anchor.BN(1_000_000_000); // 1 token with 9 decimals
-    
+
     // Get initial token balance
     const initialTokenBalance = await provider.connection.getTokenAccountBalance(referrerTokenAccount);
     console.log("Referrer initial token balance:", initialTokenBalance.value.uiAmount);
-    
+
     await program.methods
       .airdropTokens(airdropAmount)
-        .accounts({
+      .accounts({
         config: configPda,
         admin,
+        bondingCurve: bondingCurvePda,
         airdropLedger: airdropLedgerPda,
         mint,
         recipientTokenAccount: referrerTokenAccount,
@@ -207,127 +306,92 @@ const program = anchor.workspace.Yozoon as Program<Yozoon>;
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .rpc();
-    
+
     // Verify airdrop
     const finalTokenBalance = await provider.connection.getTokenAccountBalance(referrerTokenAccount);
     console.log("Referrer final token balance:", finalTokenBalance.value.uiAmount);
-    
+
     // Get airdrop ledger
     const airdropLedger = await program.account.airdropLedger.fetch(airdropLedgerPda);
     console.log("Total tokens airdropped:", airdropLedger.totalAirdropped.toString());
-    
+
     // Assertions
     chai.expect(finalTokenBalance.value.uiAmount - initialTokenBalance.value.uiAmount).to.equal(1);
     chai.expect(airdropLedger.totalAirdropped.toString()).to.equal(airdropAmount.toString());
   });
-  
-  it("Initializes bonding curve", async () => {
+
+  it("Pauses and resumes buys", async () => {
+    await program.methods
+      .setPauseState(PAUSE_BUY)
+      .accounts({
+        config: configPda,
+        admin,
+      })
+      .rpc();
+
+    const configAccount = await program.account.config.fetch(configPda);
+    chai.expect(configAccount.pauseFlags).to.equal(PAUSE_BUY);
+
     try {
-      // Define price points (in lamports per token) using anchor BN
-      const pricePoints = [
-        new anchor.BN(10_000_000), // 0.01 SOL per token at 0 supply
-        new anchor.BN(15_000_000), // 0.015 SOL per token at 25% supply
-        new anchor.BN(25_000_000), // 0.025 SOL per token at 50% supply
-        new anchor.BN(50_000_000), // 0.05 SOL per token at 75% supply
-        new anchor.BN(100_000_000)  // 0.1 SOL per token at max supply
-      ];
-      
       await program.methods
-        .initializeBondingCurve(pricePoints)
-        .accounts({
-          bondingCurve: bondingCurvePda,
-          admin,
-          systemProgram: SystemProgram.programId,
-        })
+        .buyTokens(new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL))
+        .accounts(buyAccounts(false))
+        .signers([user])
         .rpc();
-        
-      // Verify the bonding curve account
-      const bondingCurveAccount = await program.account.bondingCurve.fetch(bondingCurvePda);
-      console.log("Bonding curve initialized with", bondingCurveAccount.pricePoints.length, "price points");
-      console.log("Price points:", bondingCurveAccount.pricePoints.map(p => p.toString()));
-      
-      // Assertions
-      chai.expect(bondingCurveAccount.pricePoints.length).to.equal(pricePoints.length);
-      chai.expect(bondingCurveAccount.totalSolRaised.toString()).to.equal("0");
-      chai.expect(bondingCurveAccount.totalSoldSupply.toString()).to.equal("0");
-      chai.expect(bondingCurveAccount.isMigrated).to.equal(false);
-      
-      bondingCurveInitialized = true;
+      chai.expect.fail("Buy should fail while buys are paused");
     } catch (error) {
-      console.error("Failed to initialize bonding curve:", error);
-      // Don't fail the test, just log the error
-      bondingCurveInitialized = false;
+      chai.expect(error.toString()).to.include("ProtocolPaused");
     }
+
+    await program.methods
+      .setPauseState(0)
+      .accounts({
+        config: configPda,
+        admin,
+      })
+      .rpc();
   });
-  
-  it("User buys tokens with referral", async function() {
-    // Skip if bonding curve was not initialized
-    if (!bondingCurveInitialized) {
-      console.log("Skipping buy tokens test as bonding curve was not initialized");
-      this.skip();
-      return;
-    }
-    
-    // Define accounts for buying tokens
-    // Treasury defaults to admin initially
-    const treasury = admin;
+
+  it("User buys tokens with referral", async () => {
     const solAmount = new anchor.BN(0.05 * anchor.web3.LAMPORTS_PER_SOL); // 0.05 SOL
-    
+
     const referrerInitialBalance = await provider.connection.getBalance(referrer.publicKey);
     console.log("Referrer initial balance:", referrerInitialBalance / anchor.web3.LAMPORTS_PER_SOL, "SOL");
-    
-    const treasuryInitialBalance = await provider.connection.getBalance(treasury);
-    console.log("Treasury initial balance:", treasuryInitialBalance / anchor.web3.LAMPORTS_PER_SOL, "SOL");
-    
-    try {
-      await program.methods
-        .buyTokens(solAmount)
-        .accounts({
-          config: configPda,
-          bondingCurve: bondingCurvePda,
-          mint,
-          buyerTokenAccount: userTokenAccount,
-          buyer: user.publicKey,
-          treasury,
-          referral: userReferralPda,
-          referrer: referrer.publicKey,
-          systemProgram: SystemProgram.programId,
-          tokenProgram: TOKEN_PROGRAM_ID,
-        })
-        .signers([user])
-        .rpc();
-        
-      // Verify the results
-      const bondingCurveAccount = await program.account.bondingCurve.fetch(bondingCurvePda);
-      console.log("Tokens sold:", bondingCurveAccount.totalSoldSupply.toString());
-      console.log("SOL raised:", bondingCurveAccount.totalSolRaised.toString(), "lamports");
-      
-      // Get token balance
-      const userTokenBalance = await provider.connection.getTokenAccountBalance(userTokenAccount);
-      console.log("User token balance:", userTokenBalance.value.uiAmount);
-      
-      // Check referrer received fee
-      const referrerFinalBalance = await provider.connection.getBalance(referrer.publicKey);
-      const referrerBalanceChange = referrerFinalBalance - referrerInitialBalance;
-      console.log("Referrer balance change:", referrerBalanceChange / anchor.web3.LAMPORTS_PER_SOL, "SOL");
-      
-      // Check treasury received funds
-      const treasuryFinalBalance = await provider.connection.getBalance(treasury);
-      const treasuryBalanceChange = treasuryFinalBalance - treasuryInitialBalance;
-      console.log("Treasury balance change:", treasuryBalanceChange / anchor.web3.LAMPORTS_PER_SOL, "SOL");
-      
-      // Assertions
-      chai.expect(bondingCurveAccount.totalSoldSupply.toString()).to.not.equal("0");
-      chai.expect(userTokenBalance.value.uiAmount).to.be.greaterThan(0);
-      chai.expect(referrerBalanceChange).to.be.greaterThan(0); // Referrer received fee
-      chai.expect(treasuryBalanceChange).to.be.greaterThan(0); // Treasury received funds
-      
-      // The referrer should have received 10% of the SOL amount
-      const expectedReferrerFee = solAmount.toNumber() * 0.1;
-      chai.expect(Math.abs(referrerBalanceChange - expectedReferrerFee)).to.be.lessThan(1000); // Allow small rounding diff
-    } catch (error) {
-      console.error("Error during token purchase:", error);
-      throw error;
-    }
+
+    const reserveInitialBalance = await provider.connection.getBalance(solReservePda);
+    console.log("Reserve initial balance:", reserveInitialBalance / anchor.web3.LAMPORTS_PER_SOL, "SOL");
+
+    await program.methods
+      .buyTokens(solAmount)
+      .accounts(buyAccounts(true))
+      .signers([user])
+      .rpc();
+
+    // Verify the results
+    const bondingCurveAccount = await program.account.bondingCurve.fetch(bondingCurvePda);
+    console.log("Tokens sold:", bondingCurveAccount.totalSoldSupply.toString());
+    console.log("SOL raised:", bondingCurveAccount.totalSolRaised.toString(), "lamports");
+
+    // Get token balance
+    const userTokenBalance = await provider.connection.getTokenAccountBalance(userTokenAccount);
+    console.log("User token balance:", userTokenBalance.value.uiAmount);
+
+    // Check referrer received fee
+    const referrerFinalBalance = await provider.connection.getBalance(referrer.publicKey);
+    const referrerBalanceChange = referrerFinalBalance - referrerInitialBalance;
+    console.log("Referrer balance change:", referrerBalanceChange / anchor.web3.LAMPORTS_PER_SOL, "SOL");
+
+    // Check the reserve received the rest
+    const reserveFinalBalance = await provider.connection.getBalance(solReservePda);
+    const reserveBalanceChange = reserveFinalBalance - reserveInitialBalance;
+    console.log("Reserve balance change:", reserveBalanceChange / anchor.web3.LAMPORTS_PER_SOL, "SOL");
+
+    // Assertions: the referrer gets the 1% default fee and the reserve keeps the rest
+    const expectedReferrerFee = solAmount.toNumber() / 100;
+    chai.expect(bondingCurveAccount.totalSoldSupply.toString()).to.not.equal("0");
+    chai.expect(userTokenBalance.value.uiAmount).to.be.greaterThan(0);
+    chai.expect(referrerBalanceChange).to.equal(expectedReferrerFee);
+    chai.expect(reserveBalanceChange).to.equal(solAmount.toNumber() - expectedReferrerFee);
+    chai.expect(bondingCurveAccount.totalSolRaised.toNumber()).to.equal(reserveBalanceChange);
   });
-});
\ No newline at end of file
+});